@@ -4,7 +4,7 @@
 //! - DynamicFrame adapter (HashMap-based field lookups)
 //! - Derived adapters with varying field counts (5, 20, 50 fields)
 //! - Optional vs required field extraction overhead
-//! - Array field extraction performance
+//! - Array field extraction performance, copied into `Vec`s versus `ArrayView`s
 //!
 //! Platform: Cross-platform (uses IBT test files, CI-safe)
 
@@ -13,7 +13,7 @@
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use pitwall::adapters::FrameAdapter;
 use pitwall::types::FramePacket;
-use pitwall::{DynamicFrame, PitwallFrame, VariableSchema};
+use pitwall::{ArrayView, DynamicFrame, PitwallFrame, VariableSchema};
 use std::hint::black_box;
 use std::sync::Arc;
 
@@ -325,5 +325,63 @@ fn bench_optional_fields(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_dynamic_frame, bench_derived_adapters, bench_optional_fields);
+/// Per-car arrays a timing tower reads alongside a wide adapter
+const CAR_IDX_ARRAYS: [&str; 3] = ["CarIdxLapDistPct", "CarIdxEstTime", "CarIdxF2Time"];
+
+fn bench_borrowed_arrays(c: &mut Criterion) {
+    let (packet, schema) = get_test_frame();
+
+    let mut group = c.benchmark_group("borrowed_arrays");
+
+    group.bench_function(BenchmarkId::new("car_idx_arrays", "vec_copy"), |b| {
+        b.iter(|| {
+            let total: f32 = CAR_IDX_ARRAYS
+                .iter()
+                .filter_map(|name| packet.get::<Vec<f32>>(black_box(name)).ok())
+                .map(|values| values.iter().sum::<f32>())
+                .sum();
+            black_box(total)
+        })
+    });
+    group.bench_function(BenchmarkId::new("car_idx_arrays", "array_view"), |b| {
+        b.iter(|| {
+            let total: f32 = CAR_IDX_ARRAYS
+                .iter()
+                .filter_map(|name| packet.get_array_view::<f32>(black_box(name)).ok())
+                .map(|view| view.iter().sum::<f32>())
+                .sum();
+            black_box(total)
+        })
+    });
+
+    // The 50-field adapter plus the arrays, copied versus viewed
+    if let Ok(validation) = LargeFrame::validate_schema(&schema) {
+        group.bench_function(BenchmarkId::new("large_frame", "with_vec_arrays"), |b| {
+            b.iter(|| {
+                let frame = LargeFrame::adapt(black_box(&packet), black_box(&validation));
+                let arrays: Vec<Vec<f32>> =
+                    CAR_IDX_ARRAYS.iter().filter_map(|name| packet.get(name).ok()).collect();
+                black_box((frame, arrays))
+            })
+        });
+        group.bench_function(BenchmarkId::new("large_frame", "with_array_views"), |b| {
+            b.iter(|| {
+                let frame = LargeFrame::adapt(black_box(&packet), black_box(&validation));
+                let arrays: [Option<ArrayView<f32>>; 3] =
+                    CAR_IDX_ARRAYS.map(|name| packet.get_array_view(name).ok());
+                black_box((frame, arrays))
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_dynamic_frame,
+    bench_derived_adapters,
+    bench_optional_fields,
+    bench_borrowed_arrays
+);
 criterion_main!(benches);
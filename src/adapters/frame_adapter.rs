@@ -34,5 +34,7 @@ pub trait FrameAdapter: Sized {
     ///
     /// The frame packet provides zero-copy access to telemetry data via its
     /// Arc<[u8]> buffer. Adapters extract fields directly from packet.data.
+    /// Array fields can be held as [`ArrayView`](crate::types::ArrayView)s via
+    /// [`AdapterValidation::fetch_array_view`] to avoid allocating a `Vec` per frame.
     fn adapt(packet: &crate::types::FramePacket, validation: &AdapterValidation) -> Self;
 }
@@ -15,7 +15,7 @@
 //!
 //! ```rust
 //! use std::sync::Arc;
-//! use pitwall::{types::FramePacket, VariableSchema, Result, TelemetryError, VarData, adapters::*};
+//! use pitwall::{types::FramePacket, VariableSchema, Result, adapters::*};
 //!
 //! // Manual adapter implementation
 //! struct CarData {
@@ -30,16 +30,19 @@
 //!
 //!         // Validate required fields exist
 //!         let speed_info = schema.get_variable("Speed")
-//!             .ok_or_else(|| TelemetryError::Parse {
-//!                 context: "Field validation".to_string(),
-//!                 details: "Missing required field 'Speed'".to_string(),
-//!             })?;
+//!             .ok_or_else(|| missing_field_error("Speed", schema))?;
 //!
 //!         extraction_plan.push(FieldExtraction::Required {
 //!             name: "Speed".to_string(),
 //!             var_info: speed_info.clone(),
 //!         });
 //!
+//!         // Optional fields never fail validation
+//!         extraction_plan.push(FieldExtraction::Optional {
+//!             name: "Gear".to_string(),
+//!             var_info: schema.get_variable("Gear").cloned(),
+//!         });
+//!
 //!         Ok(AdapterValidation::new(extraction_plan))
 //!     }
 //!
@@ -48,12 +51,8 @@
 //!         let speed = validation.fetch_or_default::<f32>(packet, "Speed");
 //!         let rpm = validation.fetch_or_default::<i32>(packet, "RPM");
 //!
-//!         // Optional fields check the extraction plan for a mapped VariableInfo
-//!         let gear = validation
-//!             .index_of("Gear")
-//!             .and_then(|idx| validation.extraction_plan.get(idx))
-//!             .and_then(|field| field.var_info())
-//!             .and_then(|info| i32::from_bytes(packet.data.as_ref(), info).ok());
+//!         // Optional fields resolve to None when the variable is absent
+//!         let gear = validation.fetch_optional::<i32>(packet, "Gear");
 //!
 //!         Self { speed, rpm, gear }
 //!     }
@@ -61,13 +60,19 @@
 //! ```
 
 mod frame_adapter;
+mod registry;
 mod schema_provider;
+mod units;
 mod validation;
 
 // Re-export all public types
 pub use frame_adapter::FrameAdapter;
+pub use registry::{AdapterRegistry, BoundAdapter, DynamicValue};
 pub use schema_provider::SchemaProvider;
-pub use validation::{AdapterValidation, DefaultValue, FieldExtraction};
+pub use units::UnitConversion;
+pub use validation::{
+    AdapterValidation, DefaultValue, FieldExtraction, missing_field_error, suggest_field_names,
+};
 
 #[cfg(test)]
 mod tests {
@@ -147,11 +152,13 @@ mod tests {
         assert_eq!(required_field.field_name(), Some("Speed"));
         assert!(required_field.is_required());
         assert!(required_field.var_info().is_some());
+        assert_eq!(required_field.element_count(), Some(1));
 
         let skipped_field = FieldExtraction::Skipped;
         assert_eq!(skipped_field.field_name(), None);
         assert!(!skipped_field.is_required());
         assert!(skipped_field.var_info().is_none());
+        assert_eq!(skipped_field.element_count(), None);
     }
 
     #[test]
@@ -176,4 +183,136 @@ mod tests {
         assert!(field_names.contains(&"Speed".to_string()));
         assert!(field_names.contains(&"RPM".to_string()));
     }
+
+    #[test]
+    fn unit_conversion_applies_and_validates_units() {
+        let schema = create_test_schema();
+        let speed = schema.get_variable("Speed").unwrap();
+
+        let to_kph = UnitConversion::new("m/s", "km/h").unwrap();
+        assert!((to_kph.apply(10.0) - 36.0).abs() < 1e-9);
+        assert!(to_kph.validate(speed).is_err(), "Test schema reports Speed in mph");
+
+        let mut speed_ms = speed.clone();
+        speed_ms.units = "m/s".to_string();
+        assert!(to_kph.validate(&speed_ms).is_ok());
+
+        let to_fahrenheit = UnitConversion::new("C", "F").unwrap();
+        assert!((to_fahrenheit.apply(100.0) - 212.0).abs() < 1e-9);
+        let to_degrees = UnitConversion::new("rad", "deg").unwrap();
+        assert!((to_degrees.apply(std::f64::consts::PI) - 180.0).abs() < 1e-9);
+
+        assert_eq!(UnitConversion::new("m/s", "furlongs/fortnight"), None);
+    }
+
+    #[test]
+    fn fetch_optional_returns_none_for_missing_variables() {
+        let schema = std::sync::Arc::new(create_test_schema());
+        let mut data = vec![0u8; 8];
+        data[..4].copy_from_slice(&42.5f32.to_le_bytes());
+        let packet = crate::FramePacket::new(data, 1, 1, schema.clone());
+
+        let validation = AdapterValidation::new(vec![
+            FieldExtraction::Optional {
+                name: "Speed".to_string(),
+                var_info: schema.get_variable("Speed").cloned(),
+            },
+            FieldExtraction::Optional {
+                name: "DcBrakeBias".to_string(),
+                var_info: schema.get_variable("DcBrakeBias").cloned(),
+            },
+        ]);
+
+        assert_eq!(validation.fetch_optional::<f32>(&packet, "Speed"), Some(42.5));
+        assert_eq!(validation.fetch_optional::<f32>(&packet, "DcBrakeBias"), None);
+        assert_eq!(validation.fetch_optional::<i32>(&packet, "Speed"), None);
+    }
+
+    #[test]
+    fn fetch_or_falls_back_to_the_given_default() {
+        let schema = std::sync::Arc::new(create_test_schema());
+        let mut data = vec![0u8; 8];
+        data[..4].copy_from_slice(&42.5f32.to_le_bytes());
+        let packet = crate::FramePacket::new(data, 1, 1, schema.clone());
+
+        let validation = AdapterValidation::new(vec![
+            FieldExtraction::Required {
+                name: "Speed".to_string(),
+                var_info: schema.get_variable("Speed").cloned().unwrap(),
+            },
+            FieldExtraction::WithDefault {
+                name: "DcBrakeBias".to_string(),
+                var_info: schema.get_variable("DcBrakeBias").cloned(),
+                default_value: DefaultValue::ExplicitExpression("50.0".to_string()),
+            },
+        ]);
+
+        assert_eq!(validation.fetch_or(&packet, "Speed", 0.0f32), 42.5);
+        assert_eq!(validation.fetch_or(&packet, "DcBrakeBias", 25.0f32), 25.0);
+        assert_eq!(validation.fetch_or_default::<f32>(&packet, "DcBrakeBias"), 50.0);
+        assert_eq!(validation.fetch_or_default::<f32>(&packet, "Speed"), 42.5);
+        assert_eq!(validation.fetch_or_default::<f32>(&packet, "RPM"), 0.0, "No default set");
+    }
+
+    #[test]
+    fn explicit_defaults_decode_literals() {
+        let default = |expression: &str| DefaultValue::ExplicitExpression(expression.to_string());
+
+        assert_eq!(default("100.0f32").literal::<f32>(), Some(100.0));
+        assert_eq!(default("0i32").literal::<i32>(), Some(0));
+        assert_eq!(default("-1").literal::<i32>(), Some(-1));
+        assert_eq!(default("50").literal::<f64>(), Some(50.0));
+        assert_eq!(default("1_000").literal::<u32>(), Some(1000));
+        assert_eq!(default("true").literal::<bool>(), Some(true));
+        assert_eq!(default("2.5").literal::<i32>(), None, "Floats do not narrow to integers");
+        assert_eq!(default("speed * 2.0").literal::<f32>(), None);
+        assert_eq!(DefaultValue::TypeDefault.literal::<f32>(), None);
+    }
+
+    #[test]
+    fn short_frame_reads_fail_without_panicking() {
+        let schema = std::sync::Arc::new(create_test_schema());
+        let validation = AdapterValidation::for_fields(&["Speed", "RPM"], &schema).unwrap();
+
+        // Buffer shrank to 6 bytes: Speed still fits, RPM (offset 4, 4 bytes) does not
+        let mut data = vec![0u8; 6];
+        data[..4].copy_from_slice(&42.5f32.to_le_bytes());
+        let packet = crate::FramePacket::new(data, 1, 1, schema.clone());
+
+        assert_eq!(validation.fetch::<f32>(&packet, "Speed").unwrap(), 42.5);
+        assert!(matches!(
+            validation.fetch::<i32>(&packet, "RPM"),
+            Err(crate::TelemetryError::Memory { offset: 4, .. })
+        ));
+        assert!(matches!(
+            validation.fetch::<i32>(&packet, "Gear"),
+            Err(crate::TelemetryError::FieldNotFound { .. })
+        ));
+        assert_eq!(validation.fetch_or_default::<i32>(&packet, "RPM"), 0);
+        assert_eq!(validation.fetch_optional::<i32>(&packet, "RPM"), None);
+        assert_eq!(validation.extract_values(&packet).len(), 1);
+    }
+
+    #[test]
+    fn missing_field_error_suggests_close_names() {
+        let schema = create_test_schema();
+
+        assert_eq!(suggest_field_names("Sped", &schema), vec!["Speed".to_string()]);
+        assert_eq!(suggest_field_names("rpm", &schema), vec!["RPM".to_string()]);
+        assert!(suggest_field_names("LapDistPct", &schema).is_empty());
+
+        match missing_field_error("Sped", &schema) {
+            crate::TelemetryError::Parse { details, .. } => {
+                assert!(details.contains("did you mean 'Speed'?"), "{}", details);
+            }
+            other => panic!("Expected Parse error, got {:?}", other),
+        }
+
+        match missing_field_error("LapDistPct", &schema) {
+            crate::TelemetryError::Parse { details, .. } => {
+                assert!(!details.contains("did you mean"), "{}", details);
+            }
+            other => panic!("Expected Parse error, got {:?}", other),
+        }
+    }
 }
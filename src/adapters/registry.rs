@@ -0,0 +1,132 @@
+//! Runtime registry of frame adapters selected by name
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::{AdapterValidation, FrameAdapter};
+use crate::types::FramePacket;
+use crate::{Result, TelemetryError, VariableSchema};
+
+/// Type-erased frame produced by a registered adapter
+pub type DynamicValue = Box<dyn Any + Send>;
+
+type ValidateFn = Arc<dyn Fn(&VariableSchema) -> Result<AdapterValidation> + Send + Sync>;
+type AdaptFn = Arc<dyn Fn(&FramePacket, &AdapterValidation) -> DynamicValue + Send + Sync>;
+
+/// Adapters registered by name for plugin and scripting hosts.
+///
+/// Each entry pairs a schema validation step with a type-erased `adapt`, so a
+/// telemetry view can be chosen from a string at runtime and its frames
+/// downcast by whoever knows the concrete type.
+///
+/// ```ignore
+/// let mut registry = AdapterRegistry::new();
+/// registry.register::<CarData>("car_data");
+///
+/// let mut frames = connection.subscribe_dynamic(&registry, "car_data", UpdateRate::Native)?;
+/// if let Some(frame) = frames.next().await {
+///     let car = frame.downcast::<CarData>().expect("car_data yields CarData");
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct AdapterRegistry {
+    adapters: HashMap<String, RegisteredAdapter>,
+}
+
+#[derive(Clone)]
+struct RegisteredAdapter {
+    validate: ValidateFn,
+    adapt: AdaptFn,
+}
+
+impl AdapterRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a [`FrameAdapter`] under `name`, replacing any previous entry.
+    pub fn register<T>(&mut self, name: impl Into<String>) -> &mut Self
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        self.register_fn(name, T::validate_schema, |packet, validation| {
+            Box::new(T::adapt(packet, validation))
+        })
+    }
+
+    /// Register a validation function and type-erased adapter under `name`.
+    ///
+    /// For adapters without a concrete [`FrameAdapter`] type, such as views
+    /// defined by a script.
+    pub fn register_fn<V, A>(&mut self, name: impl Into<String>, validate: V, adapt: A) -> &mut Self
+    where
+        V: Fn(&VariableSchema) -> Result<AdapterValidation> + Send + Sync + 'static,
+        A: Fn(&FramePacket, &AdapterValidation) -> DynamicValue + Send + Sync + 'static,
+    {
+        let adapter = RegisteredAdapter { validate: Arc::new(validate), adapt: Arc::new(adapt) };
+        self.adapters.insert(name.into(), adapter);
+        self
+    }
+
+    /// Whether an adapter is registered under `name`
+    pub fn contains(&self, name: &str) -> bool {
+        self.adapters.contains_key(name)
+    }
+
+    /// Registered adapter names, sorted
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.adapters.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Validate the adapter registered under `name` against `schema`.
+    ///
+    /// Fails when nothing is registered under `name` or the adapter rejects the schema.
+    pub fn bind(&self, name: &str, schema: &VariableSchema) -> Result<BoundAdapter> {
+        let adapter = self.adapters.get(name).ok_or_else(|| TelemetryError::Parse {
+            context: "Adapter registry".to_string(),
+            details: format!(
+                "No adapter registered as '{}' (registered: {})",
+                name,
+                self.names().join(", ")
+            ),
+        })?;
+
+        let validation = (adapter.validate)(schema)?;
+        Ok(BoundAdapter { adapt: Arc::clone(&adapter.adapt), validation })
+    }
+}
+
+impl std::fmt::Debug for AdapterRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdapterRegistry").field("adapters", &self.names()).finish()
+    }
+}
+
+/// A registered adapter validated against a schema, ready to adapt frames
+#[derive(Clone)]
+pub struct BoundAdapter {
+    adapt: AdaptFn,
+    validation: AdapterValidation,
+}
+
+impl BoundAdapter {
+    /// Adapt a frame into the registered adapter's output type
+    pub fn adapt(&self, packet: &FramePacket) -> DynamicValue {
+        (self.adapt)(packet, &self.validation)
+    }
+
+    /// The extraction plan built at bind time
+    pub fn validation(&self) -> &AdapterValidation {
+        &self.validation
+    }
+}
+
+impl AsRef<AdapterValidation> for BoundAdapter {
+    fn as_ref(&self) -> &AdapterValidation {
+        &self.validation
+    }
+}
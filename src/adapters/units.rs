@@ -0,0 +1,92 @@
+//! Unit conversions applied to adapter fields after extraction
+
+use crate::{Result, TelemetryError, VariableInfo};
+
+/// Conversion from an iRacing SDK unit to a display unit.
+///
+/// Look one up with [`new`](Self::new) from a field's declared `from`/`to`
+/// units, check it against the variable with [`validate`](Self::validate) in
+/// the adapter's `validate_schema`, then [`apply`](Self::apply) it to each raw
+/// value. The `PitwallFrame` derive does not generate these calls yet; adapters
+/// that convert units make them by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitConversion {
+    /// Meters per second to kilometers per hour
+    MetersPerSecondToKph,
+    /// Meters per second to miles per hour
+    MetersPerSecondToMph,
+    /// Radians to degrees
+    RadiansToDegrees,
+    /// Degrees Celsius to degrees Fahrenheit
+    CelsiusToFahrenheit,
+    /// Kilopascals to pounds per square inch
+    KilopascalsToPsi,
+}
+
+impl UnitConversion {
+    /// Look up the conversion between two unit strings.
+    ///
+    /// Returns `None` when the pair is not supported.
+    pub fn new(from: &str, to: &str) -> Option<Self> {
+        match (from, to) {
+            ("m/s", "km/h") => Some(UnitConversion::MetersPerSecondToKph),
+            ("m/s", "mph") => Some(UnitConversion::MetersPerSecondToMph),
+            ("rad", "deg") => Some(UnitConversion::RadiansToDegrees),
+            ("C", "F") => Some(UnitConversion::CelsiusToFahrenheit),
+            ("kPa", "psi") => Some(UnitConversion::KilopascalsToPsi),
+            _ => None,
+        }
+    }
+
+    /// Unit the raw telemetry value is expected to be in.
+    pub fn source_unit(&self) -> &'static str {
+        match self {
+            UnitConversion::MetersPerSecondToKph | UnitConversion::MetersPerSecondToMph => "m/s",
+            UnitConversion::RadiansToDegrees => "rad",
+            UnitConversion::CelsiusToFahrenheit => "C",
+            UnitConversion::KilopascalsToPsi => "kPa",
+        }
+    }
+
+    /// Unit produced by the conversion.
+    pub fn target_unit(&self) -> &'static str {
+        match self {
+            UnitConversion::MetersPerSecondToKph => "km/h",
+            UnitConversion::MetersPerSecondToMph => "mph",
+            UnitConversion::RadiansToDegrees => "deg",
+            UnitConversion::CelsiusToFahrenheit => "F",
+            UnitConversion::KilopascalsToPsi => "psi",
+        }
+    }
+
+    /// Convert a raw telemetry value.
+    pub fn apply(&self, value: f64) -> f64 {
+        match self {
+            UnitConversion::MetersPerSecondToKph => value * 3.6,
+            UnitConversion::MetersPerSecondToMph => value * 2.236_936_292_054_402,
+            UnitConversion::RadiansToDegrees => value.to_degrees(),
+            UnitConversion::CelsiusToFahrenheit => value * 9.0 / 5.0 + 32.0,
+            UnitConversion::KilopascalsToPsi => value * 0.145_037_737_730_209_2,
+        }
+    }
+
+    /// Check that a variable reports the unit this conversion expects.
+    ///
+    /// Returns a `Parse` error naming the field and both units on a mismatch.
+    pub fn validate(&self, var_info: &VariableInfo) -> Result<()> {
+        if var_info.units != self.source_unit() {
+            return Err(TelemetryError::Parse {
+                context: "Field validation".to_string(),
+                details: format!(
+                    "Field '{}' has units '{}', expected '{}' for conversion to '{}'",
+                    var_info.name,
+                    var_info.units,
+                    self.source_unit(),
+                    self.target_unit()
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,17 @@
 //! Validation types and field extraction strategies for adapters
 
 #[allow(unused_imports)] // Used by generated derive macro code
-use crate::{TelemetryError, VariableInfo, VariableSchema};
+use crate::{TelemetryError, VariableInfo, VariableSchema, VariableType};
 #[allow(unused_imports)] // Used by generated derive macro code and tests
 use std::collections::HashMap;
 
+/// Upper bound on the edit distance at which a schema variable is offered as a
+/// suggestion. Shorter names get a tighter bound of one edit per three characters.
+const SUGGESTION_MAX_DISTANCE: usize = 3;
+
+/// Maximum number of suggestions included in a missing-field error.
+const SUGGESTION_LIMIT: usize = 3;
+
 /// Pre-computed extraction plan built during connection-time validation.
 ///
 /// Contains all information needed for efficient runtime extraction:
@@ -34,6 +41,47 @@ impl AdapterValidation {
         Self { extraction_plan, index_map }
     }
 
+    /// Build a plan that reads exactly the named variables, in offset order.
+    ///
+    /// Every name is required; the first one missing from `schema` fails with a
+    /// "did you mean" error. Duplicate names are read once.
+    pub fn for_fields(names: &[&str], schema: &VariableSchema) -> crate::Result<Self> {
+        let mut extraction_plan: Vec<FieldExtraction> = Vec::with_capacity(names.len());
+        for &name in names {
+            if extraction_plan.iter().any(|entry| entry.field_name() == Some(name)) {
+                continue;
+            }
+            let var_info =
+                schema.get_variable(name).ok_or_else(|| missing_field_error(name, schema))?;
+            extraction_plan.push(FieldExtraction::Required {
+                name: name.to_string(),
+                var_info: var_info.clone(),
+            });
+        }
+
+        extraction_plan
+            .sort_by_key(|entry| entry.var_info().map_or(usize::MAX, |info| info.offset));
+        Ok(Self::new(extraction_plan))
+    }
+
+    /// Decode every variable in the plan from `packet`, keyed by name.
+    ///
+    /// Variables that cannot be read from this frame are left out.
+    pub fn extract_values(
+        &self,
+        packet: &crate::types::FramePacket,
+    ) -> HashMap<String, crate::types::Value> {
+        let data = packet.data.as_ref();
+        self.extraction_plan
+            .iter()
+            .filter_map(|entry| {
+                let value =
+                    <crate::types::Value as crate::VarData>::from_bytes(data, entry.var_info()?);
+                Some((entry.field_name()?.to_string(), value.ok()?))
+            })
+            .collect()
+    }
+
     /// Get the number of fields that will be extracted.
     pub fn field_count(&self) -> usize {
         self.extraction_plan.len()
@@ -44,35 +92,127 @@ impl AdapterValidation {
         self.extraction_plan.iter().any(|field| matches!(field, FieldExtraction::Required { .. }))
     }
 
+    /// Check whether a schema change invalidates this plan.
+    ///
+    /// True when any field the plan reads was added, removed or re-laid out, so
+    /// the adapter must be validated again against the new schema.
+    pub fn is_affected_by(&self, delta: &crate::types::SchemaDelta) -> bool {
+        self.index_map.keys().any(|name| delta.touches(name))
+    }
+
     /// Lookup the extraction index for a telemetry field name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.index_map.get(name).copied()
     }
 
+    /// Fetch a telemetry value by name, reporting why it could not be read.
+    ///
+    /// Returns `FieldNotFound` when the plan has no variable for `name`, and
+    /// `Memory` when the frame is shorter than the variable's extent (as after a
+    /// car swap, before the schema is rebuilt). The whole variable is
+    /// bounds-checked before any element is decoded.
+    pub fn fetch<T>(&self, packet: &crate::types::FramePacket, name: &str) -> crate::Result<T>
+    where
+        T: crate::VarData,
+    {
+        let var_info = self
+            .index_of(name)
+            .and_then(|index| self.extraction_plan.get(index))
+            .and_then(|entry| entry.var_info())
+            .ok_or_else(|| TelemetryError::FieldNotFound { field: name.to_string() })?;
+
+        var_info.check_bounds(packet.data.len())?;
+        <T as crate::VarData>::from_bytes(packet.data.as_ref(), var_info)
+    }
+
+    /// View an array variable from the plan without copying its elements.
+    ///
+    /// Errors like [`fetch`](Self::fetch). The view shares the packet's buffer,
+    /// so an adapter can keep it for the cost of a reference count increment.
+    pub fn fetch_array_view<T>(
+        &self,
+        packet: &crate::types::FramePacket,
+        name: &str,
+    ) -> crate::Result<crate::types::ArrayView<T>>
+    where
+        T: crate::VarData,
+    {
+        let var_info = self
+            .index_of(name)
+            .and_then(|index| self.extraction_plan.get(index))
+            .and_then(|entry| entry.var_info())
+            .ok_or_else(|| TelemetryError::FieldNotFound { field: name.to_string() })?;
+
+        crate::types::ArrayView::new(std::sync::Arc::clone(&packet.data), var_info)
+    }
+
     /// Fetch a telemetry value by name using the precomputed extraction plan.
+    ///
+    /// Falls back when the value cannot be read, including when the frame is
+    /// shorter than the schema expects. The fallback is the field's
+    /// [`DefaultValue::ExplicitExpression`] when the plan has one that decodes as
+    /// `T` (see [`DefaultValue::literal`]), and `T::default()` otherwise.
     pub fn fetch_or_default<T>(&self, packet: &crate::types::FramePacket, name: &str) -> T
     where
         T: crate::VarData + ::core::default::Default,
     {
-        let data = packet.data.as_ref();
+        self.read_value(packet, name).unwrap_or_else(|| {
+            self.index_of(name)
+                .and_then(|index| self.extraction_plan.get(index))
+                .and_then(FieldExtraction::default_value)
+                .and_then(DefaultValue::literal)
+                .unwrap_or_default()
+        })
+    }
 
-        if let Some(index) = self.index_of(name) {
-            if let Some(entry) = self.extraction_plan.get(index) {
-                if let Some(var_info) = entry.var_info() {
-                    if let Ok(value) = <T as crate::VarData>::from_bytes(data, var_info) {
-                        return value;
-                    }
-                }
-            }
-        }
+    /// Fetch a telemetry value by name, falling back to `default`.
+    ///
+    /// Behaves like [`fetch_or_default`](Self::fetch_or_default) but with a
+    /// caller-chosen fallback, for fields such as `DcBrakeBias` where zero would
+    /// be misleading on cars that lack the setting.
+    pub fn fetch_or<T>(&self, packet: &crate::types::FramePacket, name: &str, default: T) -> T
+    where
+        T: crate::VarData,
+    {
+        self.read_value(packet, name).unwrap_or(default)
+    }
 
-        if let Some(var_info) = packet.schema.get_variable(name) {
-            if let Ok(value) = <T as crate::VarData>::from_bytes(data, var_info) {
-                return value;
-            }
-        }
+    /// Read `name` through the plan, then through the packet's own schema
+    fn read_value<T>(&self, packet: &crate::types::FramePacket, name: &str) -> Option<T>
+    where
+        T: crate::VarData,
+    {
+        let data = packet.data.as_ref();
+        self.index_of(name)
+            .and_then(|index| self.extraction_plan.get(index))
+            .and_then(FieldExtraction::var_info)
+            .and_then(|var_info| <T as crate::VarData>::from_bytes(data, var_info).ok())
+            .or_else(|| {
+                let var_info = packet.schema.get_variable(name)?;
+                <T as crate::VarData>::from_bytes(data, var_info).ok()
+            })
+    }
+
+    /// Fetch an optional telemetry value by name.
+    ///
+    /// Returns `None` when the variable was absent from the schema at
+    /// validation time or cannot be read from this frame.
+    pub fn fetch_optional<T>(&self, packet: &crate::types::FramePacket, name: &str) -> Option<T>
+    where
+        T: crate::VarData,
+    {
+        self.index_of(name)
+            .and_then(|index| self.extraction_plan.get(index))
+            .and_then(|entry| entry.var_info())
+            .and_then(|var_info| {
+                <T as crate::VarData>::from_bytes(packet.data.as_ref(), var_info).ok()
+            })
+    }
+}
 
-        T::default()
+impl AsRef<AdapterValidation> for AdapterValidation {
+    fn as_ref(&self) -> &AdapterValidation {
+        self
     }
 }
 
@@ -143,6 +283,22 @@ impl FieldExtraction {
             FieldExtraction::Calculated { .. } | FieldExtraction::Skipped => None,
         }
     }
+
+    /// Get the fallback strategy of a [`WithDefault`](Self::WithDefault) field.
+    pub fn default_value(&self) -> Option<&DefaultValue> {
+        match self {
+            FieldExtraction::WithDefault { default_value, .. } => Some(default_value),
+            _ => None,
+        }
+    }
+
+    /// Get the number of elements the mapped variable holds (1 for scalars).
+    ///
+    /// Returns `None` for fields that do not read a variable. Adapters can use
+    /// it to size buffers for array fields; validation does not check it.
+    pub fn element_count(&self) -> Option<usize> {
+        self.var_info().map(|info| info.count)
+    }
 }
 
 /// Describes how a default value should be produced when telemetry data is unavailable.
@@ -151,6 +307,10 @@ pub enum DefaultValue {
     /// Use the `Default` implementation of the target field type.
     TypeDefault,
     /// Evaluate a user-provided expression supplied via `#[missing = "..."]`.
+    ///
+    /// [`AdapterValidation::fetch_or_default`] falls back to the expression when
+    /// it is a literal (see [`DefaultValue::literal`]), both when the variable is
+    /// absent from the schema and when it cannot be read from a frame.
     ExplicitExpression(String),
 }
 
@@ -162,4 +322,121 @@ impl DefaultValue {
             DefaultValue::ExplicitExpression(_) => "explicit expression",
         }
     }
+
+    /// Decode an explicit default as `T`.
+    ///
+    /// Only literals are understood: `true`, `false` and numbers, with an
+    /// optional type suffix as in `50.0f32` or `0i32`. Returns `None` for
+    /// [`TypeDefault`](Self::TypeDefault), for any other expression, and when the
+    /// literal is not a valid `T`.
+    pub fn literal<T: crate::VarData>(&self) -> Option<T> {
+        let DefaultValue::ExplicitExpression(expression) = self else {
+            return None;
+        };
+
+        let literal = expression.trim().replace('_', "");
+        let candidates: Vec<(VariableType, Vec<u8>)> = match literal.as_str() {
+            "true" | "false" => {
+                vec![(VariableType::Bool, vec![u8::from(literal == "true")])]
+            }
+            _ => numeric_candidates(strip_type_suffix(&literal))?,
+        };
+
+        candidates.into_iter().find_map(|(data_type, bytes)| {
+            let info = VariableInfo {
+                name: String::new(),
+                data_type,
+                offset: 0,
+                count: 1,
+                count_as_time: false,
+                units: String::new(),
+                description: String::new(),
+            };
+            <T as crate::VarData>::from_bytes(&bytes, &info).ok()
+        })
+    }
+}
+
+/// Remove a Rust numeric type suffix such as `f32` or `i32` from a literal
+fn strip_type_suffix(literal: &str) -> &str {
+    const SUFFIXES: [&str; 12] =
+        ["f32", "f64", "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize"];
+    SUFFIXES.iter().find_map(|suffix| literal.strip_suffix(suffix)).unwrap_or(literal)
+}
+
+/// Encodings of a numeric literal as each frame type it can be read from
+///
+/// Integers are offered as every integer type they fit and as floats, so `0`
+/// serves `i32` and `f32` fields alike. Floats are offered as floats only.
+fn numeric_candidates(literal: &str) -> Option<Vec<(VariableType, Vec<u8>)>> {
+    if let Ok(value) = literal.parse::<i64>() {
+        let mut candidates = Vec::new();
+        if let Ok(value) = i32::try_from(value) {
+            candidates.push((VariableType::Int32, value.to_le_bytes().to_vec()));
+        }
+        if let Ok(value) = u32::try_from(value) {
+            candidates.push((VariableType::UInt32, value.to_le_bytes().to_vec()));
+            candidates.push((VariableType::BitField, value.to_le_bytes().to_vec()));
+        }
+        candidates.push((VariableType::Float32, (value as f32).to_le_bytes().to_vec()));
+        candidates.push((VariableType::Float64, (value as f64).to_le_bytes().to_vec()));
+        return Some(candidates);
+    }
+
+    let value = literal.parse::<f64>().ok()?;
+    Some(vec![
+        (VariableType::Float32, (value as f32).to_le_bytes().to_vec()),
+        (VariableType::Float64, value.to_le_bytes().to_vec()),
+    ])
+}
+
+/// Find schema variables whose names are close to `name`.
+///
+/// Uses case-insensitive Levenshtein distance and returns up to three
+/// candidates, closest first.
+pub fn suggest_field_names(name: &str, schema: &VariableSchema) -> Vec<String> {
+    let target = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).clamp(1, SUGGESTION_MAX_DISTANCE);
+    let mut candidates: Vec<(usize, &String)> = schema
+        .variables
+        .keys()
+        .map(|candidate| (levenshtein(&target, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    candidates.sort();
+    candidates.into_iter().take(SUGGESTION_LIMIT).map(|(_, candidate)| candidate.clone()).collect()
+}
+
+/// Build the error returned when a required field is missing from the schema.
+///
+/// The details include "did you mean" suggestions when similar variable names exist.
+pub fn missing_field_error(name: &str, schema: &VariableSchema) -> TelemetryError {
+    let suggestions = suggest_field_names(name, schema);
+    let details = if suggestions.is_empty() {
+        format!("Missing required field '{}'", name)
+    } else {
+        let quoted: Vec<String> = suggestions.iter().map(|s| format!("'{}'", s)).collect();
+        format!("Missing required field '{}', did you mean {}?", name, quoted.join(", "))
+    };
+
+    TelemetryError::Parse { context: "Field validation".to_string(), details }
+}
+
+/// Levenshtein edit distance between two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
 }
@@ -1,18 +1,30 @@
 //! Live telemetry connection for Windows
 
 use crate::Result;
+use crate::driver::DEFAULT_SESSION_CHANNEL_CAPACITY;
+use std::time::Duration;
 
 #[cfg(windows)]
 use {
+    super::poll::FramePoller,
+    super::{ConnectionStatus, StreamStats},
+    super::{TelemetrySource, add_computed_channel, attach_computed},
+    crate::adapters::{AdapterRegistry, AdapterValidation, DynamicValue},
     crate::driver::Driver,
     crate::provider::Provider,
     crate::providers::live::LiveProvider,
-    crate::stream::ThrottleExt,
-    crate::types::{FramePacket, UpdateRate},
-    crate::{FrameAdapter, SessionInfo, VariableSchema},
-    futures::{Stream, StreamExt},
+    crate::schema::session::SessionDiff,
+    crate::stream::{
+        FilterExt, IncidentEvent, LapSummary, RaceState, Revalidator, ThrottleExt,
+        adapt_revalidating, apply_rate, incident_updates, lap_completions, race_state_updates,
+        session_changes,
+    },
+    crate::types::{ComputedChannels, FrameMeta, FramePacket, FrameWindow, UpdateRate, Value},
+    crate::{FrameAdapter, SchemaProvider, SessionInfo, VariableInfo, VariableSchema},
+    futures::{Stream, StreamExt, future},
+    std::collections::HashMap,
     std::sync::Arc,
-    std::time::Duration,
+    std::sync::atomic::{AtomicU64, Ordering},
     tokio::sync::watch,
     tokio_stream::wrappers::WatchStream,
     tokio_util::sync::CancellationToken,
@@ -28,12 +40,30 @@ pub struct LiveConnection {
     /// Session watch receiver
     sessions: watch::Receiver<Option<Arc<SessionInfo>>>,
 
+    /// Preprocessed YAML behind the latest session update
+    session_yaml: watch::Receiver<Option<Arc<str>>>,
+
     /// Variable schema
     schema: Arc<VariableSchema>,
 
     /// Source frequency
     source_hz: f64,
 
+    /// Latest-frame poller for `poll_latest`
+    poller: FramePoller,
+
+    /// Computed channels attached to every delivered packet
+    computed: Arc<ComputedChannels>,
+
+    /// Connection status receiver from the provider
+    status: watch::Receiver<ConnectionStatus>,
+
+    /// Frame delivery statistics from the provider
+    stats: watch::Receiver<StreamStats>,
+
+    /// Session updates dropped by the driver's bounded parse queue
+    session_updates_dropped: Arc<AtomicU64>,
+
     /// Cancellation token for stopping tasks
     cancel: CancellationToken,
 }
@@ -48,13 +78,27 @@ impl LiveConnection {
     pub async fn connect() -> Result<Self> {
         info!("Connecting to iRacing live telemetry");
 
-        // Create provider and extract metadata
         let provider = LiveProvider::new()?;
+        Ok(Self::from_provider(provider, DEFAULT_SESSION_CHANNEL_CAPACITY))
+    }
+
+    /// Configure connection behaviour before connecting.
+    ///
+    /// See [`LiveConnectionBuilder`] for the available options.
+    pub fn builder() -> LiveConnectionBuilder {
+        LiveConnectionBuilder::default()
+    }
+
+    /// Spawn driver tasks for an already-connected provider.
+    fn from_provider(provider: LiveProvider, session_capacity: usize) -> Self {
+        // Extract metadata before handing the provider to the driver
         let schema = provider.schema();
         let source_hz = provider.tick_rate();
+        let status = provider.status_receiver();
+        let stats = provider.stats_receiver();
 
         // Spawn driver tasks - they will wait for iRacing to start
-        let channels = Driver::spawn(provider);
+        let channels = Driver::spawn_with_capacity(provider, session_capacity);
 
         // Don't wait for frames here - let the streams handle waiting
         // This allows the connection to be established even if iRacing isn't
@@ -62,31 +106,33 @@ impl LiveConnection {
 
         info!("Live connection established ({}Hz) - waiting for iRacing session", source_hz);
 
-        Ok(Self {
+        Self {
+            poller: FramePoller::new(channels.frames.clone()),
             frames: channels.frames,
             sessions: channels.sessions,
+            session_yaml: channels.session_yaml,
             schema,
             source_hz,
+            status,
+            stats,
+            session_updates_dropped: channels.session_updates_dropped,
+            computed: Arc::default(),
             cancel: channels.cancel,
-        })
+        }
     }
 
-    /// Subscribe to telemetry frames
-    pub fn subscribe<T>(&self, rate: UpdateRate) -> impl Stream<Item = T> + 'static
-    where
-        T: FrameAdapter + Send + 'static,
-    {
-        // Validate schema once at subscription time
-        let validation = T::validate_schema(&self.schema).expect("Schema validation failed");
-
-        // Create base frame stream from watch channel
+    /// Raw frame packets from the driver, waiting for iRacing if no frame has arrived yet.
+    ///
+    /// Computed channels registered with `add_computed` are attached to each packet.
+    fn frame_packets(&self) -> impl Stream<Item = Arc<FramePacket>> + 'static {
         // Important: WatchStream yields the current value immediately. If no frames
         // have arrived yet, this will be None. We must handle this carefully to avoid
         // the stream appearing to end when it's actually just waiting for data.
         //
         // We skip initial None values to keep the stream alive while waiting for iRacing.
         // Once we receive our first frame, any subsequent None indicates the provider stopped.
-        let frames = WatchStream::new(self.frames.clone())
+        let computed = Arc::clone(&self.computed);
+        WatchStream::new(self.frames.clone())
             .skip_while(|opt| {
                 // Skip leading None values (waiting for iRacing)
                 let is_none = opt.is_none();
@@ -97,22 +143,298 @@ impl LiveConnection {
                 let is_some = opt.is_some();
                 async move { is_some }
             })
-            .filter_map(|opt| async move { opt });
+            .filter_map(|opt| async move { opt })
+            .map(move |packet| attach_computed(packet, &computed))
+    }
 
-        // Apply rate control and adaptation
-        let effective_rate = rate.normalize(self.source_hz);
+    /// Register a channel computed from each frame's raw variables.
+    ///
+    /// The channel is readable by name through [`FramePacket::get_value`] and
+    /// [`FramePacket::to_map`] on every packet delivered to adapters, and is listed
+    /// by [`computed_channels`](Self::computed_channels). It is evaluated only
+    /// when read. Subscriptions made before the call do not see it. A name that
+    /// matches a telemetry variable is rejected.
+    pub fn add_computed<F>(&mut self, name: impl Into<String>, compute: F) -> Result<()>
+    where
+        F: Fn(&FramePacket) -> Value + Send + Sync + 'static,
+    {
+        add_computed_channel(&mut self.computed, &self.schema, name.into(), compute)
+    }
 
-        match effective_rate {
-            UpdateRate::Native => {
-                // Direct adaptation, no throttling
-                frames.map(move |packet| T::adapt(&packet, &validation)).boxed()
-            }
-            UpdateRate::Max(hz) => {
-                // Throttle then adapt
-                let interval = Duration::from_secs_f64(1.0 / hz as f64);
-                frames.throttle(interval).map(move |packet| T::adapt(&packet, &validation)).boxed()
-            }
-        }
+    /// Computed channels registered with [`add_computed`](Self::add_computed)
+    pub fn computed_channels(&self) -> &ComputedChannels {
+        &self.computed
+    }
+
+    /// Validate `T` against the current schema, keeping the plan in step with reconnects.
+    ///
+    /// Panics if the current schema does not satisfy `T`. When a reconnect changes
+    /// a field `T` reads, the plan is validated again; frames are skipped while the
+    /// new schema does not satisfy `T`.
+    fn revalidating_plan<T>(
+        &self,
+    ) -> Revalidator<
+        AdapterValidation,
+        impl FnMut(&VariableSchema) -> Result<AdapterValidation> + Send + 'static,
+    >
+    where
+        T: FrameAdapter + 'static,
+    {
+        let validation = T::validate_schema(&self.schema).expect("Schema validation failed");
+
+        Revalidator::with_plan(Arc::clone(&self.schema), validation, |schema: &VariableSchema| {
+            T::validate_schema(schema)
+        })
+    }
+
+    /// Subscribe to telemetry frames
+    ///
+    /// Panics if the current schema does not satisfy `T`. If a reconnect changes
+    /// the variable layout, `T` is validated again and frames are skipped while
+    /// the new schema does not satisfy it.
+    pub fn subscribe<T>(&self, rate: UpdateRate) -> impl Stream<Item = T> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        // Validate schema at subscription time
+        let mut plan = self.revalidating_plan::<T>();
+
+        // Create base frame stream from watch channel
+        let frames = self.frame_packets();
+
+        // Apply rate control, then adapt
+        apply_rate(frames, rate, self.source_hz).filter_map(move |packet| {
+            future::ready(plan.plan_for(&packet.schema).map(|v| T::adapt(&packet, v)))
+        })
+    }
+
+    /// Subscribe to the newest frame only, with no rate control.
+    ///
+    /// Frames are read from a single-slot watch channel when the stream is polled,
+    /// so frames published while the consumer is busy overwrite each other: a
+    /// slow consumer skips straight to the latest frame instead of working
+    /// through a backlog, and memory stays bounded at one frame. Processing time
+    /// sets the pace. [`subscribe`](Self::subscribe) reads the same channel but
+    /// paces delivery by its [`UpdateRate`]; use it when a fixed cadence matters
+    /// more than freshness.
+    pub fn subscribe_latest<T>(&self) -> impl Stream<Item = T> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        let mut plan = self.revalidating_plan::<T>();
+
+        self.frame_packets().filter_map(move |packet| {
+            future::ready(plan.plan_for(&packet.schema).map(|v| T::adapt(&packet, v)))
+        })
+    }
+
+    /// Subscribe to telemetry frames, surviving iRacing restarts and session reloads.
+    ///
+    /// When iRacing drops its connected status the provider re-opens the shared
+    /// memory mapping once telemetry is valid again, and this stream re-runs
+    /// `T::validate_schema` whenever the variable layout changes (e.g. after
+    /// switching cars). Unlike [`subscribe`](Self::subscribe), it does not panic
+    /// when the current schema does not satisfy `T`; frames are skipped until a
+    /// compatible schema arrives.
+    pub fn subscribe_resilient<T>(&self, rate: UpdateRate) -> impl Stream<Item = T> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        let frames = self.frame_packets();
+
+        adapt_revalidating::<T, _>(apply_rate(frames, rate, self.source_hz))
+    }
+
+    /// Subscribe to frames from an adapter selected by name at runtime.
+    ///
+    /// The adapter is looked up in `registry` and validated against the current
+    /// schema; frames are yielded type-erased for the caller to downcast. Unlike
+    /// [`subscribe`](Self::subscribe), an unknown name or incompatible schema is
+    /// returned as an error rather than a panic. The adapter is bound again when
+    /// a reconnect changes a variable it reads.
+    pub fn subscribe_dynamic(
+        &self,
+        registry: &AdapterRegistry,
+        name: &str,
+        rate: UpdateRate,
+    ) -> Result<impl Stream<Item = DynamicValue> + 'static> {
+        let adapter = registry.bind(name, &self.schema)?;
+        let (registry, name) = (registry.clone(), name.to_string());
+        let mut plan = Revalidator::with_plan(Arc::clone(&self.schema), adapter, move |schema| {
+            registry.bind(&name, schema)
+        });
+        let frames = self.frame_packets();
+
+        Ok(apply_rate(frames, rate, self.source_hz).filter_map(move |packet| {
+            future::ready(plan.plan_for(&packet.schema).map(|adapter| adapter.adapt(&packet)))
+        }))
+    }
+
+    /// Subscribe to a handful of variables by name, without defining an adapter.
+    ///
+    /// Only the named variables are read from each frame and yielded as [`Value`]s
+    /// keyed by name. Names are checked against the schema up front; an unknown
+    /// name is returned as an error with suggestions, and the names are checked
+    /// again when a reconnect changes the layout. Lighter than
+    /// [`DynamicFrame`](crate::DynamicFrame) when only a few channels are needed.
+    ///
+    /// ```ignore
+    /// let mut fields = connection.subscribe_fields(&["Speed", "RPM"], UpdateRate::Max(10))?;
+    /// ```
+    pub fn subscribe_fields(
+        &self,
+        names: &[&str],
+        rate: UpdateRate,
+    ) -> Result<impl Stream<Item = HashMap<String, Value>> + 'static> {
+        let validation = AdapterValidation::for_fields(names, &self.schema)?;
+        let names: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+        let mut plan =
+            Revalidator::with_plan(Arc::clone(&self.schema), validation, move |schema| {
+                let names: Vec<&str> = names.iter().map(String::as_str).collect();
+                AdapterValidation::for_fields(&names, schema)
+            });
+        let frames = self.frame_packets();
+
+        Ok(apply_rate(frames, rate, self.source_hz).filter_map(move |packet| {
+            future::ready(plan.plan_for(&packet.schema).map(|v| v.extract_values(&packet)))
+        }))
+    }
+
+    /// Subscribe to telemetry frames paired with their [`FrameMeta`].
+    ///
+    /// Same rate control as [`subscribe`](Self::subscribe), but each typed frame is
+    /// delivered with its tick, session version and session time so it can be
+    /// correlated with [`session_updates`](Self::session_updates).
+    pub fn subscribe_with_meta<T>(
+        &self,
+        rate: UpdateRate,
+    ) -> impl Stream<Item = (FrameMeta, T)> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        let mut plan = self.revalidating_plan::<T>();
+        let adapt = move |packet: Arc<FramePacket>| {
+            let item = plan.plan_for(&packet.schema).map(|v| (packet.meta(), T::adapt(&packet, v)));
+            future::ready(item)
+        };
+
+        apply_rate(self.frame_packets(), rate, self.source_hz).filter_map(adapt)
+    }
+
+    /// Subscribe to sliding windows of the last `n` frames, oldest first.
+    ///
+    /// Built on [`subscribe_with_meta`](Self::subscribe_with_meta), so windows hold
+    /// consecutive frames *after* rate control. An `n` below 2 is treated as 2.
+    /// Use [`FrameWindow::elapsed`] or [`FrameWindow::rate_of`] to turn differences
+    /// into rates.
+    pub fn subscribe_windowed<T>(
+        &self,
+        rate: UpdateRate,
+        n: usize,
+    ) -> impl Stream<Item = FrameWindow<T>> + 'static
+    where
+        T: FrameAdapter + Clone + Send + 'static,
+    {
+        let source_hz = self.source_hz;
+        self.subscribe_with_meta::<T>(rate)
+            .windows(n.max(2))
+            .map(move |frames| FrameWindow { frames, source_hz })
+    }
+
+    /// Subscribe to every `n`th frame after rate control.
+    ///
+    /// Thin wrapper over [`subscribe`](Self::subscribe); an `n` of 0 is treated as 1.
+    pub fn subscribe_every<T>(&self, rate: UpdateRate, n: usize) -> impl Stream<Item = T> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        self.subscribe::<T>(rate).every(n)
+    }
+
+    /// Subscribe to frames, yielding only when `key` differs from the previous frame.
+    ///
+    /// Thin wrapper over [`subscribe`](Self::subscribe). The first frame is always yielded.
+    pub fn subscribe_on_change<T, K>(
+        &self,
+        rate: UpdateRate,
+        key: fn(&T) -> K,
+    ) -> impl Stream<Item = T> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+        K: PartialEq + Send + 'static,
+    {
+        self.subscribe::<T>(rate).on_change(key)
+    }
+
+    /// Stream of completed laps with their lap time and fuel used.
+    ///
+    /// Watches `LapCompleted` and `LapLastLapTime` on unthrottled frames and emits one
+    /// [`LapSummary`] per lap, once the new lap time has been published.
+    pub fn lap_completions(&self) -> impl Stream<Item = LapSummary> + 'static {
+        lap_completions(self.frame_packets())
+    }
+
+    /// Stream of race phase changes derived from `SessionFlags`.
+    ///
+    /// Watches unthrottled frames and emits the current [`RaceState`] once it has
+    /// held for 30 ticks (half a second at 60Hz), then each debounced transition.
+    pub fn race_state_updates(&self) -> impl Stream<Item = RaceState> + 'static {
+        race_state_updates(self.frame_packets())
+    }
+
+    /// Stream of incidents picked up by the player's car.
+    ///
+    /// Watches `PlayerIncidents` on unthrottled frames and emits one
+    /// [`IncidentEvent`] per new report, with the running incident count.
+    pub fn incident_updates(&self) -> impl Stream<Item = IncidentEvent> + 'static {
+        incident_updates(self.frame_packets())
+    }
+
+    /// Adapt the newest frame if one arrived since the last call, without blocking.
+    ///
+    /// For synchronous loops (e.g. a game engine's per-frame callback) that cannot
+    /// await a stream. Returns `Ok(None)` when no new frame is available; frames that
+    /// arrive between calls are skipped. The adapter is validated on first use and
+    /// again only when the schema changes.
+    pub fn poll_latest<T>(&mut self) -> Result<Option<T>>
+    where
+        T: FrameAdapter + 'static,
+    {
+        self.poller.poll_latest::<T>(&self.computed)
+    }
+
+    /// Stream of connection state transitions, starting with the current state.
+    ///
+    /// Driven by the shared-memory `status` bitfield: [`ConnectionStatus::InSession`]
+    /// while a session is loaded, [`ConnectionStatus::Connected`] while iRacing is
+    /// running without one, and [`ConnectionStatus::Disconnected`] once the provider
+    /// gives up waiting or the connection is dropped. Ends after `Disconnected`.
+    pub fn status_updates(&self) -> impl Stream<Item = ConnectionStatus> + 'static {
+        WatchStream::new(self.status.clone())
+    }
+
+    /// Current connection state
+    pub fn status(&self) -> ConnectionStatus {
+        *self.status.borrow()
+    }
+
+    /// Snapshot of frame delivery since the connection was opened.
+    ///
+    /// Counts frames read from shared memory and frames missed between them
+    /// (gaps in iRacing's tick count), so a consumer can tell whether the
+    /// provider keeps up with the source rate. Frames skipped by a subscriber's
+    /// rate control are not counted.
+    pub fn stats(&self) -> StreamStats {
+        *self.stats.borrow()
+    }
+
+    /// Session updates dropped because the session parse queue was full.
+    ///
+    /// A non-zero count means sessions changed faster than they were parsed and
+    /// intermediate versions were skipped; see
+    /// [`LiveConnectionBuilder::session_channel_capacity`].
+    pub fn session_updates_dropped(&self) -> u64 {
+        self.session_updates_dropped.load(Ordering::Relaxed)
     }
 
     /// Get session updates as a stream
@@ -131,11 +453,57 @@ impl LiveConnection {
         WatchStream::new(self.sessions.clone()).filter_map(|opt| async move { opt })
     }
 
+    /// Session updates, coalesced to at most one per `min_interval`.
+    ///
+    /// Lossy by design: updates that arrive within an interval replace each other
+    /// and only the latest is yielded, so consumers are protected from update
+    /// storms (e.g. drivers joining during grid formation). The first update is
+    /// yielded immediately. A zero interval is treated as one millisecond.
+    pub fn session_updates_coalesced(
+        &self,
+        min_interval: Duration,
+    ) -> impl Stream<Item = Arc<SessionInfo>> + 'static {
+        self.session_updates().latest_at(min_interval.max(Duration::from_millis(1)))
+    }
+
+    /// Stream of meaningful session info changes.
+    ///
+    /// Built on [`session_updates`](Self::session_updates), but yields a [`SessionDiff`]
+    /// against the previous snapshot and drops updates where no tracked field changed.
+    /// The first snapshot is the baseline; read it with [`current_session`](Self::current_session).
+    pub fn session_changes(&self) -> impl Stream<Item = SessionDiff> + 'static {
+        session_changes(self.session_updates())
+    }
+
+    /// Stream of session info changes matching `predicate`.
+    ///
+    /// ```ignore
+    /// let joins = connection.session_changes_filtered(|diff| diff.drivers_changed());
+    /// ```
+    pub fn session_changes_filtered<F>(
+        &self,
+        predicate: F,
+    ) -> impl Stream<Item = SessionDiff> + 'static
+    where
+        F: Fn(&SessionDiff) -> bool + Send + 'static,
+    {
+        self.session_changes().filter(move |diff| future::ready(predicate(diff)))
+    }
+
     /// Get current session info (if any)
     pub fn current_session(&self) -> Option<Arc<SessionInfo>> {
         self.sessions.borrow().clone()
     }
 
+    /// Preprocessed YAML that produced the latest session update (if available).
+    ///
+    /// The exact parser input, for reproducing parse mismatches. Updated just before
+    /// [`current_session`](Self::current_session), so it may briefly be one update
+    /// ahead of it but never behind.
+    pub fn raw_session_yaml(&self) -> Option<String> {
+        self.session_yaml.borrow().as_deref().map(str::to_string)
+    }
+
     /// Get the source telemetry frequency
     pub fn source_hz(&self) -> f64 {
         self.source_hz
@@ -145,6 +513,46 @@ impl LiveConnection {
     pub fn schema(&self) -> &VariableSchema {
         &self.schema
     }
+
+    /// List every variable this source provides, sorted by name.
+    ///
+    /// Includes units, descriptions and element counts for building channel pickers.
+    pub fn available_variables(&self) -> Vec<&VariableInfo> {
+        self.schema.sorted_variables()
+    }
+}
+
+#[cfg(windows)]
+impl TelemetrySource for LiveConnection {
+    fn subscribe<T>(&self, rate: UpdateRate) -> impl Stream<Item = T> + Send + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        LiveConnection::subscribe(self, rate)
+    }
+
+    fn session_updates(&self) -> impl Stream<Item = Arc<SessionInfo>> + Send + 'static {
+        LiveConnection::session_updates(self)
+    }
+
+    fn current_session(&self) -> Option<Arc<SessionInfo>> {
+        LiveConnection::current_session(self)
+    }
+
+    fn source_hz(&self) -> f64 {
+        LiveConnection::source_hz(self)
+    }
+
+    fn schema(&self) -> &VariableSchema {
+        LiveConnection::schema(self)
+    }
+}
+
+#[cfg(windows)]
+impl SchemaProvider for LiveConnection {
+    fn get_schema(&self) -> &VariableSchema {
+        &self.schema
+    }
 }
 
 #[cfg(windows)]
@@ -156,6 +564,121 @@ impl Drop for LiveConnection {
     }
 }
 
+/// Builder for [`LiveConnection`] with configurable wait behaviour.
+///
+/// By default the builder behaves like [`LiveConnection::connect`]: a single
+/// attempt that fails immediately if iRacing's shared memory is not available.
+/// Set a [`connect_timeout`](Self::connect_timeout) to keep retrying instead.
+///
+/// ```rust,no_run
+/// use pitwall::LiveConnection;
+/// use std::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() -> pitwall::Result<()> {
+/// // Wait up to two minutes for the user to load into a session
+/// let connection = LiveConnection::builder()
+///     .connect_timeout(Duration::from_secs(120))
+///     .retry_interval(Duration::from_secs(1))
+///     .require_session(true)
+///     .connect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiveConnectionBuilder {
+    connect_timeout: Duration,
+    retry_interval: Duration,
+    require_session: bool,
+    session_channel_capacity: usize,
+}
+
+impl Default for LiveConnectionBuilder {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::ZERO,
+            retry_interval: Duration::from_millis(500),
+            require_session: false,
+            session_channel_capacity: DEFAULT_SESSION_CHANNEL_CAPACITY,
+        }
+    }
+}
+
+impl LiveConnectionBuilder {
+    /// How long to keep retrying before giving up with [`TelemetryError::Timeout`].
+    ///
+    /// A zero timeout (the default) makes a single attempt and returns its error.
+    ///
+    /// [`TelemetryError::Timeout`]: crate::TelemetryError::Timeout
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Delay between connection attempts (default 500ms).
+    pub fn retry_interval(mut self, interval: Duration) -> Self {
+        self.retry_interval = interval;
+        self
+    }
+
+    /// Wait until iRacing has a session loaded, not just shared memory present.
+    pub fn require_session(mut self, require: bool) -> Self {
+        self.require_session = require;
+        self
+    }
+
+    /// Session YAML updates queued for parsing before the oldest is dropped
+    /// (default 4, minimum 1).
+    ///
+    /// Session info is parsed off the telemetry loop, which never waits on the
+    /// parser. A smaller queue keeps session info closer to the latest version
+    /// when parsing is slow; a larger one skips fewer intermediate updates.
+    /// Drops are counted by `LiveConnection::session_updates_dropped`.
+    pub fn session_channel_capacity(mut self, capacity: usize) -> Self {
+        self.session_channel_capacity = capacity;
+        self
+    }
+
+    /// Connect using the configured options.
+    #[cfg(windows)]
+    pub async fn connect(self) -> Result<LiveConnection> {
+        info!(
+            timeout = ?self.connect_timeout,
+            require_session = self.require_session,
+            "Connecting to iRacing live telemetry"
+        );
+
+        let deadline = tokio::time::Instant::now() + self.connect_timeout;
+
+        loop {
+            match LiveProvider::new() {
+                Ok(provider) if !self.require_session || provider.is_session_active() => {
+                    let capacity = self.session_channel_capacity;
+                    return Ok(LiveConnection::from_provider(provider, capacity));
+                }
+                Ok(_) => debug!("Shared memory available but no session loaded yet"),
+                // Fail fast keeps the original error for callers of `connect()`
+                Err(e) if self.connect_timeout.is_zero() => return Err(e),
+                Err(e) => debug!("iRacing shared memory not available: {}", e),
+            }
+
+            if tokio::time::Instant::now() + self.retry_interval > deadline {
+                return Err(crate::TelemetryError::Timeout { duration: self.connect_timeout });
+            }
+            tokio::time::sleep(self.retry_interval).await;
+        }
+    }
+
+    /// Attempt to connect on non-Windows platforms.
+    ///
+    /// This always returns an error as live telemetry is only available on Windows.
+    #[cfg(not(windows))]
+    pub async fn connect(self) -> Result<LiveConnection> {
+        Err(crate::TelemetryError::unsupported_platform("Live telemetry", "Windows"))
+    }
+}
+
 // Non-Windows stub implementation
 #[cfg(not(windows))]
 pub struct LiveConnection {
@@ -171,4 +694,11 @@ impl LiveConnection {
     pub async fn connect() -> Result<Self> {
         Err(crate::TelemetryError::unsupported_platform("Live telemetry", "Windows"))
     }
+
+    /// Configure connection behaviour before connecting.
+    ///
+    /// See [`LiveConnectionBuilder`] for the available options.
+    pub fn builder() -> LiveConnectionBuilder {
+        LiveConnectionBuilder::default()
+    }
 }
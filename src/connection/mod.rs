@@ -1,7 +1,102 @@
 //! Connection types for live and replay telemetry
 
+use futures::Stream;
+use std::sync::Arc;
+
+use crate::types::{ComputedChannels, FramePacket, Value};
+use crate::{FrameAdapter, Result, SessionInfo, TelemetryError, UpdateRate, VariableSchema};
+
 pub mod live;
+mod poll;
 pub mod replay;
+mod stats;
+mod status;
+
+#[cfg(any(windows, test))]
+pub(crate) use stats::StatsCollector;
+pub use stats::StreamStats;
+pub use status::ConnectionStatus;
 
 #[cfg(test)]
 mod tests;
+
+/// Telemetry source implemented by both live and replay connections.
+///
+/// Generic code taking `&impl TelemetrySource` runs unchanged against live input or
+/// an IBT replay, so analysis can be shared between tests and production. During
+/// development, [`Pitwall::connect_mock`](crate::Pitwall::connect_mock) provides a
+/// pseudo-live replay that is swapped for a live connection on Windows.
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use pitwall::{DynamicFrame, Pitwall, TelemetrySource, UpdateRate};
+///
+/// async fn peak_speed(source: &impl TelemetrySource) -> f32 {
+///     let frames = source.subscribe::<DynamicFrame>(UpdateRate::Native);
+///     frames
+///         .fold(0.0f32, |peak, frame| async move { peak.max(frame.f32("Speed").unwrap_or(0.0)) })
+///         .await
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> pitwall::Result<()> {
+/// let replay = Pitwall::open("race.ibt").await?;
+/// println!("Peak speed: {}", peak_speed(&replay).await);
+/// # Ok(())
+/// # }
+/// ```
+pub trait TelemetrySource {
+    /// Subscribe to telemetry frames adapted to `T`
+    fn subscribe<T>(&self, rate: UpdateRate) -> impl Stream<Item = T> + Send + 'static
+    where
+        T: FrameAdapter + Send + 'static;
+
+    /// Get session updates as a stream
+    fn session_updates(&self) -> impl Stream<Item = Arc<SessionInfo>> + Send + 'static;
+
+    /// Get current session info (if any)
+    fn current_session(&self) -> Option<Arc<SessionInfo>>;
+
+    /// Get the source telemetry frequency
+    fn source_hz(&self) -> f64;
+
+    /// Get the variable schema
+    fn schema(&self) -> &VariableSchema;
+}
+
+/// Register a computed channel in a connection's channel set.
+///
+/// Names that collide with a raw variable are rejected, since the raw variable
+/// would always win the lookup. Subscriptions hold the set they started with, so
+/// the set is copied on write when a stream still shares it.
+pub(crate) fn add_computed_channel<F>(
+    computed: &mut Arc<ComputedChannels>,
+    schema: &VariableSchema,
+    name: String,
+    compute: F,
+) -> Result<()>
+where
+    F: Fn(&FramePacket) -> Value + Send + Sync + 'static,
+{
+    if schema.has_variable(&name) {
+        return Err(TelemetryError::schema_validation_error(
+            format!("Computed channel '{}' shadows a telemetry variable", name),
+            None,
+            None,
+        ));
+    }
+    Arc::make_mut(computed).insert(name, compute);
+    Ok(())
+}
+
+/// Attach a connection's computed channels to a packet, leaving it untouched when
+/// none are registered
+pub(crate) fn attach_computed(
+    packet: Arc<FramePacket>,
+    computed: &Arc<ComputedChannels>,
+) -> Arc<FramePacket> {
+    if computed.is_empty() {
+        return packet;
+    }
+    Arc::new((*packet).clone().with_computed(Arc::clone(computed)))
+}
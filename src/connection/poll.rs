@@ -0,0 +1,64 @@
+//! Non-blocking frame access for synchronous render loops
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+use crate::adapters::{AdapterValidation, FrameAdapter};
+use crate::types::{ComputedChannels, FramePacket};
+
+use super::attach_computed;
+use crate::{Result, TelemetryError, VariableSchema};
+
+/// Latest-frame poller backing `poll_latest` on the connections.
+///
+/// Holds its own watch receiver, so polling does not affect stream subscriptions,
+/// and caches one validation per adapter type until the schema changes.
+pub(crate) struct FramePoller {
+    frames: watch::Receiver<Option<Arc<FramePacket>>>,
+    validations: HashMap<TypeId, (Arc<VariableSchema>, AdapterValidation)>,
+}
+
+impl FramePoller {
+    pub(crate) fn new(mut frames: watch::Receiver<Option<Arc<FramePacket>>>) -> Self {
+        // A frame already in the channel counts as new for the first poll
+        frames.mark_changed();
+        Self { frames, validations: HashMap::new() }
+    }
+
+    /// Adapt the newest frame if one arrived since the last poll, without waiting.
+    pub(crate) fn poll_latest<T>(&mut self, computed: &Arc<ComputedChannels>) -> Result<Option<T>>
+    where
+        T: FrameAdapter + 'static,
+    {
+        match self.frames.has_changed() {
+            Ok(true) => {}
+            Ok(false) => return Ok(None),
+            Err(_) => {
+                return Err(TelemetryError::Connection {
+                    reason: "Telemetry source has stopped".to_string(),
+                    source: None,
+                });
+            }
+        }
+
+        let Some(packet) = self.frames.borrow_and_update().clone() else {
+            return Ok(None);
+        };
+        let packet = attach_computed(packet, computed);
+
+        let key = TypeId::of::<T>();
+        let stale = self
+            .validations
+            .get(&key)
+            .is_none_or(|(schema, _)| !Arc::ptr_eq(schema, &packet.schema));
+        if stale {
+            let validation = T::validate_schema(&packet.schema)?;
+            self.validations.insert(key, (Arc::clone(&packet.schema), validation));
+        }
+        let (_, validation) = &self.validations[&key];
+
+        Ok(Some(T::adapt(&packet, validation)))
+    }
+}
@@ -1,6 +1,7 @@
 //! Replay connection for IBT files
 
-use futures::{Stream, StreamExt};
+use futures::{Stream, StreamExt, future};
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
@@ -9,12 +10,22 @@ use tokio_stream::wrappers::WatchStream;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
+use super::poll::FramePoller;
+use super::{TelemetrySource, add_computed_channel, attach_computed};
+use crate::adapters::{AdapterRegistry, AdapterValidation, DynamicValue};
 use crate::driver::Driver;
 use crate::provider::Provider;
-use crate::providers::replay::ReplayProvider;
-use crate::stream::ThrottleExt;
-use crate::types::{FramePacket, UpdateRate};
-use crate::{FrameAdapter, Result, SessionInfo, VariableSchema};
+use crate::providers::replay::{ReplayProvider, ReplaySeek};
+use crate::schema::session::SessionDiff;
+use crate::stream::{
+    FilterExt, IncidentEvent, LapSummary, Limited, RaceState, ReplayProgress, ThrottleExt,
+    apply_rate, incident_updates, lap_completions, race_state_updates, replay_progress,
+    session_changes,
+};
+use crate::types::{ComputedChannels, FrameMeta, FramePacket, FrameWindow, UpdateRate, Value};
+use crate::{
+    FrameAdapter, Result, SchemaProvider, SessionInfo, TelemetryError, VariableInfo, VariableSchema,
+};
 
 /// Replay connection from IBT file
 pub struct ReplayConnection {
@@ -24,12 +35,36 @@ pub struct ReplayConnection {
     /// Session watch receiver
     sessions: watch::Receiver<Option<Arc<SessionInfo>>>,
 
+    /// Preprocessed YAML behind the latest session update
+    session_yaml: watch::Receiver<Option<Arc<str>>>,
+
     /// Variable schema
     schema: Arc<VariableSchema>,
 
     /// Source frequency
     source_hz: f64,
 
+    /// Total frames in the replay
+    total_frames: usize,
+
+    /// `SessionTime` of the first and last frames, if the file records it
+    session_time_range: Option<(f64, f64)>,
+
+    /// Seek requests for the replay provider
+    seek: watch::Sender<Option<ReplaySeek>>,
+
+    /// Playback speed for the replay provider
+    speed: watch::Sender<f64>,
+
+    /// Session reported as current, overriding the file's `CurrentSessionNum`
+    session_num: Option<i32>,
+
+    /// Latest-frame poller for `poll_latest`
+    poller: FramePoller,
+
+    /// Computed channels attached to every delivered packet
+    computed: Arc<ComputedChannels>,
+
     /// Cancellation token for stopping tasks
     cancel: CancellationToken,
 }
@@ -44,9 +79,14 @@ impl ReplayConnection {
         info!("Opening IBT file: {}", path.display());
 
         // Create provider and extract metadata
-        let provider = ReplayProvider::new(path)?;
+        let (seek, seek_rx) = watch::channel(None);
+        let (speed, speed_rx) = watch::channel(1.0);
+        let provider =
+            ReplayProvider::new(path)?.with_seek_receiver(seek_rx).with_speed_receiver(speed_rx);
         let schema = provider.schema();
         let source_hz = provider.tick_rate();
+        let total_frames = provider.total_frames();
+        let session_time_range = provider.session_time_range().ok();
 
         // Spawn driver tasks
         let channels = Driver::spawn(provider);
@@ -71,14 +111,55 @@ impl ReplayConnection {
         info!("Replay connection opened ({}Hz)", source_hz);
 
         Ok(Self {
+            poller: FramePoller::new(channels.frames.clone()),
             frames: channels.frames,
             sessions: channels.sessions,
+            session_yaml: channels.session_yaml,
             schema,
             source_hz,
+            total_frames,
+            session_time_range,
+            seek,
+            speed,
+            session_num: None,
+            computed: Arc::default(),
             cancel: channels.cancel,
         })
     }
 
+    /// Raw frame packets from the driver, with computed channels attached
+    fn frame_packets(&self) -> impl Stream<Item = Arc<FramePacket>> + 'static {
+        let computed = Arc::clone(&self.computed);
+        WatchStream::new(self.frames.clone())
+            .filter_map(|opt| async move { opt })
+            .map(move |packet| attach_computed(packet, &computed))
+    }
+
+    /// Register a channel computed from each frame's raw variables.
+    ///
+    /// The channel is readable by name through [`FramePacket::get_value`] and
+    /// [`FramePacket::to_map`] on every packet delivered to adapters, and is listed
+    /// by [`computed_channels`](Self::computed_channels). It is evaluated only
+    /// when read. Subscriptions made before the call do not see it. A name that
+    /// matches a telemetry variable is rejected.
+    ///
+    /// ```ignore
+    /// connection.add_computed("SpeedKph", |frame: &FramePacket| {
+    ///     Value::Float32(frame.get_f32("Speed").unwrap_or(0.0) * 3.6)
+    /// })?;
+    /// ```
+    pub fn add_computed<F>(&mut self, name: impl Into<String>, compute: F) -> Result<()>
+    where
+        F: Fn(&FramePacket) -> Value + Send + Sync + 'static,
+    {
+        add_computed_channel(&mut self.computed, &self.schema, name.into(), compute)
+    }
+
+    /// Computed channels registered with [`add_computed`](Self::add_computed)
+    pub fn computed_channels(&self) -> &ComputedChannels {
+        &self.computed
+    }
+
     /// Subscribe to telemetry frames
     pub fn subscribe<T>(&self, rate: UpdateRate) -> impl Stream<Item = T> + 'static
     where
@@ -88,33 +169,279 @@ impl ReplayConnection {
         let validation = T::validate_schema(&self.schema).expect("Schema validation failed");
 
         // Create base frame stream from watch channel
-        let frames = WatchStream::new(self.frames.clone()).filter_map(|opt| async move { opt });
+        let frames = self.frame_packets();
+
+        // Apply rate control, then adapt
+        apply_rate(frames, rate, self.source_hz).map(move |packet| T::adapt(&packet, &validation))
+    }
 
-        // Apply rate control and adaptation
-        let effective_rate = rate.normalize(self.source_hz);
+    /// Subscribe to the newest frame only, with no rate control.
+    ///
+    /// Frames are read from a single-slot watch channel when the stream is polled,
+    /// so frames published while the consumer is busy overwrite each other: a
+    /// slow consumer skips straight to the latest frame instead of working
+    /// through a backlog, and memory stays bounded at one frame. Processing time
+    /// sets the pace. [`subscribe`](Self::subscribe) reads the same channel but
+    /// paces delivery by its [`UpdateRate`]; use it when a fixed cadence matters
+    /// more than freshness.
+    pub fn subscribe_latest<T>(&self) -> impl Stream<Item = T> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        let validation = T::validate_schema(&self.schema).expect("Schema validation failed");
 
-        match effective_rate {
-            UpdateRate::Native => {
-                // Direct adaptation, no throttling
-                frames.map(move |packet| T::adapt(&packet, &validation)).boxed()
-            }
-            UpdateRate::Max(hz) => {
-                // Throttle then adapt
-                let interval = Duration::from_secs_f64(1.0 / hz as f64);
-                frames.throttle(interval).map(move |packet| T::adapt(&packet, &validation)).boxed()
-            }
-        }
+        self.frame_packets().map(move |packet| T::adapt(&packet, &validation))
+    }
+
+    /// Subscribe to frames from an adapter selected by name at runtime.
+    ///
+    /// The adapter is looked up in `registry` and validated against the current
+    /// schema; frames are yielded type-erased for the caller to downcast. Unlike
+    /// [`subscribe`](Self::subscribe), an unknown name or incompatible schema is
+    /// returned as an error rather than a panic.
+    pub fn subscribe_dynamic(
+        &self,
+        registry: &AdapterRegistry,
+        name: &str,
+        rate: UpdateRate,
+    ) -> Result<impl Stream<Item = DynamicValue> + 'static> {
+        let adapter = registry.bind(name, &self.schema)?;
+        let frames = self.frame_packets();
+
+        Ok(apply_rate(frames, rate, self.source_hz).map(move |packet| adapter.adapt(&packet)))
+    }
+
+    /// Subscribe to a handful of variables by name, without defining an adapter.
+    ///
+    /// Only the named variables are read from each frame and yielded as [`Value`]s
+    /// keyed by name. Names are checked against the schema up front; an unknown
+    /// name is returned as an error with suggestions. Lighter than
+    /// [`DynamicFrame`](crate::DynamicFrame) when only a few channels are needed.
+    ///
+    /// ```ignore
+    /// let mut fields = connection.subscribe_fields(&["Speed", "RPM"], UpdateRate::Max(10))?;
+    /// ```
+    pub fn subscribe_fields(
+        &self,
+        names: &[&str],
+        rate: UpdateRate,
+    ) -> Result<impl Stream<Item = HashMap<String, Value>> + 'static> {
+        let validation = AdapterValidation::for_fields(names, &self.schema)?;
+        let frames = self.frame_packets();
+
+        Ok(apply_rate(frames, rate, self.source_hz)
+            .map(move |packet| validation.extract_values(&packet)))
+    }
+
+    /// Subscribe to telemetry frames paired with their [`FrameMeta`].
+    ///
+    /// Same rate control as [`subscribe`](Self::subscribe), but each typed frame is
+    /// delivered with its tick, session version and session time so it can be
+    /// correlated with [`session_updates`](Self::session_updates).
+    pub fn subscribe_with_meta<T>(
+        &self,
+        rate: UpdateRate,
+    ) -> impl Stream<Item = (FrameMeta, T)> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        let validation = T::validate_schema(&self.schema).expect("Schema validation failed");
+        let adapt = move |packet: Arc<FramePacket>| (packet.meta(), T::adapt(&packet, &validation));
+        let frames = self.frame_packets();
+
+        apply_rate(frames, rate, self.source_hz).map(adapt)
+    }
+
+    /// Subscribe to sliding windows of the last `n` frames, oldest first.
+    ///
+    /// Built on [`subscribe_with_meta`](Self::subscribe_with_meta), so windows hold
+    /// consecutive frames *after* rate control. An `n` below 2 is treated as 2.
+    /// Use [`FrameWindow::elapsed`] or [`FrameWindow::rate_of`] to turn differences
+    /// into rates.
+    pub fn subscribe_windowed<T>(
+        &self,
+        rate: UpdateRate,
+        n: usize,
+    ) -> impl Stream<Item = FrameWindow<T>> + 'static
+    where
+        T: FrameAdapter + Clone + Send + 'static,
+    {
+        let source_hz = self.source_hz;
+        self.subscribe_with_meta::<T>(rate)
+            .windows(n.max(2))
+            .map(move |frames| FrameWindow { frames, source_hz })
+    }
+
+    /// Subscribe to every `n`th frame after rate control.
+    ///
+    /// Thin wrapper over [`subscribe`](Self::subscribe); an `n` of 0 is treated as 1.
+    pub fn subscribe_every<T>(&self, rate: UpdateRate, n: usize) -> impl Stream<Item = T> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        self.subscribe::<T>(rate).every(n)
+    }
+
+    /// Subscribe to at most `max` frames after rate control, then end.
+    ///
+    /// Thin wrapper over [`subscribe`](Self::subscribe) for sampling and
+    /// deterministic tests. Once the stream has ended, [`Limited::end`] tells
+    /// whether the limit or the end of the file stopped it. Reaching the limit
+    /// releases this subscription's frames; playback carries on for other
+    /// subscribers until the connection is dropped.
+    ///
+    /// ```ignore
+    /// let mut preview = Box::pin(connection.subscribe_limited::<CarData>(UpdateRate::Native, 600));
+    /// while let Some(frame) = preview.next().await { /* ... */ }
+    /// if preview.end() == Some(StreamEnd::SourceEnded) {
+    ///     println!("File shorter than the preview");
+    /// }
+    /// ```
+    pub fn subscribe_limited<T>(
+        &self,
+        rate: UpdateRate,
+        max: usize,
+    ) -> Limited<impl Stream<Item = T> + 'static>
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        self.subscribe::<T>(rate).limit(max)
+    }
+
+    /// Subscribe to frames, yielding only when `key` differs from the previous frame.
+    ///
+    /// Thin wrapper over [`subscribe`](Self::subscribe). The first frame is always yielded.
+    pub fn subscribe_on_change<T, K>(
+        &self,
+        rate: UpdateRate,
+        key: fn(&T) -> K,
+    ) -> impl Stream<Item = T> + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+        K: PartialEq + Send + 'static,
+    {
+        self.subscribe::<T>(rate).on_change(key)
+    }
+
+    /// Stream of completed laps with their lap time and fuel used.
+    ///
+    /// Watches `LapCompleted` and `LapLastLapTime` on unthrottled frames and emits one
+    /// [`LapSummary`] per lap, once the new lap time has been published.
+    pub fn lap_completions(&self) -> impl Stream<Item = LapSummary> + 'static {
+        let frames = self.frame_packets();
+        lap_completions(frames)
+    }
+
+    /// Stream of race phase changes derived from `SessionFlags`.
+    ///
+    /// Watches unthrottled frames and emits the current [`RaceState`] once it has
+    /// held for 30 ticks (half a second at 60Hz), then each debounced transition.
+    pub fn race_state_updates(&self) -> impl Stream<Item = RaceState> + 'static {
+        race_state_updates(self.frame_packets())
+    }
+
+    /// Stream of incidents picked up by the player's car.
+    ///
+    /// Watches `PlayerIncidents` on unthrottled frames and emits one
+    /// [`IncidentEvent`] per new report, with the running incident count.
+    pub fn incident_updates(&self) -> impl Stream<Item = IncidentEvent> + 'static {
+        incident_updates(self.frame_packets())
+    }
+
+    /// Stream of playback progress through the file.
+    ///
+    /// Reads the same frames that drive [`subscribe`](Self::subscribe), so it
+    /// follows playback speed and seeks. Like `subscribe_latest`, it reports the
+    /// newest frame when polled and skips any that arrived in between. The stream
+    /// ends when playback does, possibly before reporting the last frame (the
+    /// end-of-file marker can replace it in the channel), so treat the end of the
+    /// stream as completion.
+    ///
+    /// ```ignore
+    /// let mut progress = connection.progress().latest_at(Duration::from_millis(250));
+    /// while let Some(update) = progress.next().await {
+    ///     bar.set(update.fraction);
+    /// }
+    /// ```
+    pub fn progress(&self) -> impl Stream<Item = ReplayProgress> + 'static {
+        replay_progress(self.frame_packets(), self.total_frames)
+    }
+
+    /// Adapt the newest frame if one arrived since the last call, without blocking.
+    ///
+    /// For synchronous loops (e.g. a game engine's per-frame callback) that cannot
+    /// await a stream. Returns `Ok(None)` when no new frame is available; frames that
+    /// arrive between calls are skipped. The adapter is validated on first use and
+    /// again only when the schema changes.
+    pub fn poll_latest<T>(&mut self) -> Result<Option<T>>
+    where
+        T: FrameAdapter + 'static,
+    {
+        self.poller.poll_latest::<T>(&self.computed)
     }
 
     /// Get session updates as a stream
+    ///
+    /// With [`for_session`](Self::for_session), each update reports the pinned session
+    /// as current.
     pub fn session_updates(&self) -> impl Stream<Item = Arc<SessionInfo>> + 'static {
         // Simply watch the session channel - Driver handles all the complexity!
-        WatchStream::new(self.sessions.clone()).filter_map(|opt| async move { opt })
+        let session_num = self.session_num;
+        WatchStream::new(self.sessions.clone())
+            .filter_map(|opt| async move { opt })
+            .map(move |session| select_session(session, session_num))
+    }
+
+    /// Session updates, coalesced to at most one per `min_interval`.
+    ///
+    /// Lossy by design: updates that arrive within an interval replace each other
+    /// and only the latest is yielded, so consumers are protected from update
+    /// storms (e.g. drivers joining during grid formation). The first update is
+    /// yielded immediately. A zero interval is treated as one millisecond.
+    pub fn session_updates_coalesced(
+        &self,
+        min_interval: Duration,
+    ) -> impl Stream<Item = Arc<SessionInfo>> + 'static {
+        self.session_updates().latest_at(min_interval.max(Duration::from_millis(1)))
+    }
+
+    /// Stream of meaningful session info changes.
+    ///
+    /// Built on [`session_updates`](Self::session_updates), but yields a [`SessionDiff`]
+    /// against the previous snapshot and drops updates where no tracked field changed.
+    /// The first snapshot is the baseline; read it with [`current_session`](Self::current_session).
+    pub fn session_changes(&self) -> impl Stream<Item = SessionDiff> + 'static {
+        session_changes(self.session_updates())
+    }
+
+    /// Stream of session info changes matching `predicate`.
+    ///
+    /// ```ignore
+    /// let joins = connection.session_changes_filtered(|diff| diff.drivers_changed());
+    /// ```
+    pub fn session_changes_filtered<F>(
+        &self,
+        predicate: F,
+    ) -> impl Stream<Item = SessionDiff> + 'static
+    where
+        F: Fn(&SessionDiff) -> bool + Send + 'static,
+    {
+        self.session_changes().filter(move |diff| future::ready(predicate(diff)))
     }
 
     /// Get current session info (if available)
     pub fn current_session(&self) -> Option<Arc<SessionInfo>> {
-        self.sessions.borrow().clone()
+        let session = self.sessions.borrow().clone()?;
+        Some(select_session(session, self.session_num))
+    }
+
+    /// Preprocessed YAML that produced the latest session update (if available).
+    ///
+    /// The exact parser input, for reproducing parse mismatches. Updated just before
+    /// [`current_session`](Self::current_session), so it may briefly be one update
+    /// ahead of it but never behind.
+    pub fn raw_session_yaml(&self) -> Option<String> {
+        self.session_yaml.borrow().as_deref().map(str::to_string)
     }
 
     /// Get the source telemetry frequency
@@ -126,6 +453,145 @@ impl ReplayConnection {
     pub fn schema(&self) -> &VariableSchema {
         &self.schema
     }
+
+    /// List every variable this source provides, sorted by name.
+    ///
+    /// Includes units, descriptions and element counts for building channel pickers.
+    pub fn available_variables(&self) -> Vec<&VariableInfo> {
+        self.schema.sorted_variables()
+    }
+
+    /// Report `session_num` as the current session instead of the file's
+    /// `CurrentSessionNum`.
+    ///
+    /// Applies to [`session_updates`](Self::session_updates), [`current_session`](Self::current_session)
+    /// and everything built on them, so helpers like
+    /// [`SessionInfoData::current_session`](crate::schema::session::SessionInfoData::current_session)
+    /// resolve to the chosen practice, qualifying or race record. Updates that do not
+    /// list `session_num` are reported unchanged.
+    pub fn for_session(mut self, session_num: i32) -> Self {
+        self.session_num = Some(session_num);
+        self
+    }
+
+    /// Set the playback speed and return the connection.
+    ///
+    /// Positive speeds are clamped to `0.1..=10.0`; see
+    /// [`ReplayConnection::set_playback_speed`].
+    pub fn with_playback_speed(self, speed: f64) -> Self {
+        self.set_playback_speed(speed);
+        self
+    }
+
+    /// Set the playback speed multiplier.
+    ///
+    /// Frames are paced by their `SessionTime` deltas (or the tick rate when the
+    /// file has no `SessionTime`), so `1.0` replays in real time and `2.0` at
+    /// double speed. Positive speeds are clamped to `0.1..=10.0` (so `20.0` plays
+    /// at 10x and `0.05` at 0.1x), and a warning is logged when a speed is clamped.
+    /// `0.0` or `f64::INFINITY` delivers frames as fast as they are consumed.
+    pub fn set_playback_speed(&self, speed: f64) {
+        debug!("Requesting playback speed {}x", speed);
+        self.speed.send_replace(speed);
+    }
+
+    /// Get the total number of frames in the replay
+    pub fn total_frames(&self) -> usize {
+        self.total_frames
+    }
+
+    /// Jump playback to a frame index.
+    ///
+    /// Active subscriptions continue from the new position on their next poll.
+    /// Seeking has no effect once playback has reached the end of the file.
+    pub fn seek_to_frame(&self, index: usize) -> Result<()> {
+        if index >= self.total_frames {
+            return Err(TelemetryError::Parse {
+                context: "Frame seek".to_string(),
+                details: format!("Frame {} out of range (0..{})", index, self.total_frames),
+            });
+        }
+
+        self.send_seek(ReplaySeek::Frame(index))
+    }
+
+    /// Jump playback to the first frame at or after `session_time` (in seconds).
+    ///
+    /// The target frame is found by binary search on the `SessionTime` variable.
+    pub fn seek_to_time(&self, session_time: f64) -> Result<()> {
+        let (first, last) = self.session_time_range.ok_or_else(|| TelemetryError::Parse {
+            context: "Frame seek".to_string(),
+            details: "Replay has no SessionTime data".to_string(),
+        })?;
+
+        if !(first..=last).contains(&session_time) {
+            return Err(TelemetryError::Parse {
+                context: "Frame seek".to_string(),
+                details: format!(
+                    "Session time {} out of range ({}..={})",
+                    session_time, first, last
+                ),
+            });
+        }
+
+        self.send_seek(ReplaySeek::Time(session_time))
+    }
+
+    fn send_seek(&self, request: ReplaySeek) -> Result<()> {
+        debug!("Requesting replay seek: {:?}", request);
+        self.seek
+            .send(Some(request))
+            .map_err(|_| TelemetryError::connection_failed("Replay playback has ended"))
+    }
+}
+
+/// Report `session_num` as current, cloning only when it differs from the file's choice
+fn select_session(session: Arc<SessionInfo>, session_num: Option<i32>) -> Arc<SessionInfo> {
+    let Some(session_num) = session_num else {
+        return session;
+    };
+    if session.session_info.current_session_num == session_num {
+        return session;
+    }
+
+    let mut selected = SessionInfo::clone(&session);
+    if selected.session_info.select_session(session_num) {
+        Arc::new(selected)
+    } else {
+        warn!(session_num, "Session not listed in session info, reporting it unchanged");
+        session
+    }
+}
+
+impl TelemetrySource for ReplayConnection {
+    fn subscribe<T>(&self, rate: UpdateRate) -> impl Stream<Item = T> + Send + 'static
+    where
+        T: FrameAdapter + Send + 'static,
+    {
+        ReplayConnection::subscribe(self, rate)
+    }
+
+    fn session_updates(&self) -> impl Stream<Item = Arc<SessionInfo>> + Send + 'static {
+        ReplayConnection::session_updates(self)
+    }
+
+    fn current_session(&self) -> Option<Arc<SessionInfo>> {
+        ReplayConnection::current_session(self)
+    }
+
+    fn source_hz(&self) -> f64 {
+        ReplayConnection::source_hz(self)
+    }
+
+    fn schema(&self) -> &VariableSchema {
+        ReplayConnection::schema(self)
+    }
+}
+
+impl SchemaProvider for ReplayConnection {
+    fn get_schema(&self) -> &VariableSchema {
+        &self.schema
+    }
 }
 
 impl Drop for ReplayConnection {
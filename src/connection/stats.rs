@@ -0,0 +1,76 @@
+//! Frame delivery statistics for live telemetry
+
+use serde::Serialize;
+use std::time::Duration;
+#[cfg(any(windows, test))]
+use {crate::types::tick_after_u32, std::time::Instant, tokio::sync::watch};
+
+/// Snapshot of frame delivery, as reported by `LiveConnection::stats`
+///
+/// iRacing increments the tick count once per simulation tick, so a gap of more
+/// than one between consecutive frames means frames were produced that the
+/// provider never read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct StreamStats {
+    /// Frames read from the source
+    pub frames_received: u64,
+    /// Frames missed, counted from gaps in the tick count
+    pub frames_skipped: u64,
+    /// Mean wall-clock time between received frames
+    pub average_interval: Option<Duration>,
+    /// Tick count of the most recent frame
+    pub last_tick: Option<u32>,
+}
+
+impl StreamStats {
+    /// Fraction of produced frames that were missed, between 0 and 1
+    pub fn skip_ratio(&self) -> f64 {
+        let produced = self.frames_received + self.frames_skipped;
+        if produced == 0 { 0.0 } else { self.frames_skipped as f64 / produced as f64 }
+    }
+}
+
+/// Records frame arrivals on the provider side and publishes [`StreamStats`]
+#[cfg(any(windows, test))]
+pub(crate) struct StatsCollector {
+    stats: watch::Sender<StreamStats>,
+    last_arrival: Option<Instant>,
+    total_interval: Duration,
+}
+
+#[cfg(any(windows, test))]
+impl StatsCollector {
+    pub(crate) fn new() -> Self {
+        let (stats, _) = watch::channel(StreamStats::default());
+        Self { stats, last_arrival: None, total_interval: Duration::ZERO }
+    }
+
+    pub(crate) fn receiver(&self) -> watch::Receiver<StreamStats> {
+        self.stats.subscribe()
+    }
+
+    /// Record a frame with the given tick count, read at `at`.
+    ///
+    /// A tick that does not advance (iRacing restarted, or a replay was rewound)
+    /// starts a new sequence rather than counting as a gap.
+    pub(crate) fn record(&mut self, tick: u32, at: Instant) {
+        let interval = self.last_arrival.map(|last| at.saturating_duration_since(last));
+        self.last_arrival = Some(at);
+        if let Some(interval) = interval {
+            self.total_interval += interval;
+        }
+        let total_interval = self.total_interval;
+
+        self.stats.send_modify(|stats| {
+            if let Some(last) = stats.last_tick.filter(|&last| tick_after_u32(tick, last)) {
+                stats.frames_skipped += u64::from(tick.wrapping_sub(last) - 1);
+            }
+            stats.frames_received += 1;
+            stats.last_tick = Some(tick);
+
+            let intervals = u32::try_from(stats.frames_received - 1).unwrap_or(u32::MAX);
+            stats.average_interval = (intervals > 0).then(|| total_interval / intervals);
+        });
+    }
+}
@@ -0,0 +1,22 @@
+//! Connection state reported by live telemetry
+
+use serde::Serialize;
+
+/// State of the link to iRacing, as reported by `LiveConnection::status_updates`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum ConnectionStatus {
+    /// iRacing is not running, or the telemetry source has stopped
+    Disconnected,
+    /// iRacing's shared memory is mapped but no session is loaded
+    Connected,
+    /// A session is loaded and telemetry is live (the `status` connected bit is set)
+    InSession,
+}
+
+impl ConnectionStatus {
+    /// Returns true when telemetry frames are flowing
+    pub fn is_in_session(&self) -> bool {
+        matches!(self, ConnectionStatus::InSession)
+    }
+}
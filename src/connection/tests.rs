@@ -102,9 +102,8 @@ impl FrameAdapter for BasicTelemetry {
 
 // Simple frame for throttle testing
 #[cfg(test)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct SimpleFrame {
-    #[allow(dead_code)]
     speed: f32,
 }
 
@@ -454,3 +453,709 @@ async fn replay_telemetry_stream_throttling() {
 
     info!("Received {} frames over {:?}", frames.len(), start.elapsed());
 }
+
+#[tokio::test]
+async fn replay_seek_repositions_active_stream() {
+    use crate::test_utils;
+
+    let _ = tracing_subscriber::fmt::try_init();
+
+    // Synthetic frames carry their own index in `Speed`
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-seek", 600);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+    let mut stream = Box::pin(connection.subscribe::<SimpleFrame>(UpdateRate::Native));
+
+    // Wait until the stream observes a frame at or after `min` and before `max`
+    async fn wait_for_frame(
+        stream: &mut (impl futures::Stream<Item = SimpleFrame> + Unpin),
+        min: f32,
+        max: f32,
+    ) -> f32 {
+        tokio::time::timeout(Duration::from_secs(1), async {
+            loop {
+                let frame = stream.next().await.expect("Stream ended before seek applied");
+                if (min..max).contains(&frame.speed) {
+                    return frame.speed;
+                }
+            }
+        })
+        .await
+        .expect("Timeout waiting for seek to apply")
+    }
+
+    connection.seek_to_frame(500).expect("Seek to frame should succeed");
+    wait_for_frame(&mut stream, 500.0, 600.0).await;
+
+    connection.seek_to_time(2.0).expect("Seek to time should succeed");
+    let speed = wait_for_frame(&mut stream, 120.0, 500.0).await;
+    info!("Resumed at frame {} after seeking to 2.0s", speed);
+
+    assert!(connection.seek_to_frame(600).is_err(), "Seek past the end should fail");
+    assert!(connection.seek_to_time(-1.0).is_err(), "Seek before the start should fail");
+    assert!(connection.seek_to_time(60.0).is_err(), "Seek past the end should fail");
+
+    drop(stream);
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_unpaced_playback_runs_ahead_of_real_time() {
+    use crate::test_utils;
+
+    let _ = tracing_subscriber::fmt::try_init();
+
+    // 600 frames at 60Hz would take 10s at real-time speed
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-unpaced", 600);
+    let connection = replay::ReplayConnection::open(&ibt_file)
+        .await
+        .expect("Failed to open IBT file")
+        .with_playback_speed(f64::INFINITY);
+    let mut stream = Box::pin(connection.subscribe::<SimpleFrame>(UpdateRate::Native));
+
+    let last = tokio::time::timeout(Duration::from_secs(2), async {
+        let mut last = 0.0;
+        while let Some(frame) = stream.next().await {
+            last = frame.speed;
+        }
+        last
+    })
+    .await
+    .expect("Unpaced replay should finish well before real time");
+
+    info!("Unpaced replay ended after frame {}", last);
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_lists_available_variables() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-variables", 10);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    let variables = connection.available_variables();
+    let names: Vec<&str> = variables.iter().map(|info| info.name.as_str()).collect();
+    assert_eq!(names, vec!["Gear", "SessionTime", "Speed"]);
+
+    let speed = variables.iter().find(|info| info.name == "Speed").expect("Speed variable");
+    assert_eq!(speed.units, "m/s");
+    assert_eq!(speed.count, 1);
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test(start_paused = true)]
+async fn replay_paces_native_stream_at_recorded_tick_rate() {
+    use crate::test_utils;
+    use tokio::time::Instant;
+
+    let _ = tracing_subscriber::fmt::try_init();
+
+    // 360Hz recording; `Speed` carries the frame index
+    let ibt_file = test_utils::write_synthetic_ibt_at_rate("connection-replay-360hz", 1800, 360);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+    assert_eq!(connection.source_hz(), 360.0);
+
+    let mut stream = Box::pin(connection.subscribe::<SimpleFrame>(UpdateRate::Native));
+
+    // The clock is paused and only advances as the replay sleeps, so the measured
+    // rate reflects pacing alone, not scheduler load
+    let first = stream.next().await.expect("Stream should yield frames").speed;
+    let start = Instant::now();
+    let mut last = first;
+    while start.elapsed() < Duration::from_millis(500) {
+        last = stream.next().await.expect("Stream should not end early").speed;
+    }
+
+    let frames_per_second = (last - first) as f64 / start.elapsed().as_secs_f64();
+    info!("Replay advanced at {:.0} frames/s", frames_per_second);
+    assert!(
+        (340.0..380.0).contains(&frames_per_second),
+        "Expected ~360 frames/s, got {:.0}",
+        frames_per_second
+    );
+
+    // Time-based seeking uses the recorded SessionTime, not a 60Hz assumption
+    connection.seek_to_time(4.0).expect("Seek to time should succeed");
+    tokio::time::timeout(Duration::from_secs(1), async {
+        loop {
+            let speed = stream.next().await.expect("Stream ended before seek applied").speed;
+            if (1440.0..1800.0).contains(&speed) {
+                break;
+            }
+        }
+    })
+    .await
+    .expect("Timeout waiting for seek to apply");
+
+    drop(stream);
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[cfg(not(windows))]
+#[tokio::test]
+async fn live_builder_reports_unsupported_platform() {
+    let result = live::LiveConnection::builder()
+        .connect_timeout(Duration::from_secs(5))
+        .retry_interval(Duration::from_millis(100))
+        .require_session(true)
+        .session_channel_capacity(1)
+        .connect()
+        .await;
+
+    assert!(matches!(result, Err(crate::TelemetryError::UnsupportedPlatform { .. })));
+}
+
+#[tokio::test]
+async fn resilient_adaptation_follows_schema_changes() {
+    use crate::types::{FramePacket, VariableInfo, VariableSchema, VariableType};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    // Same bytes, but `Speed` moves between layouts as it would after a car change
+    let schema_at = |offset: usize| {
+        let speed = VariableInfo {
+            name: "Speed".to_string(),
+            data_type: VariableType::Float32,
+            offset,
+            count: 1,
+            count_as_time: false,
+            units: "m/s".to_string(),
+            description: "Speed".to_string(),
+        };
+        Arc::new(VariableSchema::new(HashMap::from([("Speed".to_string(), speed)]), 8).unwrap())
+    };
+    let data: Vec<u8> = [10.0f32, 20.0f32].iter().flat_map(|v| v.to_le_bytes()).collect();
+
+    let before = schema_at(0);
+    let after = schema_at(4);
+    let packets = vec![
+        Arc::new(FramePacket::new(data.clone(), 1, 1, Arc::clone(&before))),
+        Arc::new(FramePacket::new(data.clone(), 2, 1, before)),
+        Arc::new(FramePacket::new(data.clone(), 3, 2, after)),
+        // A reconnect with an identical layout keeps the plan
+        Arc::new(FramePacket::new(data, 4, 3, schema_at(4))),
+    ];
+
+    let speeds: Vec<f32> =
+        crate::stream::adapt_revalidating::<SimpleFrame, _>(futures::stream::iter(packets))
+            .map(|frame| frame.speed)
+            .collect()
+            .await;
+
+    assert_eq!(speeds, vec![10.0, 10.0, 20.0, 20.0]);
+}
+
+#[tokio::test]
+async fn replay_on_change_subscription_skips_unchanged_frames() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-on-change", 600);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    // `Speed` is the frame index, so the key changes once every ten frames
+    let stream = connection
+        .subscribe_on_change::<SimpleFrame, i32>(UpdateRate::Native, |frame| {
+            (frame.speed / 10.0) as i32
+        })
+        .map(|frame| (frame.speed / 10.0) as i32)
+        .take(4);
+
+    let buckets: Vec<i32> = tokio::time::timeout(Duration::from_secs(5), stream.collect())
+        .await
+        .expect("Timeout waiting for changed frames");
+
+    assert_eq!(buckets.len(), 4);
+    assert!(
+        buckets.windows(2).all(|pair| pair[0] < pair[1]),
+        "Keys should never repeat: {:?}",
+        buckets
+    );
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_meta_subscription_carries_tick_and_session_time() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-meta", 120);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+    let mut stream = Box::pin(connection.subscribe_with_meta::<SimpleFrame>(UpdateRate::Native));
+
+    for _ in 0..3 {
+        let (meta, frame) = tokio::time::timeout(Duration::from_secs(1), stream.next())
+            .await
+            .expect("Timeout waiting for frame")
+            .expect("Stream ended early");
+
+        // Synthetic frames store their index in `Speed` and `index / 60` in `SessionTime`
+        let expected_time = frame.speed as f64 / 60.0;
+        assert!((meta.session_time.expect("SessionTime present") - expected_time).abs() < 1e-9);
+        assert_eq!(meta.session_version, 1);
+    }
+
+    drop(stream);
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn connect_mock_streams_through_telemetry_source() {
+    use crate::{Pitwall, TelemetrySource, test_utils};
+
+    // App code only sees the shared trait
+    async fn first_speed(connection: &impl TelemetrySource) -> Option<f32> {
+        let mut stream = Box::pin(connection.subscribe::<SimpleFrame>(UpdateRate::Native));
+        stream.next().await.map(|frame| frame.speed)
+    }
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-mock", 120);
+    let connection = Pitwall::connect_mock(&ibt_file).await.expect("Failed to open mock");
+
+    assert_eq!(TelemetrySource::source_hz(&connection), 60.0);
+    let speed = tokio::time::timeout(Duration::from_secs(1), first_speed(&connection))
+        .await
+        .expect("Timeout waiting for frame");
+    assert!(speed.is_some());
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_sources_share_schema_provider() {
+    use crate::{IbtReader, SchemaProvider, test_utils};
+
+    fn has_gear(source: &impl SchemaProvider) -> bool {
+        source.has_field("Gear") && source.get_field_info("Gear").is_some()
+    }
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-schema-provider", 10);
+    let reader = IbtReader::open(&ibt_file).expect("Failed to open IBT file");
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    assert!(has_gear(&reader));
+    assert!(has_gear(&connection));
+    assert!(!connection.has_field("RPM"));
+
+    let mut names = connection.get_field_names();
+    names.sort();
+    assert_eq!(names, vec!["Gear", "SessionTime", "Speed"]);
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_latest_at_coalesces_to_freshest_frame() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-latest-at", 600);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    // 60Hz source sampled at 10Hz: each frame should skip the ones in between
+    let stream =
+        connection.subscribe::<SimpleFrame>(UpdateRate::LatestAt(10)).map(|f| f.speed).take(5);
+    let speeds: Vec<f32> = tokio::time::timeout(Duration::from_secs(5), stream.collect())
+        .await
+        .expect("Timeout waiting for sampled frames");
+
+    assert_eq!(speeds.len(), 5);
+    assert!(
+        speeds.windows(2).skip(1).all(|pair| pair[1] - pair[0] >= 3.0),
+        "Intermediate frames should be coalesced: {:?}",
+        speeds
+    );
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_windowed_subscription_yields_consecutive_frames() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-windowed", 600);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    let stream = connection.subscribe_windowed::<SimpleFrame>(UpdateRate::Native, 2).take(3);
+    let windows: Vec<_> = tokio::time::timeout(Duration::from_secs(5), stream.collect())
+        .await
+        .expect("Timeout waiting for frame windows");
+
+    assert_eq!(windows.len(), 3);
+    for window in &windows {
+        assert_eq!(window.frames.len(), 2);
+        assert!(window.tick_delta() >= 1);
+
+        // `Speed` advances by one per frame at 60Hz
+        let rate = window.rate_of(|frame| frame.speed as f64).expect("time should advance");
+        assert!((rate - 60.0).abs() < 1e-3, "Unexpected rate {}", rate);
+    }
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_subscribe_dynamic_yields_registered_adapter_frames() {
+    use crate::adapters::AdapterRegistry;
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-dynamic", 60);
+    let connection = replay::ReplayConnection::open(&ibt_file)
+        .await
+        .expect("Failed to open IBT file")
+        .with_playback_speed(0.0);
+
+    let mut registry = AdapterRegistry::new();
+    registry.register::<SimpleFrame>("simple");
+    assert_eq!(registry.names(), vec!["simple"]);
+
+    let error = connection
+        .subscribe_dynamic(&registry, "car_data", UpdateRate::Native)
+        .err()
+        .expect("Unknown adapter name should fail");
+    assert!(error.to_string().contains("car_data"), "{}", error);
+
+    let frame = connection
+        .subscribe_dynamic(&registry, "simple", UpdateRate::Native)
+        .expect("Registered adapter should bind")
+        .next()
+        .await
+        .expect("Stream should yield a frame");
+    let frame = frame.downcast::<SimpleFrame>().expect("Adapter yields SimpleFrame");
+    assert!(frame.speed >= 0.0 && frame.speed < 60.0);
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test(start_paused = true)]
+async fn replay_poll_latest_returns_each_new_frame_once() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-poll", 600);
+    let mut connection = replay::ReplayConnection::open(&ibt_file)
+        .await
+        .expect("Failed to open IBT file")
+        .with_playback_speed(0.05);
+
+    let first = connection.poll_latest::<SimpleFrame>().expect("Poll should succeed");
+    assert!(first.is_some(), "Frame delivered before open returned counts as new");
+
+    // Speed 0.05 is clamped to 0.1, pacing the 60Hz file at 6Hz, and the paused
+    // clock does not move between polls, so a second poll has nothing new
+    let again = connection.poll_latest::<SimpleFrame>().expect("Poll should succeed");
+    assert!(again.is_none());
+
+    // Half a second of virtual time covers about three frames
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let later = connection
+        .poll_latest::<SimpleFrame>()
+        .expect("Poll should succeed")
+        .expect("New frames arrive while playing");
+    let advanced = later.speed - first.unwrap().speed;
+    assert!((2.0..=4.0).contains(&advanced), "Expected ~3 frames, advanced {}", advanced);
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_subscribe_fields_reads_only_named_variables() {
+    use crate::test_utils;
+    use crate::types::Value;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-fields", 60);
+    let connection = replay::ReplayConnection::open(&ibt_file)
+        .await
+        .expect("Failed to open IBT file")
+        .with_playback_speed(0.0);
+
+    let error = connection
+        .subscribe_fields(&["Speed", "Gears"], UpdateRate::Native)
+        .err()
+        .expect("Unknown field should fail");
+    assert!(error.to_string().contains("'Gear'"), "{}", error);
+
+    let fields = connection
+        .subscribe_fields(&["Gear", "Speed", "Speed"], UpdateRate::Native)
+        .expect("Known fields should validate")
+        .next()
+        .await
+        .expect("Stream should yield a frame");
+    assert_eq!(fields.len(), 2);
+    assert!(matches!(fields["Speed"], Value::Float32(speed) if (0.0..60.0).contains(&speed)));
+    assert!(matches!(fields["Gear"], Value::Int32(_)));
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_attaches_computed_channels_to_packets() {
+    use crate::test_utils;
+    use crate::types::{FramePacket, Value};
+
+    /// Reads the computed channel through the packet, as a user adapter would
+    struct SpeedKph(Option<Value>);
+
+    impl FrameAdapter for SpeedKph {
+        fn validate_schema(_schema: &crate::VariableSchema) -> crate::Result<AdapterValidation> {
+            Ok(AdapterValidation::new(Vec::new()))
+        }
+
+        fn adapt(packet: &FramePacket, _validation: &AdapterValidation) -> Self {
+            SpeedKph(packet.get_value("SpeedKph").ok())
+        }
+    }
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-computed", 60);
+    let mut connection = replay::ReplayConnection::open(&ibt_file)
+        .await
+        .expect("Failed to open IBT file")
+        .with_playback_speed(0.0);
+
+    let before = connection.subscribe::<SpeedKph>(UpdateRate::Native);
+
+    let error = connection
+        .add_computed("Speed", |_: &FramePacket| Value::Float32(0.0))
+        .expect_err("Raw variable names are rejected");
+    assert!(error.to_string().contains("'Speed'"), "{}", error);
+
+    connection
+        .add_computed("SpeedKph", |frame: &FramePacket| {
+            Value::Float32(frame.get_f32("Speed").unwrap_or(0.0) * 3.6)
+        })
+        .expect("New names are accepted");
+    assert_eq!(connection.computed_channels().names().collect::<Vec<_>>(), ["SpeedKph"]);
+
+    let frame = connection
+        .subscribe::<SpeedKph>(UpdateRate::Native)
+        .next()
+        .await
+        .expect("Stream should yield a frame");
+    assert!(matches!(frame.0, Some(Value::Float32(kph)) if kph >= 0.0));
+
+    let polled = connection.poll_latest::<SpeedKph>().expect("Poll should succeed");
+    assert!(polled.is_some_and(|frame| frame.0.is_some()));
+
+    let mut before = Box::pin(before);
+    let earlier = before.next().await.expect("Stream should yield a frame");
+    assert_eq!(earlier.0, None, "Subscriptions keep the channels they started with");
+
+    #[cfg(feature = "json")]
+    {
+        let json = connection.schema().to_json_with_computed(connection.computed_channels());
+        assert_eq!(json["computed"], serde_json::json!(["SpeedKph"]));
+        let schema = crate::VariableSchema::from_json(&json).expect("Computed section is ignored");
+        assert!(!schema.has_variable("SpeedKph"));
+    }
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_exposes_raw_session_yaml() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-raw-yaml", 60);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    let session = Box::pin(connection.session_updates())
+        .next()
+        .await
+        .expect("Replay should publish session info");
+    let yaml = connection.raw_session_yaml().expect("YAML kept alongside the session");
+    assert!(yaml.contains("Synthetic Raceway"));
+    assert_eq!(SessionInfo::parse(&yaml).expect("YAML should parse"), *session);
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_for_session_pins_reported_session() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-for-session", 60);
+
+    // The synthetic recording lists only session 0, so an unknown session is ignored
+    let connection = replay::ReplayConnection::open(&ibt_file)
+        .await
+        .expect("Failed to open IBT file")
+        .for_session(3);
+    let session = Box::pin(connection.session_updates())
+        .next()
+        .await
+        .expect("Replay should publish session info");
+    assert_eq!(session.session_info.current_session_num, 0);
+
+    let connection = connection.for_session(0);
+    let session = connection.current_session().expect("Session already published");
+    assert_eq!(session.session_info.current_session().map(|s| s.session_num), Some(0));
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_coalesced_session_updates_yield_latest_session() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-coalesced", 60);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    let session = tokio::time::timeout(
+        Duration::from_secs(5),
+        Box::pin(connection.session_updates_coalesced(Duration::from_millis(250))).next(),
+    )
+    .await
+    .expect("First update is not delayed by the interval")
+    .expect("Replay should publish session info");
+    assert_eq!(session.weekend_info.track_display_name, "Synthetic Raceway");
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_latest_subscription_skips_frames_missed_by_slow_consumer() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-latest", 600);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    let mut stream = Box::pin(connection.subscribe_latest::<SimpleFrame>());
+    let first = stream.next().await.expect("Stream should yield a frame");
+
+    // ~18 frames are published at 60Hz while the consumer is busy
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    let next = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("Timeout waiting for frame")
+        .expect("Stream should yield a frame");
+
+    assert!(
+        next.speed - first.speed >= 10.0,
+        "Missed frames should be skipped, not queued: {} then {}",
+        first.speed,
+        next.speed
+    );
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[test]
+fn stats_count_tick_gaps_as_skipped_frames() {
+    use std::time::Instant;
+
+    let mut collector = StatsCollector::new();
+    let stats = collector.receiver();
+    assert_eq!(*stats.borrow(), StreamStats::default());
+
+    let start = Instant::now();
+    for (i, tick) in [100, 101, 104, 105].into_iter().enumerate() {
+        collector.record(tick, start + Duration::from_millis(20 * i as u64));
+    }
+    let snapshot = *stats.borrow();
+    assert_eq!(snapshot.frames_received, 4);
+    assert_eq!(snapshot.frames_skipped, 2, "Ticks 102 and 103 were never read");
+    assert_eq!(snapshot.last_tick, Some(105));
+    assert_eq!(snapshot.average_interval, Some(Duration::from_millis(20)));
+    assert!((snapshot.skip_ratio() - 2.0 / 6.0).abs() < 1e-9);
+
+    // A tick counter reset (iRacing restarted) is not a gap
+    collector.record(5, start + Duration::from_millis(80));
+    assert_eq!(stats.borrow().frames_skipped, 2);
+    assert_eq!(stats.borrow().last_tick, Some(5));
+}
+
+#[tokio::test]
+async fn replay_progress_follows_playback_to_the_last_frame() {
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-progress", 30);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+    let mut progress = Box::pin(connection.progress());
+
+    let updates = tokio::time::timeout(Duration::from_secs(2), async {
+        let mut updates = Vec::new();
+        while let Some(update) = progress.next().await {
+            updates.push(update);
+        }
+        updates
+    })
+    .await
+    .expect("Replay should finish within two seconds");
+
+    assert!(updates.windows(2).all(|pair| pair[0].current_frame < pair[1].current_frame));
+    // The end-of-file marker may overwrite the final frame before it is read
+    let last = updates.last().expect("At least one progress update");
+    assert!(last.current_frame >= 28, "Stopped at frame {}", last.current_frame);
+    assert_eq!(last.total_frames, 30);
+    assert!((last.fraction - (last.current_frame + 1) as f64 / 30.0).abs() < 1e-12);
+    let expected_time = last.current_frame as f64 / 60.0;
+    assert!((last.session_time.expect("SessionTime present") - expected_time).abs() < 1e-9);
+
+    drop(progress);
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
+
+#[tokio::test]
+async fn replay_subscribe_limited_reports_limit_and_end_of_file() {
+    use crate::stream::StreamEnd;
+    use crate::test_utils;
+
+    let ibt_file = test_utils::write_synthetic_ibt("connection-replay-limited", 60);
+    let connection =
+        replay::ReplayConnection::open(&ibt_file).await.expect("Failed to open IBT file");
+
+    let mut limited = Box::pin(connection.subscribe_limited::<SimpleFrame>(UpdateRate::Native, 5));
+    let frames: Vec<SimpleFrame> = tokio::time::timeout(Duration::from_secs(1), async {
+        let mut frames = Vec::new();
+        while let Some(frame) = limited.next().await {
+            frames.push(frame);
+        }
+        frames
+    })
+    .await
+    .expect("Five frames should arrive within a second");
+    assert_eq!(frames.len(), 5);
+    assert_eq!(limited.end(), Some(StreamEnd::LimitReached));
+
+    let mut unlimited =
+        Box::pin(connection.subscribe_limited::<SimpleFrame>(UpdateRate::Native, 1000));
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while unlimited.next().await.is_some() {}
+    })
+    .await
+    .expect("Replay should finish within two seconds");
+    assert_eq!(unlimited.end(), Some(StreamEnd::SourceEnded));
+
+    drop(connection);
+    let _ = std::fs::remove_file(ibt_file);
+}
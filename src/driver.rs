@@ -1,7 +1,10 @@
 //! Driver spawns and manages telemetry processing tasks
 
-use std::sync::Arc;
-use tokio::sync::watch;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{Notify, watch};
+use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, trace, warn};
 
@@ -15,14 +18,115 @@ pub struct DriverChannels {
     pub frames: watch::Receiver<Option<Arc<FramePacket>>>,
     /// Receiver for session info updates
     pub sessions: watch::Receiver<Option<Arc<SessionInfo>>>,
+    /// Receiver for the preprocessed YAML behind the latest session update
+    pub session_yaml: watch::Receiver<Option<Arc<str>>>,
+    /// Session YAML updates dropped because the parse queue was full
+    pub session_updates_dropped: Arc<AtomicU64>,
     /// Cancellation token for graceful shutdown
     pub cancel: CancellationToken,
 }
 
+/// Pending session parses used when no capacity is configured
+pub const DEFAULT_SESSION_CHANNEL_CAPACITY: usize = 4;
+
+/// Senders for parsed session info and the YAML it was parsed from
+#[derive(Clone)]
+struct SessionSenders {
+    sessions: watch::Sender<Option<Arc<SessionInfo>>>,
+    yaml: watch::Sender<Option<Arc<str>>>,
+}
+
+impl SessionSenders {
+    /// Publish a parsed session; the YAML is sent first so it is never older than the session
+    fn send(&self, session: SessionInfo, yaml: String) {
+        let _ = self.yaml.send(Some(yaml.into()));
+        let _ = self.sessions.send(Some(Arc::new(session)));
+    }
+
+    fn clear(&self) {
+        let _ = self.yaml.send(None);
+        let _ = self.sessions.send(None);
+    }
+}
+
+/// Bounded queue of session YAML waiting to be parsed.
+///
+/// The frame reader never waits on it: when the queue is full the oldest pending
+/// YAML is dropped, since a newer version supersedes it anyway.
+struct SessionParseQueue {
+    state: Mutex<ParseQueueState>,
+    capacity: usize,
+    ready: Notify,
+    dropped: Arc<AtomicU64>,
+}
+
+#[derive(Default)]
+struct ParseQueueState {
+    pending: VecDeque<String>,
+    closed: bool,
+}
+
+impl SessionParseQueue {
+    fn new(capacity: usize) -> Self {
+        Self {
+            state: Mutex::default(),
+            capacity: capacity.max(1),
+            ready: Notify::new(),
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    fn push(&self, yaml: String) {
+        {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            if state.pending.len() >= self.capacity {
+                state.pending.pop_front();
+                let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                debug!(dropped, "Session parse queue full, dropped oldest pending update");
+            }
+            state.pending.push_back(yaml);
+        }
+        self.ready.notify_one();
+    }
+
+    /// Stop the parser once its current parse finishes; pending YAML is discarded
+    fn close(&self) {
+        {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.pending.clear();
+            state.closed = true;
+        }
+        self.ready.notify_one();
+    }
+
+    /// Wait for the next YAML to parse, or `None` once closed
+    async fn next(&self) -> Option<String> {
+        loop {
+            {
+                let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+                if state.closed {
+                    return None;
+                }
+                if let Some(yaml) = state.pending.pop_front() {
+                    return Some(yaml);
+                }
+            }
+            self.ready.notified().await;
+        }
+    }
+}
+
+/// Parser task paired with the queue feeding it
+struct SessionParser {
+    queue: Arc<SessionParseQueue>,
+    task: JoinHandle<()>,
+}
+
 /// Driver spawns and manages telemetry processing tasks
 ///
 /// Spawns a frame reader task that owns the Provider and detects session changes.
-/// YAML parsing happens in short-lived spawned tasks to maintain <1ms frame latency.
+/// YAML is parsed in order by a separate parser task, fed through a bounded queue,
+/// to maintain <1ms frame latency.
 pub struct Driver;
 
 impl Driver {
@@ -31,12 +135,25 @@ impl Driver {
     /// Returns watch receivers for frames and sessions, plus a cancellation token
     /// for graceful shutdown.
     pub fn spawn<P>(provider: P) -> DriverChannels
+    where
+        P: Provider,
+    {
+        Self::spawn_with_capacity(provider, DEFAULT_SESSION_CHANNEL_CAPACITY)
+    }
+
+    /// Spawn driver tasks, queueing at most `session_capacity` session YAML
+    /// updates for parsing (at least 1).
+    ///
+    /// When the parser falls behind, the oldest pending update is dropped and
+    /// counted in [`DriverChannels::session_updates_dropped`].
+    pub fn spawn_with_capacity<P>(provider: P, session_capacity: usize) -> DriverChannels
     where
         P: Provider,
     {
         // Create the communication channels
         let (frame_tx, frame_rx) = watch::channel(None);
         let (session_tx, session_rx) = watch::channel(None);
+        let (yaml_tx, yaml_rx) = watch::channel(None);
 
         // Create cancellation token for coordinated shutdown
         let cancel = CancellationToken::new();
@@ -44,20 +161,47 @@ impl Driver {
         // Clone what we need for the frame reader task
         let cancel_frame = cancel.clone();
 
-        // Spawn frame reader task (owns the provider)
-        // YAML parsing happens via short-lived spawned tasks (see frame_reader_task)
+        let senders = SessionSenders { sessions: session_tx, yaml: yaml_tx };
+        let queue = Arc::new(SessionParseQueue::new(session_capacity));
+        let session_updates_dropped = Arc::clone(&queue.dropped);
+
+        // Spawn the parser task, then the frame reader task (owns the provider)
+        let task = tokio::spawn(Self::session_parser_task(Arc::clone(&queue), senders.clone()));
+        let parser = SessionParser { queue, task };
         tokio::spawn(async move {
-            Self::frame_reader_task(provider, frame_tx, session_tx, cancel_frame).await;
+            Self::frame_reader_task(provider, frame_tx, senders, parser, cancel_frame).await;
         });
 
-        DriverChannels { frames: frame_rx, sessions: session_rx, cancel }
+        DriverChannels {
+            frames: frame_rx,
+            sessions: session_rx,
+            session_yaml: yaml_rx,
+            session_updates_dropped,
+            cancel,
+        }
+    }
+
+    /// Session parser task - parses queued YAML in order and publishes sessions
+    async fn session_parser_task(queue: Arc<SessionParseQueue>, session_tx: SessionSenders) {
+        while let Some(yaml) = queue.next().await {
+            match SessionInfo::parse(&yaml) {
+                Ok(session) => {
+                    debug!("Session parsed: Track={}", session.weekend_info.track_name);
+                    session_tx.send(session, yaml);
+                }
+                Err(e) => {
+                    warn!("Failed to parse session YAML: {}", e);
+                }
+            }
+        }
     }
 
     /// Frame reader task - reads frames and detects session changes
     async fn frame_reader_task<P>(
         mut provider: P,
         frame_tx: watch::Sender<Option<Arc<FramePacket>>>,
-        session_tx: watch::Sender<Option<Arc<SessionInfo>>>,
+        session_tx: SessionSenders,
+        mut parser: SessionParser,
         cancel: CancellationToken,
     ) where
         P: Provider,
@@ -103,7 +247,7 @@ impl Driver {
                             version
                         );
 
-                        // Fetch YAML and spawn short-lived task to parse it
+                        // Fetch YAML and queue it for the parser task
                         // This avoids blocking frame processing while YAML parsing happens
                         match provider.session_yaml(version).await {
                             Ok(Some(yaml)) => {
@@ -112,26 +256,7 @@ impl Driver {
                                     yaml.len(),
                                     version
                                 );
-
-                                // Clone session_tx for the spawned task
-                                let session_tx_clone = session_tx.clone();
-
-                                // Spawn detached task to parse YAML without blocking frame reader
-                                // Task automatically cleans up when parsing completes (~1-10ms)
-                                tokio::spawn(async move {
-                                    match SessionInfo::parse(&yaml) {
-                                        Ok(session) => {
-                                            debug!(
-                                                "Session parsed: Track={}",
-                                                session.weekend_info.track_name
-                                            );
-                                            let _ = session_tx_clone.send(Some(Arc::new(session)));
-                                        }
-                                        Err(e) => {
-                                            warn!("Failed to parse session YAML: {}", e);
-                                        }
-                                    }
-                                });
+                                parser.queue.push(yaml);
                             }
                             Ok(None) => {
                                 debug!("No session YAML for version {}", version);
@@ -154,7 +279,8 @@ impl Driver {
                     info!("Provider stream ended after {} frames", frame_count);
                     // Send None to indicate end of stream
                     let _ = frame_tx.send(None);
-                    let _ = session_tx.send(None);
+                    Self::stop_parser(&mut parser).await;
+                    session_tx.clear();
                     break;
                 }
                 Err(e) => {
@@ -165,7 +291,8 @@ impl Driver {
                     if error_count >= MAX_ERRORS {
                         error!("Too many provider errors, shutting down");
                         let _ = frame_tx.send(None);
-                        let _ = session_tx.send(None);
+                        Self::stop_parser(&mut parser).await;
+                        session_tx.clear();
                         break;
                     }
 
@@ -176,6 +303,59 @@ impl Driver {
             }
         }
 
+        // Cancelled or every receiver dropped: stop parsing without clearing sessions
+        // (a no-op when the stream already ended)
+        parser.queue.close();
         info!("Frame reader task ended (processed {} frames)", frame_count);
     }
+
+    /// Close the parse queue and wait for an in-flight parse, so no session is
+    /// published after the channels are cleared
+    async fn stop_parser(parser: &mut SessionParser) {
+        parser.queue.close();
+        let _ = (&mut parser.task).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn full_parse_queue_drops_oldest_pending_update() {
+        let queue = SessionParseQueue::new(2);
+        for version in 1..=4 {
+            queue.push(format!("version {}", version));
+        }
+
+        assert_eq!(queue.dropped.load(Ordering::Relaxed), 2);
+        assert_eq!(queue.next().await.as_deref(), Some("version 3"));
+        assert_eq!(queue.next().await.as_deref(), Some("version 4"));
+
+        queue.push("version 5".to_string());
+        queue.close();
+        assert_eq!(queue.next().await, None, "Closing discards pending updates");
+    }
+
+    #[tokio::test]
+    async fn parser_waits_for_updates_and_stops_when_closed() {
+        let queue = Arc::new(SessionParseQueue::new(0));
+        assert_eq!(queue.capacity, 1);
+
+        let waiting = tokio::spawn({
+            let queue = Arc::clone(&queue);
+            async move { queue.next().await }
+        });
+        tokio::task::yield_now().await;
+        queue.push("version 1".to_string());
+        assert_eq!(waiting.await.unwrap().as_deref(), Some("version 1"));
+
+        let waiting = tokio::spawn({
+            let queue = Arc::clone(&queue);
+            async move { queue.next().await }
+        });
+        tokio::task::yield_now().await;
+        queue.close();
+        assert_eq!(waiting.await.unwrap(), None);
+    }
 }
@@ -8,10 +8,13 @@
 use crate::Result;
 use crate::{
     adapters::{AdapterValidation, FrameAdapter},
-    types::{FramePacket, VarData, VariableInfo, VariableSchema},
+    types::{BitField, FramePacket, Value, VarData, VariableInfo, VariableSchema},
 };
 use std::sync::Arc;
 
+#[cfg(feature = "json")]
+use crate::types::{VariableType, decode_engine_warnings, decode_session_flags};
+
 /// A self-contained view over a single telemetry frame supporting by-name lookups.
 #[derive(Debug, Clone)]
 pub struct DynamicFrame {
@@ -33,6 +36,12 @@ impl DynamicFrame {
         T::from_bytes(self.data.as_ref(), info).ok()
     }
 
+    /// Lookup by variable name, decoded according to the schema's data type.
+    /// Array variables are returned as `Value::Array`.
+    pub fn value(&self, name: &str) -> Option<Value> {
+        self.get(name)
+    }
+
     /// Convenience typed helpers
     pub fn f32(&self, name: &str) -> Option<f32> {
         self.get(name)
@@ -46,11 +55,78 @@ impl DynamicFrame {
     pub fn bool(&self, name: &str) -> Option<bool> {
         self.get(name)
     }
+    pub fn bitfield(&self, name: &str) -> Option<BitField> {
+        self.get(name)
+    }
 
     /// Accessors for metadata
     pub fn tick_count(&self) -> u32 {
         self.tick_count
     }
+
+    /// Serialize every variable into a JSON object keyed by variable name.
+    ///
+    /// Arrays become JSON arrays and `Char` arrays become strings. Bitfields with a
+    /// known decoder (`SessionFlags`, `CarIdxSessionFlags`, `EngineWarnings`) become
+    /// `{ "value": u32, "flags": [...] }`; other bitfields are emitted as their `u32`.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Value {
+        let object = self
+            .schema
+            .variables
+            .iter()
+            .filter_map(|(name, info)| {
+                let value = if info.data_type == VariableType::Char && info.count > 1 {
+                    serde_json::Value::String(self.get::<String>(name)?)
+                } else {
+                    json_value(name, &self.value(name)?)
+                };
+                Some((name.clone(), value))
+            })
+            .collect();
+        serde_json::Value::Object(object)
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_value(name: &str, value: &Value) -> serde_json::Value {
+    use serde_json::json;
+
+    match value {
+        Value::Char(v) | Value::UInt8(v) => json!(v),
+        Value::Int8(v) => json!(v),
+        Value::Int16(v) => json!(v),
+        Value::UInt16(v) => json!(v),
+        Value::Int32(v) => json!(v),
+        Value::UInt32(v) => json!(v),
+        Value::Float32(v) => json!(v),
+        Value::Float64(v) => json!(v),
+        Value::Bool(v) => json!(v),
+        Value::BitField(bits) => match decoded_flags(name, *bits) {
+            Some(flags) => json!({ "value": bits.value(), "flags": flags }),
+            None => json!(bits.value()),
+        },
+        Value::Array(values) => values.iter().map(|v| json_value(name, v)).collect(),
+    }
+}
+
+/// Names of the set flags for bitfields with a known decoder
+#[cfg(feature = "json")]
+fn decoded_flags(name: &str, bits: BitField) -> Option<Vec<String>> {
+    let decoded = match name {
+        "SessionFlags" | "CarIdxSessionFlags" => serde_json::to_value(decode_session_flags(bits)),
+        "EngineWarnings" => serde_json::to_value(decode_engine_warnings(bits)),
+        _ => return None,
+    }
+    .ok()?;
+
+    let flags = decoded
+        .as_object()?
+        .iter()
+        .filter(|(_, set)| set.as_bool() == Some(true))
+        .map(|(flag, _)| flag.clone())
+        .collect();
+    Some(flags)
 }
 
 impl FrameAdapter for DynamicFrame {
@@ -134,5 +210,52 @@ mod tests {
         let lap_dist_values: Vec<f32> = df.get("CarIdxLapDistPct").unwrap();
         assert_eq!(lap_dist_values, lap_dist);
         assert_eq!(df.u32("Missing"), None);
+
+        assert_eq!(df.value("RPM"), Some(Value::Int32(1234)));
+        assert_eq!(df.value("Speed"), Some(Value::Float32(42.5)));
+        assert_eq!(
+            df.value("CarIdxLapDistPct"),
+            Some(Value::Array(lap_dist.iter().copied().map(Value::Float32).collect()))
+        );
+        assert_eq!(df.value("Missing"), None);
+        assert_eq!(df.f32("RPM"), None, "Type mismatch should return None");
+        assert_eq!(df.bitfield("Speed"), None, "Type mismatch should return None");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn dynamic_frame_to_json() {
+        use crate::test_utils::test_schema;
+        use crate::types::irsdk_flags::session_flags;
+        use serde_json::json;
+
+        let schema = test_schema(
+            &[
+                ("Gear", VariableType::Int32, 0, 1),
+                ("Speed", VariableType::Float32, 4, 1),
+                ("SessionFlags", VariableType::BitField, 8, 1),
+                ("CamCameraState", VariableType::BitField, 12, 1),
+                ("CarIdxGear", VariableType::Int32, 16, 2),
+            ],
+            24,
+        );
+
+        let flags = session_flags::GREEN | session_flags::BLUE;
+        let words: [u32; 6] = [3, 45.5f32.to_bits(), flags, 0x48, 2, 4];
+        let data: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+
+        let packet = FramePacket::new(data, 10, 0, Arc::new(schema));
+        let df = DynamicFrame::adapt(&packet, &AdapterValidation::new(vec![]));
+
+        assert_eq!(
+            df.to_json(),
+            json!({
+                "Gear": 3,
+                "Speed": 45.5,
+                "SessionFlags": { "value": flags, "flags": ["blue", "green"] },
+                "CamCameraState": 0x48,
+                "CarIdxGear": [2, 4],
+            })
+        );
     }
 }
@@ -52,7 +52,7 @@ use std::path::PathBuf;
 use std::time::Duration;
 use thiserror::Error;
 
-#[cfg(windows)]
+#[cfg(all(windows, feature = "runtime"))]
 use windows_core as core;
 
 /// Result type alias for telemetry operations.
@@ -102,7 +102,7 @@ pub enum TelemetryError {
     UnsupportedPlatform { feature: String, required_platform: String },
 
     #[error("Windows API error: {operation}")]
-    #[cfg(windows)]
+    #[cfg(all(windows, feature = "runtime"))]
     WindowsApi {
         operation: String,
         #[source]
@@ -135,7 +135,7 @@ impl TelemetryError {
             TelemetryError::FieldNotFound { .. } => false,
             TelemetryError::TypeConversion { .. } => false,
             TelemetryError::UnsupportedPlatform { .. } => false,
-            #[cfg(windows)]
+            #[cfg(all(windows, feature = "runtime"))]
             TelemetryError::WindowsApi { .. } => true,
             TelemetryError::SchemaValidation { .. } => false,
         }
@@ -191,7 +191,7 @@ impl TelemetryError {
                 "Consider IBT file replay for cross-platform testing",
                 "Check documentation for platform requirements",
             ],
-            #[cfg(windows)]
+            #[cfg(all(windows, feature = "runtime"))]
             TelemetryError::WindowsApi { .. } => vec![
                 "Check Windows API permissions",
                 "Verify system resources availability",
@@ -234,7 +234,7 @@ impl TelemetryError {
     }
 
     /// Helper constructor for Windows API errors.
-    #[cfg(windows)]
+    #[cfg(all(windows, feature = "runtime"))]
     pub fn windows_api_error(operation: impl Into<String>, source: core::Error) -> Self {
         TelemetryError::WindowsApi { operation: operation.into(), source }
     }
@@ -272,7 +272,7 @@ impl From<std::io::Error> for TelemetryError {
     }
 }
 
-#[cfg(windows)]
+#[cfg(all(windows, feature = "runtime"))]
 impl From<core::Error> for TelemetryError {
     fn from(err: core::Error) -> Self {
         TelemetryError::WindowsApi {
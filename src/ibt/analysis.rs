@@ -0,0 +1,338 @@
+//! Lap comparison for IBT recordings
+//!
+//! The delta-to-reference trace aligns two laps on `LapDistPct` and differences
+//! the time each took to reach the same point on track. Positive deltas mean the
+//! compared lap is slower than the reference at that point. [`theoretical_best`]
+//! sums the fastest time through each sector of the track across all laps.
+
+use std::ops::Range;
+
+use super::IbtReader;
+#[cfg(feature = "session")]
+use crate::SessionInfo;
+use crate::{Result, TelemetryError, VarData, VariableInfo};
+
+/// How far from a lap's first or last sample the start/finish line may be for
+/// the lap to count as crossing it, as a fraction of the lap
+#[cfg(feature = "session")]
+const LINE_TOLERANCE_PCT: f32 = 0.01;
+
+/// Distance and time samples for one lap of a recording
+#[derive(Debug, Clone, PartialEq)]
+pub struct LapFrames {
+    /// Lap number (from `Lap`)
+    pub lap: i32,
+    /// Indices of the lap's frames in the recording
+    pub frames: Range<usize>,
+    /// `LapDistPct` of each kept sample, non-decreasing
+    pub dist_pct: Vec<f32>,
+    /// `SessionTime` of each kept sample
+    pub session_time: Vec<f64>,
+}
+
+impl LapFrames {
+    /// Extract the first contiguous run of frames where `Lap == lap`.
+    ///
+    /// `LapDistPct` lags the lap counter at the start/finish line, so leading
+    /// samples still near the end of the previous lap are dropped, as is any
+    /// sample where the distance goes backwards. Returns `FieldNotFound` when the
+    /// recording lacks `Lap`, `LapDistPct` or `SessionTime`, and a parse error
+    /// when the lap is not in the recording.
+    pub fn from_reader(reader: &IbtReader, lap: i32) -> Result<Self> {
+        let variable = |name: &str| {
+            reader
+                .variables()
+                .get_variable(name)
+                .ok_or_else(|| TelemetryError::FieldNotFound { field: name.to_string() })
+        };
+        let lap_info = variable("Lap")?;
+        let dist_info = variable("LapDistPct")?;
+        let time_info = variable("SessionTime")?;
+
+        let lap_at =
+            |index: usize| reader.with_frame_bytes(index, |frame| i32::from_bytes(frame, lap_info));
+
+        let total = reader.total_frames();
+        let mut start = None;
+        for index in 0..total {
+            if lap_at(index)? == lap {
+                start = Some(index);
+                break;
+            }
+        }
+        let start = start.ok_or_else(|| TelemetryError::Parse {
+            context: "Lap extraction".to_string(),
+            details: format!("Lap {} not found in recording", lap),
+        })?;
+        let mut end = start + 1;
+        while end < total && lap_at(end)? == lap {
+            end += 1;
+        }
+
+        let mut dist_pct: Vec<f32> = Vec::with_capacity(end - start);
+        let mut session_time = Vec::with_capacity(end - start);
+        for index in start..end {
+            let (dist, time) = read_sample(reader, index, dist_info, time_info)?;
+            let keep = match dist_pct.last() {
+                Some(&previous) => dist >= previous,
+                None => dist < 0.5,
+            };
+            if keep {
+                dist_pct.push(dist);
+                session_time.push(time);
+            }
+        }
+
+        Ok(Self { lap, frames: start..end, dist_pct, session_time })
+    }
+
+    /// Time from the first to the last kept sample, in seconds
+    pub fn duration(&self) -> Option<f64> {
+        Some(self.session_time.last()? - self.session_time.first()?)
+    }
+
+    /// Seconds since the lap's first sample when it reached `dist_pct`, interpolated
+    /// between samples. `None` outside the distance range the lap covers.
+    pub fn elapsed_at(&self, dist_pct: f32) -> Option<f64> {
+        let (&first_dist, &last_dist) = (self.dist_pct.first()?, self.dist_pct.last()?);
+        if dist_pct < first_dist || dist_pct > last_dist {
+            return None;
+        }
+
+        let start = self.session_time[0];
+        let upper = self.dist_pct.partition_point(|&d| d < dist_pct);
+        if upper == 0 || self.dist_pct[upper] == dist_pct {
+            return Some(self.session_time[upper] - start);
+        }
+
+        let lower = upper - 1;
+        let span = f64::from(self.dist_pct[upper] - self.dist_pct[lower]);
+        let fraction = f64::from(dist_pct - self.dist_pct[lower]) / span;
+        let time = self.session_time[lower]
+            + fraction * (self.session_time[upper] - self.session_time[lower]);
+        Some(time - start)
+    }
+}
+
+/// Delta time of `compare` against `reference` along the lap.
+///
+/// Returns `(LapDistPct, delta seconds)` for each sample of `compare` that falls
+/// within the distance range covered by `reference`.
+pub fn lap_delta(reference: &LapFrames, compare: &LapFrames) -> Vec<(f32, f32)> {
+    let Some(&compare_start) = compare.session_time.first() else {
+        return Vec::new();
+    };
+
+    compare
+        .dist_pct
+        .iter()
+        .zip(&compare.session_time)
+        .filter_map(|(&dist, &time)| {
+            let reference_elapsed = reference.elapsed_at(dist)?;
+            Some((dist, (time - compare_start - reference_elapsed) as f32))
+        })
+        .collect()
+}
+
+/// Fastest time through one sector across a recording
+#[cfg(feature = "session")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectorBest {
+    /// Sector index in track order
+    pub sector: usize,
+    /// `(start_pct, end_pct)` of the sector
+    pub bounds: (f32, f32),
+    /// Sector time in seconds
+    pub time: f64,
+    /// Lap the time was set on
+    pub lap: i32,
+}
+
+/// Sum of the best sector times, with the lap each sector came from
+#[cfg(feature = "session")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TheoreticalBest {
+    /// Best time for each sector, in track order
+    pub sectors: Vec<SectorBest>,
+}
+
+#[cfg(feature = "session")]
+impl TheoreticalBest {
+    /// Theoretical best lap time in seconds
+    pub fn lap_time(&self) -> f64 {
+        self.sectors.iter().map(|sector| sector.time).sum()
+    }
+}
+
+/// Theoretical best lap of a recording: the fastest time through each sector
+/// across all laps, summed.
+///
+/// Sectors come from the session YAML's `SplitTimeInfo`. A lap counts towards a
+/// sector only when its samples cover the whole sector, so out laps and the
+/// unfinished last lap contribute only the sectors they complete. Returns `None`
+/// when the recording has no session YAML or sectors, or some sector was never
+/// completed, and `FieldNotFound` when it lacks `Lap`, `LapDistPct` or `SessionTime`.
+#[cfg(feature = "session")]
+pub fn theoretical_best(reader: &IbtReader) -> Result<Option<TheoreticalBest>> {
+    let Some(yaml) = reader.session_yaml()? else {
+        return Ok(None);
+    };
+    let info = SessionInfo::parse(&yaml)?;
+    let bounds = info.split_time_info.map(|split| split.sector_bounds()).unwrap_or_default();
+    if bounds.is_empty() {
+        return Ok(None);
+    }
+
+    let laps = lap_numbers(reader)?
+        .into_iter()
+        .map(|lap| LapFrames::from_reader(reader, lap))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(best_sectors(&laps, &bounds))
+}
+
+/// Lap numbers in the order they first appear in the recording
+#[cfg(feature = "session")]
+fn lap_numbers(reader: &IbtReader) -> Result<Vec<i32>> {
+    let lap_info = reader
+        .variables()
+        .get_variable("Lap")
+        .ok_or_else(|| TelemetryError::FieldNotFound { field: "Lap".to_string() })?;
+
+    let mut laps: Vec<i32> = Vec::new();
+    for index in 0..reader.total_frames() {
+        let lap = reader.with_frame_bytes(index, |frame| i32::from_bytes(frame, lap_info))?;
+        if !laps.contains(&lap) {
+            laps.push(lap);
+        }
+    }
+    Ok(laps)
+}
+
+#[cfg(feature = "session")]
+fn best_sectors(laps: &[LapFrames], bounds: &[(f32, f32)]) -> Option<TheoreticalBest> {
+    let sectors = bounds
+        .iter()
+        .enumerate()
+        .map(|(sector, &(start, end))| {
+            laps.iter()
+                .filter_map(|lap| {
+                    let time = time_at(lap, end)? - time_at(lap, start)?;
+                    Some(SectorBest { sector, bounds: (start, end), time, lap: lap.lap })
+                })
+                .min_by(|a, b| a.time.total_cmp(&b.time))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(TheoreticalBest { sectors })
+}
+
+/// Like [`LapFrames::elapsed_at`], but treats the start/finish line as reached
+/// when the lap's first or last sample lies within [`LINE_TOLERANCE_PCT`] of it.
+#[cfg(feature = "session")]
+fn time_at(lap: &LapFrames, dist_pct: f32) -> Option<f64> {
+    let (&first_dist, &last_dist) = (lap.dist_pct.first()?, lap.dist_pct.last()?);
+    if dist_pct <= 0.0 && first_dist <= LINE_TOLERANCE_PCT {
+        return Some(0.0);
+    }
+    if dist_pct >= 1.0 && last_dist >= 1.0 - LINE_TOLERANCE_PCT {
+        return lap.duration();
+    }
+    lap.elapsed_at(dist_pct)
+}
+
+fn read_sample(
+    reader: &IbtReader,
+    index: usize,
+    dist_info: &VariableInfo,
+    time_info: &VariableInfo,
+) -> Result<(f32, f64)> {
+    reader.with_frame_bytes(index, |frame| {
+        Ok((f32::from_bytes(frame, dist_info)?, f64::from_bytes(frame, time_info)?))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::synthetic_lap_ibt_bytes;
+
+    #[test]
+    fn extracts_laps_and_computes_delta_to_reference() -> Result<()> {
+        let reader = IbtReader::from_bytes(&synthetic_lap_ibt_bytes(&[90.0, 92.0, 91.0]))?;
+
+        let reference = LapFrames::from_reader(&reader, 1)?;
+        let compare = LapFrames::from_reader(&reader, 2)?;
+        assert_eq!(reference.frames, 0..5400);
+        assert_eq!(compare.frames.start, 5400);
+        assert!((compare.duration().unwrap() - 92.0).abs() < 0.05);
+
+        let delta = lap_delta(&reference, &compare);
+        assert!(!delta.is_empty());
+        let (halfway_dist, halfway_delta) = delta[delta.len() / 2];
+        assert!((halfway_dist - 0.5).abs() < 0.01);
+        assert!((halfway_delta - 1.0).abs() < 0.05, "Lap 2 loses a second by halfway");
+        let (_, final_delta) = *delta.last().unwrap();
+        assert!((final_delta - 2.0).abs() < 0.05);
+
+        assert!(LapFrames::from_reader(&reader, 7).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "session")]
+    #[test]
+    fn theoretical_best_sums_sector_bests_across_laps() -> Result<()> {
+        // Uniform pace within each lap, so lap 1 holds both halves' bests
+        let reader = IbtReader::from_bytes(&synthetic_lap_ibt_bytes(&[90.0, 92.0, 91.0]))?;
+        let best = theoretical_best(&reader)?.expect("Synthetic recording defines two sectors");
+
+        assert_eq!(best.sectors.len(), 2);
+        assert!(best.sectors.iter().all(|sector| sector.lap == 1));
+        assert!((best.lap_time() - 90.0).abs() < 0.05, "{}", best.lap_time());
+
+        // Lap 1 is quicker through the first half, lap 2 through the second
+        let laps = [
+            LapFrames {
+                lap: 1,
+                frames: 0..3,
+                dist_pct: vec![0.0, 0.5, 1.0],
+                session_time: vec![0.0, 40.0, 100.0],
+            },
+            LapFrames {
+                lap: 2,
+                frames: 3..6,
+                dist_pct: vec![0.0, 0.5, 1.0],
+                session_time: vec![100.0, 145.0, 195.0],
+            },
+        ];
+        let best = best_sectors(&laps, &[(0.0, 0.5), (0.5, 1.0)]).unwrap();
+        assert_eq!((best.sectors[0].lap, best.sectors[0].time), (1, 40.0));
+        assert_eq!((best.sectors[1].lap, best.sectors[1].time), (2, 50.0));
+        assert_eq!(best.lap_time(), 90.0);
+
+        // A sector no lap completes leaves the theoretical best undefined
+        let unfinished = LapFrames {
+            lap: 3,
+            frames: 6..8,
+            dist_pct: vec![0.0, 0.5, 0.6],
+            session_time: vec![195.0, 235.0, 245.0],
+        };
+        assert!(best_sectors(&[unfinished], &[(0.0, 0.5), (0.5, 1.0)]).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn elapsed_at_interpolates_between_samples() {
+        let lap = LapFrames {
+            lap: 1,
+            frames: 0..3,
+            dist_pct: vec![0.0, 0.5, 1.0],
+            session_time: vec![10.0, 40.0, 100.0],
+        };
+
+        assert_eq!(lap.elapsed_at(0.0), Some(0.0));
+        assert_eq!(lap.elapsed_at(0.25), Some(15.0));
+        assert_eq!(lap.elapsed_at(0.75), Some(60.0));
+        assert_eq!(lap.elapsed_at(1.0), Some(90.0));
+        assert_eq!(lap.elapsed_at(1.5), None);
+    }
+}
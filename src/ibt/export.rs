@@ -0,0 +1,367 @@
+//! Columnar export of IBT telemetry
+//!
+//! Reads selected variables from every frame into typed columns that map directly
+//! onto Arrow/Parquet types, plus file-level metadata from the session YAML.
+//! Array variables become fixed-length list columns stored flattened, row-major.
+//!
+//! With the `parquet` feature, [`export_parquet`] writes those columns to a
+//! Parquet file for analysis tools such as pandas or Polars.
+
+use super::IbtReader;
+#[cfg(feature = "session")]
+use crate::SessionInfo;
+use crate::{Result, TelemetryError, Value, VarData, VariableInfo, VariableType};
+
+/// Arrow logical type a telemetry variable is exported as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Boolean,
+    Int32,
+    Float32,
+    Float64,
+}
+
+impl ColumnType {
+    /// Map an iRacing variable type to its export type.
+    ///
+    /// Integer, char and bitfield types widen (or reinterpret, for `UInt32` and
+    /// `BitField`) to `Int32`, so bit patterns are preserved.
+    pub fn for_variable(data_type: VariableType) -> ColumnType {
+        match data_type {
+            VariableType::Bool => ColumnType::Boolean,
+            VariableType::Float32 => ColumnType::Float32,
+            VariableType::Float64 => ColumnType::Float64,
+            VariableType::Char
+            | VariableType::Int8
+            | VariableType::UInt8
+            | VariableType::Int16
+            | VariableType::UInt16
+            | VariableType::Int32
+            | VariableType::UInt32
+            | VariableType::BitField => ColumnType::Int32,
+        }
+    }
+}
+
+/// Values of one exported column, flattened across rows
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnValues {
+    Boolean(Vec<bool>),
+    Int32(Vec<i32>),
+    Float32(Vec<f32>),
+    Float64(Vec<f64>),
+}
+
+/// One exported variable
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    /// Variable name
+    pub name: String,
+    /// Units as reported by the schema
+    pub units: String,
+    /// Element type of the column
+    pub column_type: ColumnType,
+    /// List length for array variables; `None` for scalars
+    pub list_len: Option<usize>,
+    /// Values for all rows; list columns hold `list_len` values per row
+    pub values: ColumnValues,
+}
+
+/// Read `fields` from every frame of `reader` into columns.
+///
+/// Returns `FieldNotFound` if any field is missing from the file's schema.
+pub fn read_columns(reader: &IbtReader, fields: &[&str]) -> Result<Vec<Column>> {
+    let infos = fields
+        .iter()
+        .map(|name| {
+            reader
+                .variables()
+                .get_variable(name)
+                .ok_or_else(|| TelemetryError::FieldNotFound { field: name.to_string() })
+        })
+        .collect::<Result<Vec<&VariableInfo>>>()?;
+
+    let rows = reader.total_frames();
+    let mut columns: Vec<Column> = infos
+        .iter()
+        .map(|info| {
+            let column_type = ColumnType::for_variable(info.data_type);
+            let capacity = rows * info.count;
+            Column {
+                name: info.name.clone(),
+                units: info.units.clone(),
+                column_type,
+                list_len: (info.count > 1).then_some(info.count),
+                values: match column_type {
+                    ColumnType::Boolean => ColumnValues::Boolean(Vec::with_capacity(capacity)),
+                    ColumnType::Int32 => ColumnValues::Int32(Vec::with_capacity(capacity)),
+                    ColumnType::Float32 => ColumnValues::Float32(Vec::with_capacity(capacity)),
+                    ColumnType::Float64 => ColumnValues::Float64(Vec::with_capacity(capacity)),
+                },
+            }
+        })
+        .collect();
+
+    let mut elements: Vec<VariableInfo> = infos.iter().map(|info| info.element_layout()).collect();
+    for index in 0..rows {
+        reader.with_frame_bytes(index, |frame| {
+            for ((column, info), element) in columns.iter_mut().zip(&infos).zip(&mut elements) {
+                for i in 0..info.count {
+                    element.offset = info.offset + i * info.data_type.size();
+                    push_value(&mut column.values, Value::from_bytes(frame, element)?);
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(columns)
+}
+
+/// File-level metadata for the export: track name and current session type.
+///
+/// Keys are `track_name` and `session_type`; entries are omitted when the
+/// session YAML is missing or does not contain them.
+#[cfg(feature = "session")]
+pub fn export_metadata(reader: &IbtReader) -> Result<Vec<(String, String)>> {
+    let Some(yaml) = reader.session_yaml()? else {
+        return Ok(Vec::new());
+    };
+    let info = SessionInfo::parse(&yaml)?;
+
+    let mut metadata = Vec::new();
+    if !info.weekend_info.track_name.is_empty() {
+        metadata.push(("track_name".to_string(), info.weekend_info.track_name.clone()));
+    }
+    if let Some(session) = info.session_info.current_session() {
+        metadata.push(("session_type".to_string(), session.session_type.clone()));
+    }
+
+    Ok(metadata)
+}
+
+/// Write `fields` from every frame of `reader` to a Parquet file at `path`.
+///
+/// Columns are typed per [`ColumnType::for_variable`], array variables become
+/// fixed-size list columns, and each field carries its units as `units` metadata.
+/// The file's key-value metadata holds [`export_metadata`].
+#[cfg(feature = "parquet")]
+pub fn export_parquet(
+    reader: &mut IbtReader,
+    path: &std::path::Path,
+    fields: &[&str],
+) -> Result<()> {
+    use arrow::array::{ArrayRef, FixedSizeListArray, RecordBatch, RecordBatchOptions};
+    use arrow::datatypes::{Field, Schema};
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::metadata::KeyValue;
+    use parquet::file::properties::WriterProperties;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    let columns = read_columns(reader, fields)?;
+    let metadata = export_metadata(reader)?;
+
+    let mut schema_fields = Vec::with_capacity(columns.len());
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(columns.len());
+    for column in columns {
+        let values = column.values.into_array();
+        let array: ArrayRef = match column.list_len {
+            None => values,
+            Some(len) => {
+                let item = Arc::new(Field::new("item", values.data_type().clone(), false));
+                Arc::new(
+                    FixedSizeListArray::try_new(item, len as i32, values, None)
+                        .map_err(parquet_error)?,
+                )
+            }
+        };
+        let units = HashMap::from([("units".to_string(), column.units)]);
+        schema_fields
+            .push(Field::new(column.name, array.data_type().clone(), false).with_metadata(units));
+        arrays.push(array);
+    }
+
+    let schema = Arc::new(Schema::new(schema_fields));
+    let options = RecordBatchOptions::new().with_row_count(Some(reader.total_frames()));
+    let batch = RecordBatch::try_new_with_options(Arc::clone(&schema), arrays, &options)
+        .map_err(parquet_error)?;
+
+    let properties = WriterProperties::builder()
+        .set_key_value_metadata(Some(
+            metadata.into_iter().map(|(key, value)| KeyValue::new(key, value)).collect(),
+        ))
+        .build();
+    let file = std::fs::File::create(path)
+        .map_err(|e| TelemetryError::file_error(path.to_path_buf(), e))?;
+    let mut writer = ArrowWriter::try_new(file, schema, Some(properties)).map_err(parquet_error)?;
+    writer.write(&batch).map_err(parquet_error)?;
+    writer.close().map_err(parquet_error)?;
+
+    Ok(())
+}
+
+#[cfg(feature = "parquet")]
+impl ColumnValues {
+    fn into_array(self) -> arrow::array::ArrayRef {
+        use arrow::array::{BooleanArray, Float32Array, Float64Array, Int32Array};
+        use std::sync::Arc;
+
+        match self {
+            ColumnValues::Boolean(values) => Arc::new(BooleanArray::from(values)),
+            ColumnValues::Int32(values) => Arc::new(Int32Array::from(values)),
+            ColumnValues::Float32(values) => Arc::new(Float32Array::from(values)),
+            ColumnValues::Float64(values) => Arc::new(Float64Array::from(values)),
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_error(error: impl std::fmt::Display) -> TelemetryError {
+    TelemetryError::Parse { context: "Parquet export".to_string(), details: error.to_string() }
+}
+
+#[cfg(feature = "json")]
+impl IbtReader {
+    /// Write the file's variable schema to `path` as JSON.
+    ///
+    /// See [`VariableSchema::to_json`](crate::VariableSchema::to_json) for the format.
+    pub fn export_schema<P: AsRef<std::path::Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(&self.variables().to_json()).map_err(|e| {
+            TelemetryError::Parse { context: "Schema export".to_string(), details: e.to_string() }
+        })?;
+        std::fs::write(path, json).map_err(|e| TelemetryError::file_error(path.to_path_buf(), e))
+    }
+}
+
+fn push_value(values: &mut ColumnValues, value: Value) {
+    match (values, value) {
+        (ColumnValues::Boolean(v), Value::Bool(x)) => v.push(x),
+        (ColumnValues::Float32(v), Value::Float32(x)) => v.push(x),
+        (ColumnValues::Float64(v), Value::Float64(x)) => v.push(x),
+        (ColumnValues::Int32(v), value) => v.push(match value {
+            Value::Char(x) | Value::UInt8(x) => x as i32,
+            Value::Int8(x) => x as i32,
+            Value::Int16(x) => x as i32,
+            Value::UInt16(x) => x as i32,
+            Value::Int32(x) => x,
+            Value::UInt32(x) => x as i32,
+            Value::BitField(bits) => bits.value() as i32,
+            _ => unreachable!("column type is derived from the variable type"),
+        }),
+        _ => unreachable!("column type is derived from the variable type"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::synthetic_ibt_bytes;
+
+    #[test]
+    fn reads_typed_columns() -> Result<()> {
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes(8))?;
+
+        let columns = read_columns(&reader, &["Speed", "Gear"])?;
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].column_type, ColumnType::Float32);
+        assert_eq!(columns[0].list_len, None);
+        assert_eq!(columns[0].values, ColumnValues::Float32((0..8).map(|i| i as f32).collect()));
+        assert_eq!(columns[1].values, ColumnValues::Int32((0..8).map(|i| i % 6).collect()));
+
+        assert!(matches!(
+            read_columns(&reader, &["RPM"]),
+            Err(TelemetryError::FieldNotFound { field }) if field == "RPM"
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "session")]
+    #[test]
+    fn exports_session_metadata() -> Result<()> {
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes(1))?;
+        assert_eq!(
+            export_metadata(&reader)?,
+            vec![
+                ("track_name".to_string(), "synthetic".to_string()),
+                ("session_type".to_string(), "Practice".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn export_parquet_writes_typed_columns_and_metadata() -> Result<()> {
+        use arrow::array::{Float32Array, Int32Array};
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let mut reader = IbtReader::from_bytes(&synthetic_ibt_bytes(8))?;
+        let path =
+            std::env::temp_dir().join(format!("pitwall-export-{}.parquet", std::process::id()));
+
+        export_parquet(&mut reader, &path, &["Speed", "Gear"])?;
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path).unwrap()).unwrap();
+        let metadata = builder.metadata().file_metadata().key_value_metadata().unwrap().clone();
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(
+            metadata
+                .iter()
+                .any(|kv| kv.key == "track_name" && kv.value.as_deref() == Some("synthetic"))
+        );
+        assert!(
+            metadata
+                .iter()
+                .any(|kv| kv.key == "session_type" && kv.value.as_deref() == Some("Practice"))
+        );
+
+        assert_eq!(batch.num_rows(), 8);
+        assert_eq!(batch.schema().field(0).metadata()["units"], "m/s");
+        let speed = batch.column(0).as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(speed.values().to_vec(), (0..8).map(|i| i as f32).collect::<Vec<_>>());
+        let gear = batch.column(1).as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(gear.values().to_vec(), (0..8).map(|i| i % 6).collect::<Vec<_>>());
+
+        assert!(matches!(
+            export_parquet(&mut reader, &path, &["RPM"]),
+            Err(TelemetryError::FieldNotFound { .. })
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn export_schema_round_trips_through_json() -> Result<()> {
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes(2))?;
+        let path =
+            std::env::temp_dir().join(format!("pitwall-export-schema-{}.json", std::process::id()));
+
+        reader.export_schema(&path)?;
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let names: Vec<&str> = json["variables"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Gear", "SessionTime", "Speed"]);
+        assert_eq!(json["variables"][2]["type"], "Float32");
+        assert_eq!(json["variables"][2]["units"], "m/s");
+
+        let schema = crate::VariableSchema::from_json(&json)?;
+        assert!(reader.variables().diff(&schema).is_empty());
+        assert_eq!(schema.get_variable("Speed").unwrap().units, "m/s");
+
+        let mut future = json.clone();
+        future["version"] = serde_json::json!(99);
+        assert!(crate::VariableSchema::from_json(&future).is_err());
+        Ok(())
+    }
+}
@@ -123,6 +123,14 @@ impl IbtHeader {
         })
     }
 
+    /// Whether the header declares telemetry variables and a frame size
+    ///
+    /// False for files recorded with session info only. Frames are counted and
+    /// read only when this holds, and the variable schema is left empty otherwise.
+    pub fn has_telemetry(&self) -> bool {
+        self.buf_len > 0 && self.num_vars > 0
+    }
+
     pub fn validate(&self) -> Result<()> {
         if self.version != 2 {
             return Err(TelemetryError::Version { expected: 2, found: self.version as u32 });
@@ -216,10 +224,22 @@ impl IbtDiskSubHeader {
 pub fn extract_variable_schema<R: Read + Seek>(
     reader: &mut R,
     header: &IbtHeader,
+) -> Result<VariableSchema> {
+    extract_variable_schema_with(reader, header, &|_| None)
+}
+
+/// Extract variable schema from IBT file headers with a custom type mapping.
+///
+/// `type_map` is consulted first for every `var_type` code; returning `None`
+/// falls back to the built-in mapping, which skips variables of unknown type.
+pub fn extract_variable_schema_with<R: Read + Seek>(
+    reader: &mut R,
+    header: &IbtHeader,
+    type_map: &dyn Fn(i32) -> Option<VariableType>,
 ) -> Result<VariableSchema> {
     debug!("Extracting variable schema for {} variables", header.num_vars);
     // Handle IBT files with no telemetry data frames (bufLen = 0)
-    if header.buf_len == 0 || header.num_vars <= 0 {
+    if !header.has_telemetry() {
         // File contains only session info, no telemetry data
         return VariableSchema::new(HashMap::new(), 0);
     }
@@ -270,18 +290,19 @@ pub fn extract_variable_schema<R: Read + Seek>(
         }
 
         // Convert iRacing var type to our VariableType
-        let data_type = match var_type {
-            0 => VariableType::Int8,    // char
-            1 => VariableType::Bool,    // bool
-            2 => VariableType::Int32,   // int
-            3 => VariableType::Int32,   // bitField (treat as int32)
-            4 => VariableType::Float32, // float
-            5 => VariableType::Float64, // double
-            _ => {
-                // Log unknown types for diagnostics
-                debug!("Skipping variable '{}' with unknown type {}", name, var_type);
-                continue;
-            }
+        let default_type = match var_type {
+            0 => Some(VariableType::Int8),    // char
+            1 => Some(VariableType::Bool),    // bool
+            2 => Some(VariableType::Int32),   // int
+            3 => Some(VariableType::Int32),   // bitField (treat as int32)
+            4 => Some(VariableType::Float32), // float
+            5 => Some(VariableType::Float64), // double
+            _ => None,
+        };
+        let Some(data_type) = type_map(var_type).or(default_type) else {
+            // Log unknown types for diagnostics
+            debug!("Skipping variable '{}' with unknown type {}", name, var_type);
+            continue;
         };
 
         variables.insert(
@@ -299,7 +320,9 @@ pub fn extract_variable_schema<R: Read + Seek>(
     }
 
     debug!("Extracted {} variables with frame size {}", variables.len(), header.buf_len);
-    VariableSchema::new(variables, header.buf_len as usize)
+    let schema = VariableSchema::new(variables, header.buf_len as usize)?;
+    schema.validate_no_overlap()?;
+    Ok(schema)
 }
 
 /// Verify that the IBT file length is at least large enough to contain headers and all records
@@ -404,6 +427,30 @@ mod tests {
     use std::fs::File;
     use std::path::{Path, PathBuf};
 
+    #[test]
+    fn custom_type_map_keeps_variables_of_unknown_type() -> Result<()> {
+        let mut bytes = crate::test_utils::synthetic_ibt_bytes(4);
+        // Third variable header (Gear) gets a type code outside the SDK range
+        let gear_header = 144 + 32 + 2 * IRSDK_VAR_HEADER_SIZE;
+        bytes[gear_header..gear_header + 4].copy_from_slice(&42i32.to_le_bytes());
+
+        let mut cursor = std::io::Cursor::new(&bytes);
+        let header = IbtHeader::parse_from_reader(&mut cursor)?;
+        let schema = extract_variable_schema(&mut cursor, &header)?;
+        ensure!(schema.get_variable("Gear").is_none(), "Unknown type is skipped by default");
+
+        cursor.set_position(header.var_header_offset as u64);
+        let type_map = |code| (code == 42).then_some(VariableType::UInt32);
+        let schema = extract_variable_schema_with(&mut cursor, &header, &type_map)?;
+        let gear = schema.get_variable("Gear").context("Gear mapped by custom type map")?;
+        assert_eq!(gear.data_type, VariableType::UInt32);
+        assert_eq!(
+            schema.get_variable("Speed").map(|info| info.data_type),
+            Some(VariableType::Float32)
+        );
+        Ok(())
+    }
+
     fn collect_files() -> Result<Vec<PathBuf>> {
         Ok(require_ibt_fixtures()?)
     }
@@ -0,0 +1,186 @@
+//! Integrity checks for IBT recordings
+//!
+//! A truncated or corrupted download still parses: the headers are intact and
+//! frames decode to plausible-looking numbers. [`IbtReader::verify_integrity`]
+//! walks every frame and collects the anomalies it finds, so a recording can be
+//! checked before trusting an analysis run.
+
+use super::IbtReader;
+use crate::{Result, VarData};
+
+/// Anomalies beyond this many are counted but not listed individually
+const MAX_REPORTED_ANOMALIES: usize = 100;
+
+/// A problem found by [`IbtReader::verify_integrity`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntegrityAnomaly {
+    /// The disk header's `record_count` disagrees with the frames in the file
+    RecordCountMismatch {
+        /// Frames the disk header claims were recorded
+        recorded: i32,
+        /// Whole frames actually present
+        present: usize,
+    },
+    /// A frame could not be read from the file
+    FrameUnreadable {
+        /// Frame index
+        frame: usize,
+        /// Why the read failed
+        reason: String,
+    },
+    /// The recording has no `SessionTime` variable, so ordering was not checked
+    SessionTimeMissing,
+    /// `SessionTime` is NaN or infinite
+    SessionTimeInvalid {
+        /// Frame index
+        frame: usize,
+        /// Value read
+        session_time: f64,
+    },
+    /// `SessionTime` went backwards between consecutive frames
+    SessionTimeDecreased {
+        /// Frame index where time went backwards
+        frame: usize,
+        /// `SessionTime` of the previous frame
+        previous: f64,
+        /// `SessionTime` of this frame
+        session_time: f64,
+    },
+}
+
+/// Result of [`IbtReader::verify_integrity`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IntegrityReport {
+    /// Frames read during the check
+    pub frames_checked: usize,
+    /// Anomalies found, in file order, up to a limit of 100
+    pub anomalies: Vec<IntegrityAnomaly>,
+    /// Anomalies found beyond the listed ones
+    pub suppressed: usize,
+}
+
+impl IntegrityReport {
+    /// Returns true when no anomalies were found
+    pub fn is_ok(&self) -> bool {
+        self.anomalies.is_empty()
+    }
+
+    fn record(&mut self, anomaly: IntegrityAnomaly) {
+        if self.anomalies.len() < MAX_REPORTED_ANOMALIES {
+            self.anomalies.push(anomaly);
+        } else {
+            self.suppressed += 1;
+        }
+    }
+}
+
+impl IbtReader {
+    /// Check the recording for signs of truncation or corruption.
+    ///
+    /// Reads every frame, checking that it lies within the file, that
+    /// `SessionTime` is finite and never decreases, and that the disk header's
+    /// `record_count` matches the number of whole frames present. Anomalies are
+    /// collected into the report rather than failing on the first one.
+    pub fn verify_integrity(&self) -> Result<IntegrityReport> {
+        let mut report = IntegrityReport::default();
+
+        let recorded = self.disk_header().record_count;
+        let present = self.total_frames();
+        if usize::try_from(recorded).ok() != Some(present) {
+            report.record(IntegrityAnomaly::RecordCountMismatch { recorded, present });
+        }
+
+        if !self.has_telemetry() {
+            return Ok(report);
+        }
+
+        let session_time = self.variables().get_variable("SessionTime");
+        if session_time.is_none() {
+            report.record(IntegrityAnomaly::SessionTimeMissing);
+        }
+
+        let mut previous: Option<f64> = None;
+        for frame in 0..present {
+            let read = self.with_frame_bytes(frame, |bytes| {
+                session_time.map(|info| f64::from_bytes(bytes, info)).transpose()
+            });
+            report.frames_checked += 1;
+
+            let time = match read {
+                Ok(Some(time)) => time,
+                Ok(None) => continue,
+                Err(e) => {
+                    let reason = e.to_string();
+                    report.record(IntegrityAnomaly::FrameUnreadable { frame, reason });
+                    continue;
+                }
+            };
+
+            if !time.is_finite() {
+                report.record(IntegrityAnomaly::SessionTimeInvalid { frame, session_time: time });
+                continue;
+            }
+            if let Some(previous) = previous.filter(|&previous| time < previous) {
+                report.record(IntegrityAnomaly::SessionTimeDecreased {
+                    frame,
+                    previous,
+                    session_time: time,
+                });
+            }
+            previous = Some(time);
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::synthetic_ibt_bytes;
+
+    /// Offset of `record_count` in the disk sub-header
+    const RECORD_COUNT_OFFSET: usize = 144 + 28;
+
+    #[test]
+    fn clean_recording_has_no_anomalies() -> Result<()> {
+        let report = IbtReader::from_bytes(&synthetic_ibt_bytes(30))?.verify_integrity()?;
+
+        assert!(report.is_ok(), "{:?}", report.anomalies);
+        assert_eq!(report.frames_checked, 30);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_each_anomaly_without_stopping() -> Result<()> {
+        let frame_count = 30;
+        let mut bytes = synthetic_ibt_bytes(frame_count);
+        let frame_data_start = bytes.len() - frame_count * 16;
+
+        // Frame 10 jumps back in time, frame 20 is NaN
+        let frame_10 = frame_data_start + 10 * 16;
+        bytes[frame_10..frame_10 + 8].copy_from_slice(&0.05f64.to_le_bytes());
+        let frame_20 = frame_data_start + 20 * 16;
+        bytes[frame_20..frame_20 + 8].copy_from_slice(&f64::NAN.to_le_bytes());
+        // Header claims more records than the file holds
+        bytes[RECORD_COUNT_OFFSET..RECORD_COUNT_OFFSET + 4].copy_from_slice(&40i32.to_le_bytes());
+
+        let report = IbtReader::from_bytes(&bytes)?.verify_integrity()?;
+
+        assert_eq!(report.frames_checked, frame_count);
+        assert_eq!(report.anomalies.len(), 3, "{:?}", report.anomalies);
+        assert_eq!(
+            report.anomalies[0],
+            IntegrityAnomaly::RecordCountMismatch { recorded: 40, present: frame_count }
+        );
+        assert!(matches!(
+            report.anomalies[1],
+            IntegrityAnomaly::SessionTimeDecreased { frame: 10, session_time, .. } if session_time == 0.05
+        ));
+        assert!(matches!(
+            report.anomalies[2],
+            IntegrityAnomaly::SessionTimeInvalid { frame: 20, .. }
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+//! Locating IBT recordings on disk
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::{Result, TelemetryError};
+
+/// iRacing's telemetry directory, relative to the user's Documents folder
+const TELEMETRY_SUBDIR: [&str; 2] = ["iRacing", "telemetry"];
+
+/// Directory iRacing writes IBT recordings to (`Documents\iRacing\telemetry`).
+///
+/// Resolves the Documents known folder, so redirected or localized folders are
+/// handled. Returns `None` off Windows, or when the directory does not exist.
+pub fn default_telemetry_dir() -> Option<PathBuf> {
+    let dir = TELEMETRY_SUBDIR.iter().fold(documents_dir()?, |path, part| path.join(part));
+    dir.is_dir().then_some(dir)
+}
+
+#[cfg(all(windows, feature = "runtime"))]
+fn documents_dir() -> Option<PathBuf> {
+    use windows::Win32::System::Com::CoTaskMemFree;
+    use windows::Win32::UI::Shell::{FOLDERID_Documents, KF_FLAG_DEFAULT, SHGetKnownFolderPath};
+
+    // SAFETY: the returned buffer is owned by us and released with CoTaskMemFree
+    // after copying it into an owned String.
+    let known = unsafe {
+        SHGetKnownFolderPath(&FOLDERID_Documents, KF_FLAG_DEFAULT, None).ok().map(|path| {
+            let owned = path.to_string().ok();
+            CoTaskMemFree(Some(path.0 as *const _));
+            owned
+        })
+    };
+
+    known.flatten().map(PathBuf::from).or_else(|| {
+        std::env::var_os("USERPROFILE").map(|home| PathBuf::from(home).join("Documents"))
+    })
+}
+
+/// Core builds on Windows lack the `windows` crate, so assume the default location
+#[cfg(all(windows, not(feature = "runtime")))]
+fn documents_dir() -> Option<PathBuf> {
+    std::env::var_os("USERPROFILE").map(|home| PathBuf::from(home).join("Documents"))
+}
+
+#[cfg(not(windows))]
+fn documents_dir() -> Option<PathBuf> {
+    None
+}
+
+/// The `n` most recently modified `.ibt` files in `dir`, newest first.
+///
+/// The extension match is case-insensitive; subdirectories are not searched.
+pub fn list_recent(dir: impl AsRef<Path>, n: usize) -> Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| TelemetryError::file_error(dir.to_path_buf(), e))?;
+
+    let mut files: Vec<(SystemTime, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext.eq_ignore_ascii_case("ibt")))
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok().filter(|metadata| metadata.is_file())?;
+            Some((metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH), entry.path()))
+        })
+        .collect();
+
+    files.sort_by(|a, b| b.cmp(a));
+    Ok(files.into_iter().take(n).map(|(_, path)| path).collect())
+}
+
+/// The most recently modified `.ibt` file in `dir`.
+///
+/// Returns a `File` error with kind `NotFound` when `dir` holds no recordings.
+pub fn latest_in(dir: impl AsRef<Path>) -> Result<PathBuf> {
+    let dir = dir.as_ref();
+    list_recent(dir, 1)?.pop().ok_or_else(|| {
+        let source = std::io::Error::new(std::io::ErrorKind::NotFound, "No .ibt recordings found");
+        TelemetryError::file_error(dir.to_path_buf(), source)
+    })
+}
+
+/// The most recently modified recording in [`default_telemetry_dir`].
+///
+/// Returns `UnsupportedPlatform` off Windows, and a `NotFound` `File` error when
+/// the telemetry directory is missing or empty.
+pub fn latest_recording() -> Result<PathBuf> {
+    if cfg!(not(windows)) {
+        return Err(TelemetryError::unsupported_platform("Default telemetry directory", "Windows"));
+    }
+
+    match default_telemetry_dir() {
+        Some(dir) => latest_in(dir),
+        None => {
+            let dir = TELEMETRY_SUBDIR.iter().collect::<PathBuf>();
+            let source = std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "iRacing telemetry directory not found in Documents",
+            );
+            Err(TelemetryError::file_error(dir, source))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn list_recent_returns_newest_ibt_files_first() {
+        let dir = std::env::temp_dir().join(format!("pitwall-list-recent-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let base = SystemTime::now() - Duration::from_secs(60);
+        for (i, name) in ["old.ibt", "newest.IBT", "middle.ibt", "notes.txt"].iter().enumerate() {
+            let file = std::fs::File::create(dir.join(name)).unwrap();
+            let mtime = match *name {
+                "old.ibt" => base,
+                "middle.ibt" => base + Duration::from_secs(10),
+                _ => base + Duration::from_secs(20 + i as u64),
+            };
+            file.set_modified(mtime).unwrap();
+        }
+
+        let recent = list_recent(&dir, 2).unwrap();
+        let names: Vec<_> =
+            recent.iter().map(|path| path.file_name().unwrap().to_str().unwrap()).collect();
+        assert_eq!(names, vec!["newest.IBT", "middle.ibt"]);
+        assert_eq!(list_recent(&dir, 10).unwrap().len(), 3);
+
+        assert_eq!(latest_in(&dir).unwrap().file_name().unwrap(), "newest.IBT");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(list_recent(&dir, 1), Err(TelemetryError::File { .. })));
+    }
+
+    #[test]
+    fn latest_in_reports_empty_directory_as_not_found() {
+        let dir = std::env::temp_dir().join(format!("pitwall-latest-empty-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let error = latest_in(&dir).unwrap_err();
+        assert!(matches!(
+            &error,
+            TelemetryError::File { path, source }
+                if *path == dir && source.kind() == std::io::ErrorKind::NotFound
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn latest_recording_is_unsupported_off_windows() {
+        assert!(matches!(latest_recording(), Err(TelemetryError::UnsupportedPlatform { .. })));
+    }
+}
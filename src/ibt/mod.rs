@@ -3,7 +3,16 @@
 //! This module provides support for reading iRacing's IBT (telemetry) files
 //! and implementing the FrameProvider interface for unified telemetry streaming.
 
+pub mod analysis;
+pub mod export;
 pub mod format;
+pub mod integrity;
+pub mod locate;
+pub mod multi;
 pub mod reader;
+pub mod resample;
+mod timeline;
 
+pub use locate::{default_telemetry_dir, latest_in, latest_recording, list_recent};
+pub use multi::MultiReader;
 pub use reader::IbtReader;
@@ -0,0 +1,333 @@
+//! Reading several IBT files as one continuous recording
+//!
+//! iRacing starts a new file when telemetry recording is toggled or the sim
+//! reconnects, so a long stint often spans several files. [`MultiReader`]
+//! concatenates their frames in the order given and shifts `SessionTime` so the
+//! timeline does not jump back at a file boundary.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use super::{IbtReader, timeline};
+use crate::types::{FramePacket, VariableSchema, VariableType};
+use crate::{Result, TelemetryError};
+
+/// A file within a [`MultiReader`]
+struct Part {
+    reader: IbtReader,
+    /// Combined index of the file's first frame
+    first_frame: usize,
+    /// Seconds added to the file's `SessionTime`
+    time_offset: f64,
+}
+
+/// Several IBT files with the same variable layout, read as one recording.
+///
+/// Frames are numbered across all files, while each frame keeps the tick its own
+/// file's reader reports (its index within that file). A file whose
+/// `SessionTime` starts at or before the end of the previous file (because the
+/// sim restarted its clock) is shifted to begin one tick after it; files that
+/// already continue the timeline are left unchanged. The shift is applied to
+/// the `SessionTime` bytes of every frame returned.
+pub struct MultiReader {
+    parts: Vec<Part>,
+    schema: Arc<VariableSchema>,
+    total_frames: usize,
+    current_frame: usize,
+}
+
+impl MultiReader {
+    /// Open the files at `paths`, in order, as one recording.
+    ///
+    /// Fails if any file cannot be opened or its variable layout differs from
+    /// the first file's.
+    pub fn open<P: AsRef<Path>>(paths: &[P]) -> Result<Self> {
+        let readers = paths.iter().map(IbtReader::open).collect::<Result<Vec<_>>>()?;
+        Self::from_readers(readers)
+    }
+
+    /// Combine already-opened readers, in order, as one recording.
+    ///
+    /// Every reader must have the first reader's frame size and variable
+    /// layout (offsets, types and counts), or a `SchemaValidation` error is
+    /// returned. At least one reader is required.
+    pub fn from_readers(readers: Vec<IbtReader>) -> Result<Self> {
+        let Some(first) = readers.first() else {
+            return Err(TelemetryError::Parse {
+                context: "Multi-file IBT".to_string(),
+                details: "No files given".to_string(),
+            });
+        };
+
+        let schema = first.schema();
+        for reader in &readers[1..] {
+            let delta = schema.diff(reader.variables());
+            if !delta.is_empty() {
+                return Err(TelemetryError::schema_validation_error(
+                    format!(
+                        "{} does not match the variable layout of {} ({} added, {} removed, {} changed{})",
+                        reader.file_path().display(),
+                        first.file_path().display(),
+                        delta.added.len(),
+                        delta.removed.len(),
+                        delta.changed.len(),
+                        if delta.frame_size_changed { ", frame size differs" } else { "" }
+                    ),
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        let mut parts = Vec::with_capacity(readers.len());
+        let mut first_frame = 0;
+        let mut end_time: Option<f64> = None;
+        for reader in readers {
+            let range = reader.session_time_range().ok();
+            let time_offset = match (end_time, range) {
+                (Some(end), Some((start, _))) if start <= end => {
+                    end + 1.0 / reader.tick_rate() - start
+                }
+                _ => 0.0,
+            };
+            if let Some((_, last)) = range {
+                end_time = Some(last + time_offset);
+            }
+
+            let frames = reader.total_frames();
+            parts.push(Part { reader, first_frame, time_offset });
+            first_frame += frames;
+        }
+
+        Ok(Self { parts, schema, total_frames: first_frame, current_frame: 0 })
+    }
+
+    /// Get the variable schema shared by every file
+    pub fn variables(&self) -> &VariableSchema {
+        &self.schema
+    }
+
+    /// Get a shared handle to the variable schema
+    pub fn schema(&self) -> Arc<VariableSchema> {
+        Arc::clone(&self.schema)
+    }
+
+    /// Get the underlying readers, in timeline order
+    pub fn readers(&self) -> impl Iterator<Item = &IbtReader> {
+        self.parts.iter().map(|part| &part.reader)
+    }
+
+    /// Get the index of the file holding combined frame `index`
+    pub fn file_index_of(&self, index: usize) -> Option<usize> {
+        (index < self.total_frames)
+            .then(|| self.parts.partition_point(|part| part.first_frame <= index) - 1)
+    }
+
+    /// Get total number of frames across all files
+    pub fn total_frames(&self) -> usize {
+        self.total_frames
+    }
+
+    /// Get current frame position
+    pub fn current_frame(&self) -> usize {
+        self.current_frame
+    }
+
+    /// Get the tick rate of the first file
+    pub fn tick_rate(&self) -> f64 {
+        self.parts[0].reader.tick_rate()
+    }
+
+    /// Seek to a specific frame (for random access)
+    pub fn seek_to_frame(&mut self, frame_number: usize) -> Result<()> {
+        if frame_number >= self.total_frames {
+            return Err(TelemetryError::Parse {
+                context: "Frame seek".to_string(),
+                details: format!("Frame {} out of range (0..{})", frame_number, self.total_frames),
+            });
+        }
+
+        self.current_frame = frame_number;
+        Ok(())
+    }
+
+    /// Seek to the first frame at or after `session_time` on the combined timeline
+    pub fn seek_to_time(&mut self, session_time: f64) -> Result<()> {
+        let frame_number = self.find_frame_at_time(session_time)?;
+        self.seek_to_frame(frame_number)
+    }
+
+    /// Find the first frame whose shifted `SessionTime` is at or after `session_time`
+    ///
+    /// Times outside the combined range return a `Parse` error.
+    pub fn find_frame_at_time(&self, session_time: f64) -> Result<usize> {
+        let time_at = |index| self.session_time_at(index);
+        timeline::find_frame_at_time(self.total_frames, time_at, session_time)
+    }
+
+    /// Get the shifted `SessionTime` of the first and last frames
+    pub fn session_time_range(&self) -> Result<(f64, f64)> {
+        timeline::session_time_range(self.total_frames, |index| self.session_time_at(index))
+    }
+
+    /// Read the next frame as raw bytes
+    ///
+    /// Returns frame data, the tick within its file, and the session version of
+    /// the file the frame came from.
+    pub fn read_next_frame(&mut self) -> Result<Option<(Vec<u8>, u32, u32)>> {
+        let frame = self.frame_parts(self.current_frame)?;
+        if frame.is_some() {
+            self.current_frame += 1;
+        }
+        Ok(frame)
+    }
+
+    /// Iterate over the remaining frames as `FramePacket`s
+    ///
+    /// Starts at the current read position and advances it as frames are
+    /// consumed. If a frame fails to read, the error is yielded once and the
+    /// iterator then ends.
+    pub fn frames(&mut self) -> impl Iterator<Item = Result<FramePacket>> + '_ {
+        let schema = self.schema();
+        let mut failed = false;
+
+        std::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+
+            match self.read_next_frame() {
+                Ok(Some((data, tick, session_version))) => {
+                    Some(Ok(FramePacket::new(data, tick, session_version, Arc::clone(&schema))))
+                }
+                Ok(None) => None,
+                Err(e) => {
+                    failed = true;
+                    Some(Err(e))
+                }
+            }
+        })
+    }
+
+    /// Read the frame at combined `index` as a `FramePacket` (random access)
+    ///
+    /// Does not move the sequential read position. Returns `Ok(None)` past the
+    /// last frame.
+    pub fn read_frame_at(&self, index: usize) -> Result<Option<FramePacket>> {
+        Ok(self.frame_parts(index)?.map(|(data, tick, session_version)| {
+            FramePacket::new(data, tick, session_version, Arc::clone(&self.schema))
+        }))
+    }
+
+    /// File holding combined frame `index`, and the frame's index within it
+    fn locate(&self, index: usize) -> Option<(&Part, usize)> {
+        let part = &self.parts[self.file_index_of(index)?];
+        Some((part, index - part.first_frame))
+    }
+
+    /// Copy out frame `index` with its `SessionTime` shifted onto the combined timeline
+    fn frame_parts(&self, index: usize) -> Result<Option<(Vec<u8>, u32, u32)>> {
+        let Some((part, local)) = self.locate(index) else {
+            return Ok(None);
+        };
+
+        let mut data = part.reader.with_frame_bytes(local, |frame| Ok(frame.to_vec()))?;
+        if part.time_offset != 0.0 {
+            self.shift_session_time(&mut data, part.time_offset)?;
+        }
+        let session_version = part.reader.header().session_info_update as u32;
+
+        Ok(Some((data, local as u32, session_version)))
+    }
+
+    /// Add `offset` seconds to the `SessionTime` stored in `frame`
+    ///
+    /// Frames without a `Float64` `SessionTime` are left unchanged. Returns a
+    /// `Memory` error when the variable does not fit in the frame.
+    fn shift_session_time(&self, frame: &mut [u8], offset: f64) -> Result<()> {
+        let Some(info) = self
+            .schema
+            .get_variable("SessionTime")
+            .filter(|info| info.data_type == VariableType::Float64)
+        else {
+            return Ok(());
+        };
+        let bytes =
+            info.offset.checked_add(8).and_then(|end| frame.get_mut(info.offset..end)).ok_or_else(
+                || TelemetryError::Memory {
+                    offset: info.offset,
+                    source: Some(
+                        format!("SessionTime at offset {} does not fit in the frame", info.offset)
+                            .into(),
+                    ),
+                },
+            )?;
+        let mut time = [0u8; 8];
+        time.copy_from_slice(bytes);
+        bytes.copy_from_slice(&(f64::from_le_bytes(time) + offset).to_le_bytes());
+        Ok(())
+    }
+
+    /// Shifted `SessionTime` of combined frame `index`
+    fn session_time_at(&self, index: usize) -> Result<f64> {
+        let (part, local) = self.locate(index).ok_or_else(|| TelemetryError::Parse {
+            context: "Frame reading".to_string(),
+            details: format!("Frame {} out of range (0..{})", index, self.total_frames),
+        })?;
+
+        Ok(part.reader.session_time_at(local)? + part.time_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{synthetic_ibt_bytes, synthetic_lap_ibt_bytes, write_synthetic_ibt};
+
+    #[test]
+    fn concatenates_files_on_a_continuous_timeline() -> Result<()> {
+        let second = write_synthetic_ibt("multi-reader-second", 30);
+        let mut reader = MultiReader::from_readers(vec![
+            IbtReader::from_bytes(&synthetic_ibt_bytes(60))?,
+            IbtReader::open(&second)?,
+        ])?;
+        std::fs::remove_file(&second).unwrap();
+
+        assert_eq!(reader.total_frames(), 90);
+        assert_eq!(reader.file_index_of(59), Some(0));
+        assert_eq!(reader.file_index_of(60), Some(1));
+        assert_eq!(reader.file_index_of(90), None);
+
+        // Both files start at SessionTime 0, so the second is shifted to follow the first
+        let (first, last) = reader.session_time_range()?;
+        assert_eq!(first, 0.0);
+        assert!((last - 89.0 / 60.0).abs() < 1e-9);
+
+        let packet = reader.read_frame_at(60)?.expect("Frame 60 exists");
+        assert_eq!(packet.tick, 0, "Ticks are kept from the original file");
+        assert!((packet.get_f64("SessionTime")? - 1.0).abs() < 1e-9);
+        assert_eq!(packet.get_f32("Speed")?, 0.0, "Other variables are untouched");
+
+        reader.seek_to_time(1.25)?;
+        assert_eq!(reader.current_frame(), 75);
+        let remaining: Vec<FramePacket> = reader.frames().collect::<Result<_>>()?;
+        assert_eq!(remaining.len(), 15);
+        assert_eq!(remaining.last().unwrap().tick, 29);
+        assert!(reader.read_next_frame()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_files_with_a_different_layout() -> Result<()> {
+        let error = MultiReader::from_readers(vec![
+            IbtReader::from_bytes(&synthetic_ibt_bytes(10))?,
+            IbtReader::from_bytes(&synthetic_lap_ibt_bytes(&[1.0]))?,
+        ])
+        .err()
+        .expect("Layouts differ");
+        assert!(matches!(error, TelemetryError::SchemaValidation { .. }), "{}", error);
+
+        assert!(MultiReader::from_readers(Vec::new()).is_err());
+        Ok(())
+    }
+}
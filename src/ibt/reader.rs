@@ -14,10 +14,11 @@
 //!     println!("File contains {} frames", reader.total_frames());
 //!
 //!     // Read frames sequentially
-//!     while let Some((frame_data, tick, session_version)) = reader.read_next_frame()? {
+//!     for frame in reader.frames() {
+//!         let frame = frame?;
 //!         println!("Frame at tick {} with session version {}",
-//!             tick,
-//!             session_version);
+//!             frame.tick,
+//!             frame.session_version);
 //!     }
 //!
 //!     Ok(())
@@ -27,26 +28,74 @@
 //! ## Performance Notes
 //!
 //! - File data is loaded into memory at construction time for fast random access
+//! - With the `mmap` feature, `IbtReader::open_mmap` maps the file instead of reading it
 //! - Frame reading is zero-allocation except for the returned `RawFrame`
 //! - Seeking operations are O(1) as they only update internal position counters
 
 use super::format::{IRSDK_VAR_HEADER_SIZE, IbtDiskSubHeader, IbtHeader, extract_variable_schema};
-use crate::{Result, TelemetryError, VariableSchema, yaml_utils};
+use super::timeline;
+use crate::{
+    AdapterValidation, FrameAdapter, FramePacket, Result, SchemaProvider, TelemetryError, VarData,
+    VariableSchema, yaml_utils,
+};
 use std::fs::File;
-use std::io::Read;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use tracing::warn;
 
+/// Seekable byte source for readers opened with [`IbtReader::open_from_reader`]
+trait ReadSeek: Read + Seek + Send {}
+
+impl<R: Read + Seek + Send> ReadSeek for R {}
+
+/// Backing storage for the raw IBT file contents
+enum IbtData {
+    /// File contents read fully into memory
+    Owned(Vec<u8>),
+    /// Read-only memory map of the file
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+    /// Headers and session info held in memory; frames read from the source on demand
+    Streamed { head: Vec<u8>, source: Mutex<Box<dyn ReadSeek>> },
+}
+
+impl std::ops::Deref for IbtData {
+    type Target = [u8];
+
+    /// Resident bytes; for `Streamed` this stops where frame data begins
+    fn deref(&self) -> &[u8] {
+        match self {
+            IbtData::Owned(data) => data,
+            #[cfg(feature = "mmap")]
+            IbtData::Mapped(map) => map,
+            IbtData::Streamed { head, .. } => head,
+        }
+    }
+}
+
+/// File layout parsed from the IBT headers
+struct IbtLayout {
+    header: IbtHeader,
+    disk_header: IbtDiskSubHeader,
+    variable_schema: VariableSchema,
+    frame_data_start: usize,
+    total_frames: usize,
+    partial_frame_bytes: usize,
+}
+
 /// IBT file reader that implements FrameProvider for cross-platform replay
 pub struct IbtReader {
-    data: Vec<u8>,
+    data: IbtData,
     current_position: usize,
     path: PathBuf,
     header: IbtHeader,
     disk_header: IbtDiskSubHeader,
-    variable_schema: VariableSchema,
+    variable_schema: Arc<VariableSchema>,
     current_frame: usize,
     total_frames: usize,
+    /// Bytes of an incomplete frame after the last whole one
+    partial_frame_bytes: usize,
     frame_data_start: usize,
 }
 
@@ -60,27 +109,146 @@ impl IbtReader {
         file.read_to_end(&mut data)
             .map_err(|e| TelemetryError::File { path: path.as_ref().to_path_buf(), source: e })?;
 
-        Self::from_bytes_with_path(&data, path.as_ref().to_path_buf())
+        Self::from_backing(IbtData::Owned(data), path.as_ref().to_path_buf())
+    }
+
+    /// Open an IBT file by memory-mapping it read-only
+    ///
+    /// Avoids loading the whole file into RAM, which matters for multi-hour
+    /// endurance recordings. While the reader is alive the file may only grow:
+    /// bytes already written must not be rewritten and the file must not be
+    /// truncated. Appended frames become visible after
+    /// [`poll_new_frames`](Self::poll_new_frames) re-maps the file. iRacing
+    /// rewrites the header when it closes a recording, so tail a recording in
+    /// progress with [`open_tailing`](Self::open_tailing) instead.
+    #[cfg(feature = "mmap")]
+    pub fn open_mmap<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(&path)
+            .map_err(|e| TelemetryError::File { path: path.as_ref().to_path_buf(), source: e })?;
+
+        // Safety: The map is read-only, and the caller guarantees the file is only
+        // appended to while mapped (see above), so mapped bytes never change and the
+        // mapped range never shrinks. All access goes through bounds-checked slicing.
+        let map = unsafe { memmap2::Mmap::map(&file) }
+            .map_err(|e| TelemetryError::File { path: path.as_ref().to_path_buf(), source: e })?;
+
+        Self::from_backing(IbtData::Mapped(map), path.as_ref().to_path_buf())
+    }
+
+    /// Open an IBT file that is still being written, e.g. by a running iRacing session.
+    ///
+    /// The file must already contain its header, variable headers and session info.
+    /// Call [`poll_new_frames`](Self::poll_new_frames) to pick up frames appended
+    /// since the last poll; `read_next_frame` then continues with them.
+    pub fn open_tailing<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open(path)
+    }
+
+    /// Re-read the file and expose frames written since the last poll.
+    ///
+    /// Returns the number of newly available frames. A partially written final
+    /// frame is not exposed until it is complete. Works for any reader opened
+    /// from a path; readers opened with [`open_from_reader`](Self::open_from_reader)
+    /// re-measure their source. Memory-mapped readers are re-mapped, which relies
+    /// on the append-only contract of [`open_mmap`](Self::open_mmap).
+    pub fn poll_new_frames(&mut self) -> Result<usize> {
+        let file_error = |source| TelemetryError::File { path: self.path.clone(), source };
+
+        let data_len = match &mut self.data {
+            IbtData::Streamed { source, .. } => {
+                let source = source.get_mut().unwrap_or_else(|poisoned| poisoned.into_inner());
+                source.seek(SeekFrom::End(0)).map_err(file_error)? as usize
+            }
+            IbtData::Owned(data) => {
+                // Frames are only ever appended, so read from where we left off
+                let mut file = File::open(&self.path).map_err(file_error)?;
+                file.seek(SeekFrom::Start(data.len() as u64)).map_err(file_error)?;
+                file.read_to_end(data).map_err(file_error)?;
+                data.len()
+            }
+            #[cfg(feature = "mmap")]
+            IbtData::Mapped(map) => {
+                let file = File::open(&self.path).map_err(file_error)?;
+                // Safety: same contract as `open_mmap`. The file is only appended to, so the
+                // new map covers the old bytes unchanged and only adds frames past them.
+                *map = unsafe { memmap2::Mmap::map(&file) }.map_err(file_error)?;
+                map.len()
+            }
+        };
+
+        let remaining_bytes = data_len.saturating_sub(self.frame_data_start);
+        let total_frames = complete_frames(remaining_bytes, &self.header);
+        let new_frames = total_frames.saturating_sub(self.total_frames);
+        self.total_frames = total_frames.max(self.total_frames);
+        self.partial_frame_bytes = partial_frame_bytes(remaining_bytes, &self.header);
+
+        Ok(new_frames)
     }
 
     /// Create IbtReader from bytes (for testing)
     pub fn from_bytes(data: &[u8]) -> Result<Self> {
-        Self::from_bytes_with_path(data, PathBuf::from("<memory>"))
+        Self::from_backing(IbtData::Owned(data.to_vec()), PathBuf::from("<memory>"))
     }
 
-    /// Create IbtReader from bytes with path context
-    fn from_bytes_with_path(data: &[u8], path: PathBuf) -> Result<Self> {
-        let mut cursor = std::io::Cursor::new(data);
+    /// Open an IBT recording from any seekable byte source
+    ///
+    /// Headers, variable headers and session info are read up front; frames are
+    /// read from `source` on demand, so the recording is never fully loaded.
+    /// Suited to object storage or HTTP range readers. Zero-copy access through
+    /// [`raw_frame_at`](Self::raw_frame_at) is not available for these readers.
+    pub fn open_from_reader<R>(mut source: R) -> Result<Self>
+    where
+        R: Read + Seek + Send + 'static,
+    {
+        let path = PathBuf::from("<reader>");
+        let io_error = |source| TelemetryError::File { path: path.clone(), source };
+
+        let len = source.seek(SeekFrom::End(0)).map_err(io_error)? as usize;
+        source.seek(SeekFrom::Start(0)).map_err(io_error)?;
+        let layout = Self::parse_layout(&mut source, len)?;
+
+        // Keep everything before the frame data resident for session info access
+        let mut head = vec![0u8; layout.frame_data_start];
+        source.seek(SeekFrom::Start(0)).map_err(io_error)?;
+        source.read_exact(&mut head).map_err(io_error)?;
+
+        let data = IbtData::Streamed { head, source: Mutex::new(Box::new(source)) };
+        Ok(Self::from_layout(data, path, layout))
+    }
 
+    /// Create IbtReader from its backing storage with path context
+    fn from_backing(backing: IbtData, path: PathBuf) -> Result<Self> {
+        let data: &[u8] = &backing;
+        let layout = Self::parse_layout(&mut std::io::Cursor::new(data), data.len())?;
+        Ok(Self::from_layout(backing, path, layout))
+    }
+
+    fn from_layout(data: IbtData, path: PathBuf, layout: IbtLayout) -> Self {
+        IbtReader {
+            data,
+            current_position: layout.frame_data_start,
+            path,
+            header: layout.header,
+            disk_header: layout.disk_header,
+            variable_schema: Arc::new(layout.variable_schema),
+            current_frame: 0,
+            total_frames: layout.total_frames,
+            partial_frame_bytes: layout.partial_frame_bytes,
+            frame_data_start: layout.frame_data_start,
+        }
+    }
+
+    /// Parse headers and the variable schema from a source of `len` bytes
+    fn parse_layout<R: Read + Seek>(cursor: &mut R, len: usize) -> Result<IbtLayout> {
         // Parse IBT header
-        let header = IbtHeader::parse_from_reader(&mut cursor)?;
+        let header = IbtHeader::parse_from_reader(cursor)?;
         header.validate()?;
 
         // Parse disk sub-header (note: may be corrupted, but we'll try)
-        let disk_header = IbtDiskSubHeader::parse_from_reader(&mut cursor)?;
+        let disk_header = IbtDiskSubHeader::parse_from_reader(cursor)?;
 
         // Extract variable schema
-        let variable_schema = extract_variable_schema(&mut cursor, &header)?;
+        let variable_schema = extract_variable_schema(cursor, &header)?;
 
         // Calculate frame data start position correctly with checked arithmetic
         // Frame data starts AFTER both variable headers AND session info
@@ -118,16 +286,13 @@ impl IbtReader {
 
         // Calculate total frames based on remaining file data with bounds checking
         let remaining_bytes =
-            data.len().checked_sub(frame_data_start).ok_or_else(|| TelemetryError::Parse {
+            len.checked_sub(frame_data_start).ok_or_else(|| TelemetryError::Parse {
                 context: "Frame data calculation".to_string(),
                 details: "Frame data start position exceeds file size".to_string(),
             })?;
 
-        let total_frames = if header.buf_len > 0 {
-            remaining_bytes / header.buf_len as usize
-        } else {
-            0 // No telemetry data if buf_len is 0
-        };
+        let total_frames = complete_frames(remaining_bytes, &header);
+        let partial_frame_bytes = partial_frame_bytes(remaining_bytes, &header);
 
         // Cross-check disk_header.record_count against total_frames for debugging
         if disk_header.record_count > 0 && total_frames > 0 {
@@ -140,19 +305,14 @@ impl IbtReader {
             }
         }
 
-        let reader = IbtReader {
-            data: data.to_vec(),
-            current_position: frame_data_start,
-            path,
+        Ok(IbtLayout {
             header,
             disk_header,
             variable_schema,
-            current_frame: 0,
-            total_frames,
             frame_data_start,
-        };
-
-        Ok(reader)
+            total_frames,
+            partial_frame_bytes,
+        })
     }
 
     /// Get cleaned session YAML from the IBT file
@@ -167,7 +327,7 @@ impl IbtReader {
         }
 
         // Extract raw YAML from memory
-        let raw_yaml = yaml_utils::extract_yaml_from_memory(
+        let raw_yaml = yaml_utils::extract_yaml(
             &self.data,
             self.header.session_info_offset,
             self.header.session_info_len,
@@ -189,11 +349,42 @@ impl IbtReader {
         &self.variable_schema
     }
 
+    /// Get a shared handle to the variable schema
+    ///
+    /// The schema is parsed once at construction; every `FramePacket` built by
+    /// this reader shares the same `Arc`.
+    pub fn schema(&self) -> Arc<VariableSchema> {
+        Arc::clone(&self.variable_schema)
+    }
+
+    /// Check that adapter `T` can read this file, without opening a connection
+    ///
+    /// Runs `T::validate_schema` against the file's schema and returns the
+    /// resulting plan, or the same field-mapping error (with "did you mean"
+    /// suggestions) a connection would fail with. Useful for checking an
+    /// adapter against recordings of several cars in CI.
+    pub fn validate_adapter<T: FrameAdapter>(&self) -> Result<AdapterValidation> {
+        T::validate_schema(&self.variable_schema)
+    }
+
     /// Get total number of frames in the file
+    ///
+    /// Zero either for a file recorded with session info only or for a truncated
+    /// file; use [`has_telemetry`](Self::has_telemetry) to tell them apart.
     pub fn total_frames(&self) -> usize {
         self.total_frames
     }
 
+    /// Whether the file declares telemetry variables and a frame size
+    ///
+    /// iRacing writes files with `bufLen == 0` and no variables when only session info
+    /// was recorded. Those open successfully with an empty schema and no frames, while a
+    /// file that declares telemetry but was cut off before any frame returns `true` here
+    /// with `total_frames() == 0`. Every frame accessor checks the same condition.
+    pub fn has_telemetry(&self) -> bool {
+        self.header.has_telemetry()
+    }
+
     /// Get current frame position
     pub fn current_frame(&self) -> usize {
         self.current_frame
@@ -201,7 +392,8 @@ impl IbtReader {
 
     /// Get the tick rate from IBT header
     ///
-    /// Returns the actual recording frequency, or 60Hz as fallback if invalid.
+    /// Returns the actual recording frequency (e.g. 60Hz or 360Hz). A header
+    /// `tick_rate` of 0 or less falls back to 60Hz.
     pub fn tick_rate(&self) -> f64 {
         if self.header.tick_rate > 0 {
             self.header.tick_rate as f64
@@ -256,6 +448,57 @@ impl IbtReader {
         Ok(())
     }
 
+    /// Seek to the first frame at or after `session_time` (in seconds)
+    pub fn seek_to_time(&mut self, session_time: f64) -> Result<()> {
+        let frame_number = self.find_frame_at_time(session_time)?;
+        self.seek_to_frame(frame_number)
+    }
+
+    /// Find the first frame whose `SessionTime` is at or after `session_time`
+    ///
+    /// Binary-searches the recording, so `SessionTime` must be non-decreasing.
+    /// Times outside the recorded range return a `Parse` error.
+    pub fn find_frame_at_time(&self, session_time: f64) -> Result<usize> {
+        let time_at = |index| self.session_time_at(index);
+        timeline::find_frame_at_time(self.total_frames, time_at, session_time)
+    }
+
+    /// Read the frames with `start_s <= SessionTime <= end_s`, in order.
+    ///
+    /// Binary-searches both ends of the window, then seeks to its first frame and
+    /// reads sequentially, leaving the read position after the last frame
+    /// returned. A window reaching past either end of the recording is clipped
+    /// to it; one outside it (or with `start_s > end_s`) returns no frames and
+    /// does not move the read position.
+    pub fn frames_in_time_range(&mut self, start_s: f64, end_s: f64) -> Result<Vec<FramePacket>> {
+        let (first, last) = self.session_time_range()?;
+        let overlaps = start_s <= end_s && start_s <= last && end_s >= first;
+        if !overlaps {
+            return Ok(Vec::new());
+        }
+
+        let time_at = |index| self.session_time_at(index);
+        let start = timeline::partition_by_time(self.total_frames, time_at, |time| time < start_s)?;
+        let end = timeline::partition_by_time(self.total_frames, time_at, |time| time <= end_s)?;
+        self.seek_to_frame(start)?;
+        self.frames().take(end - start).collect()
+    }
+
+    /// Get the `SessionTime` of the first and last frames
+    pub fn session_time_range(&self) -> Result<(f64, f64)> {
+        timeline::session_time_range(self.total_frames, |index| self.session_time_at(index))
+    }
+
+    /// Read the `SessionTime` variable from the frame at `index`
+    pub(crate) fn session_time_at(&self, index: usize) -> Result<f64> {
+        let info = self
+            .variable_schema
+            .get_variable("SessionTime")
+            .ok_or_else(|| TelemetryError::FieldNotFound { field: "SessionTime".to_string() })?;
+
+        self.with_frame_bytes(index, |frame| f64::from_bytes(frame, info))
+    }
+
     /// Read the next frame as raw bytes
     ///
     /// Returns frame data, tick count, and session version for FramePacket construction
@@ -266,42 +509,190 @@ impl IbtReader {
         }
 
         // Handle IBT files with no telemetry data
-        if self.header.buf_len == 0 {
+        if !self.has_telemetry() {
             return Ok(None);
         }
 
-        let frame_size = self.header.buf_len as usize;
-        let start_pos = self.current_position;
-        let end_pos = start_pos + frame_size;
+        let frame_data = self.with_frame_bytes(self.current_frame, |frame| Ok(frame.to_vec()))?;
+        let tick_count = self.current_frame as u32;
+        let session_version = self.header.session_info_update as u32;
 
-        if end_pos > self.data.len() {
+        // Advance to next frame
+        self.current_frame += 1;
+        self.current_position += frame_data.len();
+
+        Ok(Some((frame_data, tick_count, session_version)))
+    }
+
+    /// Iterate over the remaining frames as `FramePacket`s
+    ///
+    /// Starts at the current read position and advances it as frames are
+    /// consumed. If a frame fails to read, the error is yielded once and the
+    /// iterator then ends. All packets share the reader's schema `Arc`.
+    pub fn frames(&mut self) -> impl Iterator<Item = Result<FramePacket>> + '_ {
+        let schema = self.schema();
+        let mut failed = false;
+
+        std::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+
+            match self.read_next_frame() {
+                Ok(Some((data, tick, session_version))) => {
+                    Some(Ok(FramePacket::new(data, tick, session_version, Arc::clone(&schema))))
+                }
+                Ok(None) => None,
+                Err(e) => {
+                    failed = true;
+                    Some(Err(e))
+                }
+            }
+        })
+    }
+
+    /// Read the frame at `index` as a `FramePacket` (random access)
+    ///
+    /// Does not move the sequential read position, so it is safe to call from
+    /// indexed or parallel loops. Returns `Ok(None)` past the last frame, and a
+    /// `Parse` error for a final frame the file was cut off partway through.
+    pub fn read_frame_at(&self, index: usize) -> Result<Option<FramePacket>> {
+        self.check_not_truncated(index)?;
+        if index >= self.total_frames || !self.has_telemetry() {
+            return Ok(None);
+        }
+
+        let frame_data = self.with_frame_bytes(index, |frame| Ok(frame.to_vec()))?;
+
+        Ok(Some(FramePacket::new(
+            frame_data,
+            index as u32,
+            self.header.session_info_update as u32,
+            Arc::clone(&self.variable_schema),
+        )))
+    }
+
+    /// Borrow the raw bytes of the frame at `index` without copying
+    ///
+    /// Returns `Ok(None)` past the last frame and a `Parse` error for a truncated
+    /// final frame. Does not move the sequential read position. Readers opened with
+    /// [`open_from_reader`](Self::open_from_reader) do not hold frames in memory and
+    /// return a `Parse` error; use [`read_frame_at`](Self::read_frame_at) instead.
+    pub fn raw_frame_at(&self, index: usize) -> Result<Option<&[u8]>> {
+        self.check_not_truncated(index)?;
+        if index >= self.total_frames || !self.has_telemetry() {
+            return Ok(None);
+        }
+
+        self.frame_bytes(index).map(Some)
+    }
+
+    /// Run `f` on the bytes of the frame at `index`, whatever the backing storage
+    ///
+    /// Resident data is borrowed; streamed readers read the frame into a
+    /// temporary buffer first.
+    pub(crate) fn with_frame_bytes<T>(
+        &self,
+        index: usize,
+        f: impl FnOnce(&[u8]) -> Result<T>,
+    ) -> Result<T> {
+        let IbtData::Streamed { source, .. } = &self.data else {
+            return f(self.frame_bytes(index)?);
+        };
+
+        if index >= self.total_frames {
+            return Err(TelemetryError::Parse {
+                context: "Frame reading".to_string(),
+                details: format!("Frame {} out of range (0..{})", index, self.total_frames),
+            });
+        }
+
+        let io_error = |source| TelemetryError::File { path: self.path.clone(), source };
+        let range = self.frame_range(index)?;
+        let mut frame = vec![0u8; range.len()];
+        let mut source = source.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        source.seek(SeekFrom::Start(range.start as u64)).map_err(io_error)?;
+        source.read_exact(&mut frame).map_err(io_error)?;
+        drop(source);
+
+        f(&frame)
+    }
+
+    /// Borrow the raw bytes of the frame at `index` with bounds checking
+    fn frame_bytes(&self, index: usize) -> Result<&[u8]> {
+        if matches!(self.data, IbtData::Streamed { .. }) {
+            return Err(TelemetryError::Parse {
+                context: "Frame reading".to_string(),
+                details: "Frames of a streamed reader are not held in memory".to_string(),
+            });
+        }
+
+        let range = self.frame_range(index)?;
+        let end_pos = range.end;
+
+        self.data.get(range).ok_or_else(|| TelemetryError::Parse {
+            context: "Frame reading".to_string(),
+            details: format!(
+                "Frame {} extends beyond data bounds ({} > {})",
+                index,
+                end_pos,
+                self.data.len()
+            ),
+        })
+    }
+
+    /// Fail with a `Parse` error when `index` is a final frame the file cut off
+    fn check_not_truncated(&self, index: usize) -> Result<()> {
+        if index == self.total_frames && self.partial_frame_bytes > 0 {
             return Err(TelemetryError::Parse {
                 context: "Frame reading".to_string(),
                 details: format!(
-                    "Frame {} extends beyond data bounds ({} > {})",
-                    self.current_frame,
-                    end_pos,
-                    self.data.len()
+                    "Frame {} is truncated ({} of {} bytes)",
+                    index, self.partial_frame_bytes, self.header.buf_len
                 ),
             });
         }
+        Ok(())
+    }
 
-        let frame_data = self.data[start_pos..end_pos].to_vec();
-        let tick_count = self.current_frame as u32;
-        let session_version = self.header.session_info_update as u32;
+    /// Byte range of the frame at `index` within the file, with checked arithmetic
+    fn frame_range(&self, index: usize) -> Result<std::ops::Range<usize>> {
+        let frame_size = self.header.buf_len as usize;
+        index
+            .checked_mul(frame_size)
+            .and_then(|offset| self.frame_data_start.checked_add(offset))
+            .and_then(|start| Some(start..start.checked_add(frame_size)?))
+            .ok_or_else(|| TelemetryError::Parse {
+                context: "Frame reading".to_string(),
+                details: format!("Frame {} position calculation overflowed", index),
+            })
+    }
+}
 
-        // Advance to next frame
-        self.current_frame += 1;
-        self.current_position = end_pos;
+/// Number of whole frames in `bytes` of frame data (0 when the file has no telemetry)
+fn complete_frames(bytes: usize, header: &IbtHeader) -> usize {
+    if header.has_telemetry() { bytes / header.buf_len as usize } else { 0 }
+}
 
-        Ok(Some((frame_data, tick_count, session_version)))
+/// Bytes of frame data left over after the whole frames in `bytes`
+fn partial_frame_bytes(bytes: usize, header: &IbtHeader) -> usize {
+    if header.has_telemetry() { bytes % header.buf_len as usize } else { 0 }
+}
+
+impl SchemaProvider for IbtReader {
+    fn get_schema(&self) -> &VariableSchema {
+        &self.variable_schema
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::require_smallest_ibt_fixture;
+    #[cfg(feature = "mmap")]
+    use crate::test_utils::write_synthetic_ibt;
+    use crate::test_utils::{
+        require_smallest_ibt_fixture, synthetic_ibt_bytes, synthetic_ibt_bytes_at_rate,
+    };
     use anyhow::{Context, Result, ensure};
 
     use std::path::PathBuf;
@@ -525,6 +916,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "session")]
     #[test]
     fn test_real_ibt_session_yaml_extraction() -> Result<()> {
         let test_file = fixture_path()?;
@@ -572,4 +964,255 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_frame_at_builds_packets_without_moving_cursor() -> Result<()> {
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes(10))?;
+        assert_eq!(reader.total_frames(), 10);
+
+        let packet = reader.read_frame_at(7)?.expect("Frame 7 should exist");
+        assert_eq!(packet.tick, 7);
+        assert_eq!(packet.session_version, 1);
+        assert!(Arc::ptr_eq(&packet.schema, &reader.schema()));
+
+        let speed = packet.schema.get_variable("Speed").expect("Speed variable");
+        assert_eq!(f32::from_bytes(&packet.data, speed)?, 7.0);
+
+        assert_eq!(reader.current_frame(), 0, "Random access must not move the cursor");
+        assert!(reader.read_frame_at(10)?.is_none());
+        assert!(reader.read_frame_at(usize::MAX)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_frame_at_rejects_truncated_final_frame() -> Result<()> {
+        // Cut the recording off halfway through its last 16-byte frame
+        let full = synthetic_ibt_bytes(5);
+        let reader = IbtReader::from_bytes(&full[..full.len() - 8])?;
+        assert_eq!(reader.total_frames(), 4);
+
+        assert_eq!(reader.read_frame_at(3)?.expect("Frame 3 is complete").get_f32("Speed")?, 3.0);
+        let err = reader.read_frame_at(4).expect_err("Frame 4 is cut off");
+        assert!(
+            matches!(&err, TelemetryError::Parse { details, .. } if details.contains("truncated")),
+            "Unexpected error: {err:?}"
+        );
+        assert!(reader.raw_frame_at(4).is_err());
+        assert!(reader.read_frame_at(5)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_frames_iterates_from_current_position() -> Result<()> {
+        let mut reader = IbtReader::from_bytes(&synthetic_ibt_bytes(10))?;
+        reader.seek_to_frame(4)?;
+        let schema = reader.schema();
+
+        let ticks = reader
+            .frames()
+            .map(|frame| {
+                let frame = frame?;
+                ensure!(Arc::ptr_eq(&frame.schema, &schema), "Schema should be shared");
+                Ok(frame.tick)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        assert_eq!(ticks, vec![4, 5, 6, 7, 8, 9]);
+        assert_eq!(reader.current_frame(), 10);
+        assert!(reader.frames().next().is_none(), "Exhausted reader should yield nothing");
+        Ok(())
+    }
+
+    #[test]
+    fn test_raw_frame_at_borrows_frame_bytes() -> Result<()> {
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes(4))?;
+
+        let raw = reader.raw_frame_at(2)?.expect("Frame 2 should exist");
+        let packet = reader.read_frame_at(2)?.expect("Frame 2 should exist");
+        assert_eq!(raw, &packet.data[..]);
+        assert!(reader.raw_frame_at(4)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_from_reader_reads_frames_lazily() -> Result<()> {
+        let bytes = synthetic_ibt_bytes(6);
+        let owned = IbtReader::from_bytes(&bytes)?;
+        let mut streamed = IbtReader::open_from_reader(std::io::Cursor::new(bytes))?;
+
+        assert_eq!(streamed.total_frames(), 6);
+        assert_eq!(streamed.session_yaml()?, owned.session_yaml()?);
+        assert_eq!(streamed.session_time_range()?, owned.session_time_range()?);
+        assert_eq!(streamed.find_frame_at_time(3.0 / 60.0)?, 3);
+
+        let packet = streamed.read_frame_at(4)?.expect("Frame 4 should exist");
+        assert_eq!(&packet.data[..], owned.raw_frame_at(4)?.expect("Frame 4 should exist"));
+        assert!(streamed.read_frame_at(6)?.is_none());
+        ensure!(streamed.raw_frame_at(4).is_err(), "Streamed frames are not resident");
+
+        streamed.seek_to_frame(5)?;
+        let (data, tick, _) = streamed.read_next_frame()?.expect("Frame 5 should exist");
+        assert_eq!((&data[..], tick), (owned.raw_frame_at(5)?.unwrap(), 5));
+        assert!(streamed.read_next_frame()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_telemetry_distinguishes_session_only_from_truncated() -> Result<()> {
+        let truncated = IbtReader::from_bytes(&synthetic_ibt_bytes(0))?;
+        assert!(truncated.has_telemetry());
+        assert_eq!(truncated.total_frames(), 0);
+
+        // Same file with the header's bufLen and numVars zeroed, as iRacing writes it
+        let mut bytes = synthetic_ibt_bytes(0);
+        bytes[24..28].copy_from_slice(&0i32.to_le_bytes());
+        bytes[36..40].copy_from_slice(&0i32.to_le_bytes());
+        let mut session_only = IbtReader::from_bytes(&bytes)?;
+        assert!(!session_only.has_telemetry());
+        assert_eq!(session_only.total_frames(), 0);
+        assert!(session_only.session_yaml()?.is_some(), "Session info is still readable");
+        assert!(session_only.read_next_frame()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_accessors_follow_has_telemetry() -> Result<()> {
+        // Frame data is present, but with no variables declared it cannot be read
+        let mut bytes = synthetic_ibt_bytes(4);
+        bytes[24..28].copy_from_slice(&0i32.to_le_bytes());
+        let mut reader = IbtReader::from_bytes(&bytes)?;
+
+        assert!(!reader.has_telemetry());
+        assert_eq!(reader.total_frames(), 0);
+        assert!(reader.read_frame_at(0)?.is_none());
+        assert!(reader.raw_frame_at(0)?.is_none());
+        assert!(reader.read_next_frame()?.is_none());
+        Ok(())
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_open_mmap_matches_owned_reader() -> Result<()> {
+        let path = write_synthetic_ibt("reader-open-mmap", 8);
+        let mapped = IbtReader::open_mmap(&path)?;
+        let owned = IbtReader::open(&path)?;
+
+        assert_eq!(mapped.total_frames(), owned.total_frames());
+        assert_eq!(mapped.session_yaml()?, owned.session_yaml()?);
+        for index in 0..owned.total_frames() {
+            assert_eq!(mapped.raw_frame_at(index)?, owned.raw_frame_at(index)?);
+        }
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_to_time_finds_first_frame_at_or_after() -> Result<()> {
+        let mut reader = IbtReader::from_bytes(&synthetic_ibt_bytes(120))?;
+
+        assert_eq!(reader.session_time_range()?, (0.0, 119.0 / 60.0));
+        assert_eq!(reader.find_frame_at_time(0.0)?, 0);
+        assert_eq!(reader.find_frame_at_time(1.0)?, 60);
+        assert_eq!(reader.find_frame_at_time(1.01)?, 61);
+        assert_eq!(reader.find_frame_at_time(119.0 / 60.0)?, 119);
+
+        reader.seek_to_time(0.5)?;
+        assert_eq!(reader.current_frame(), 30);
+
+        assert!(reader.seek_to_time(-0.1).is_err());
+        assert!(reader.seek_to_time(2.5).is_err());
+        assert_eq!(reader.current_frame(), 30, "Failed seek must not move the cursor");
+        Ok(())
+    }
+
+    #[test]
+    fn test_frames_in_time_range_reads_window_and_advances() -> Result<()> {
+        let mut reader = IbtReader::from_bytes(&synthetic_ibt_bytes(600))?;
+
+        let window = reader.frames_in_time_range(1.0, 2.0)?;
+        assert_eq!(window.len(), 61);
+        assert_eq!(window.first().unwrap().get_f64("SessionTime")?, 1.0);
+        assert_eq!(window.last().unwrap().get_f64("SessionTime")?, 2.0);
+        assert_eq!(reader.current_frame(), 121, "Reading resumes after the window");
+
+        // Windows are clipped to the recording
+        assert_eq!(reader.frames_in_time_range(-5.0, 0.5)?.len(), 31);
+        assert_eq!(reader.frames_in_time_range(9.5, 30.0)?.len(), 30);
+
+        reader.seek_to_frame(10)?;
+        assert!(reader.frames_in_time_range(20.0, 30.0)?.is_empty());
+        assert!(reader.frames_in_time_range(2.0, 1.0)?.is_empty());
+        assert_eq!(reader.current_frame(), 10, "Empty windows do not move the read position");
+        Ok(())
+    }
+
+    #[test]
+    fn test_tick_rate_uses_header_with_60hz_fallback() -> Result<()> {
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes_at_rate(720, 360))?;
+        assert_eq!(reader.tick_rate(), 360.0);
+        assert_eq!(reader.find_frame_at_time(1.0)?, 360);
+
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes_at_rate(1, 0))?;
+        assert_eq!(reader.tick_rate(), 60.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tailing_exposes_only_complete_appended_frames() -> Result<()> {
+        use std::io::Write;
+
+        // Synthetic frames are 16 bytes and carry their index in `Speed`
+        let full = synthetic_ibt_bytes(10);
+        let frame_data_start = full.len() - 10 * 16;
+        let path =
+            std::env::temp_dir().join(format!("pitwall-ibt-tailing-{}.ibt", std::process::id()));
+
+        // Header plus four frames and half of the fifth
+        std::fs::write(&path, &full[..frame_data_start + 4 * 16 + 8]).unwrap();
+        let mut reader = IbtReader::open_tailing(&path)?;
+        assert_eq!(reader.total_frames(), 4);
+        assert_eq!(reader.frames().count(), 4);
+        assert!(reader.read_next_frame()?.is_none());
+
+        // Finish the fifth frame and append the rest
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&full[frame_data_start + 4 * 16 + 8..]).unwrap();
+        drop(file);
+
+        assert_eq!(reader.poll_new_frames()?, 6);
+        assert_eq!(reader.total_frames(), 10);
+        let next = reader.read_frame_at(4)?.expect("frame 4 now complete");
+        assert_eq!(next.get_f32("Speed")?, 4.0);
+        assert_eq!(reader.frames().count(), 6, "Iteration resumes after the last read frame");
+        assert_eq!(reader.poll_new_frames()?, 0);
+
+        let _ = std::fs::remove_file(path);
+        Ok(())
+    }
+
+    #[test]
+    fn validate_adapter_reports_missing_fields_offline() -> Result<()> {
+        struct Fields<const OK: bool>;
+
+        impl<const OK: bool> FrameAdapter for Fields<OK> {
+            fn validate_schema(schema: &VariableSchema) -> crate::Result<AdapterValidation> {
+                let names: &[&str] = if OK { &["Speed", "Gear"] } else { &["Sped", "Gear"] };
+                AdapterValidation::for_fields(names, schema)
+            }
+
+            fn adapt(_packet: &FramePacket, _validation: &AdapterValidation) -> Self {
+                Fields
+            }
+        }
+
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes(10))?;
+
+        let validation = reader.validate_adapter::<Fields<true>>()?;
+        ensure!(validation.index_of("Gear").is_some(), "Gear should be in the plan");
+
+        let error = reader.validate_adapter::<Fields<false>>().err().context("Sped is missing")?;
+        ensure!(error.to_string().contains("did you mean 'Speed'?"), "Unexpected error: {}", error);
+        Ok(())
+    }
 }
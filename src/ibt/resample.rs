@@ -0,0 +1,145 @@
+//! Resampling IBT frames onto a fixed time grid
+//!
+//! Comparing laps or sessions recorded at different tick rates (or with dropped
+//! frames) needs values on a common grid. Floating point fields are linearly
+//! interpolated between the bracketing frames; integers, booleans, chars and
+//! bitfields use the nearest frame.
+
+use super::IbtReader;
+use crate::{Result, TelemetryError, Value, VarData, VariableInfo};
+
+/// Field values at one point of the resampled grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResampledFrame {
+    /// Session time in seconds
+    pub time: f64,
+    /// Values in the order of the requested fields
+    pub values: Vec<Value>,
+}
+
+/// Resample `fields` onto a `hz` grid spanning the recording's `SessionTime` range.
+///
+/// Assumes `SessionTime` increases across the file. Returns `FieldNotFound` for
+/// unknown fields (or a missing `SessionTime`) and a parse error if `hz` is not
+/// a positive rate.
+pub fn resample_by_time(
+    reader: &IbtReader,
+    hz: f64,
+    fields: &[&str],
+) -> Result<Vec<ResampledFrame>> {
+    if !hz.is_finite() || hz <= 0.0 {
+        return Err(TelemetryError::Parse {
+            context: "Resampling".to_string(),
+            details: format!("Invalid resample rate {}", hz),
+        });
+    }
+
+    let infos = fields
+        .iter()
+        .map(|name| {
+            reader
+                .variables()
+                .get_variable(name)
+                .ok_or_else(|| TelemetryError::FieldNotFound { field: name.to_string() })
+        })
+        .collect::<Result<Vec<&VariableInfo>>>()?;
+
+    let total = reader.total_frames();
+    if total == 0 {
+        return Ok(Vec::new());
+    }
+    let times =
+        (0..total).map(|index| reader.session_time_at(index)).collect::<Result<Vec<_>>>()?;
+
+    let read_values = |index: usize| -> Result<Vec<Value>> {
+        reader.with_frame_bytes(index, |frame| {
+            infos.iter().map(|info| Value::from_bytes(frame, info)).collect()
+        })
+    };
+
+    let (start, end) = (times[0], times[total - 1]);
+    let step = 1.0 / hz;
+    let mut output = Vec::new();
+    let mut lower = 0;
+    let mut cached: Option<(usize, Vec<Value>, Vec<Value>)> = None;
+
+    for k in 0.. {
+        let time = start + k as f64 * step;
+        if time > end + f64::EPSILON * end.abs().max(1.0) {
+            break;
+        }
+
+        // Advance to the last frame at or before `time`
+        while lower + 1 < total && times[lower + 1] <= time {
+            lower += 1;
+        }
+        let upper = (lower + 1).min(total - 1);
+
+        if cached.as_ref().is_none_or(|(index, _, _)| *index != lower) {
+            cached = Some((lower, read_values(lower)?, read_values(upper)?));
+        }
+        let (_, low_values, high_values) = cached.as_ref().expect("cache filled above");
+
+        let span = times[upper] - times[lower];
+        let weight = if span > 0.0 { ((time - times[lower]) / span).clamp(0.0, 1.0) } else { 0.0 };
+
+        let values =
+            low_values.iter().zip(high_values).map(|(a, b)| interpolate(a, b, weight)).collect();
+        output.push(ResampledFrame { time, values });
+    }
+
+    Ok(output)
+}
+
+/// Linear interpolation for floats, nearest-neighbor for everything else
+fn interpolate(a: &Value, b: &Value, weight: f64) -> Value {
+    match (a, b) {
+        (Value::Float32(x), Value::Float32(y)) => Value::Float32(x + (y - x) * weight as f32),
+        (Value::Float64(x), Value::Float64(y)) => Value::Float64(x + (y - x) * weight),
+        (Value::Array(xs), Value::Array(ys)) => {
+            Value::Array(xs.iter().zip(ys).map(|(x, y)| interpolate(x, y, weight)).collect())
+        }
+        _ if weight < 0.5 => a.clone(),
+        _ => b.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::synthetic_ibt_bytes;
+
+    #[test]
+    fn resamples_floats_linearly_and_ints_nearest() -> Result<()> {
+        // 60Hz synthetic recording: Speed = frame index, Gear = index % 6
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes(7))?;
+        let frames = resample_by_time(&reader, 240.0, &["Speed", "Gear"])?;
+
+        // 0.1s span at 240Hz
+        assert_eq!(frames.len(), 25);
+        assert_eq!(frames[0].values, vec![Value::Float32(0.0), Value::Int32(0)]);
+
+        // A quarter of the way from frame 2 to frame 3
+        let quarter = &frames[9];
+        assert!((quarter.time - 9.0 / 240.0).abs() < 1e-12);
+        let Value::Float32(speed) = quarter.values[0] else { panic!("Speed is Float32") };
+        assert!((speed - 2.25).abs() < 1e-5);
+        assert_eq!(quarter.values[1], Value::Int32(2));
+
+        // Three quarters snaps to the next frame's gear
+        assert_eq!(frames[11].values[1], Value::Int32(3));
+        assert_eq!(frames[24].values, vec![Value::Float32(6.0), Value::Int32(0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_rates_and_fields() -> Result<()> {
+        let reader = IbtReader::from_bytes(&synthetic_ibt_bytes(4))?;
+        assert!(resample_by_time(&reader, 0.0, &["Speed"]).is_err());
+        assert!(matches!(
+            resample_by_time(&reader, 30.0, &["RPM"]),
+            Err(TelemetryError::FieldNotFound { .. })
+        ));
+        Ok(())
+    }
+}
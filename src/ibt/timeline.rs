@@ -0,0 +1,60 @@
+//! Binary search over the `SessionTime` of recorded frames
+//!
+//! Shared by [`IbtReader`](super::IbtReader) and [`MultiReader`](super::MultiReader),
+//! which differ only in how the `SessionTime` of a frame index is read.
+
+use crate::{Result, TelemetryError};
+
+/// Get the `SessionTime` of the first and last of `total_frames` frames
+pub(super) fn session_time_range(
+    total_frames: usize,
+    time_at: impl Fn(usize) -> Result<f64>,
+) -> Result<(f64, f64)> {
+    if total_frames == 0 {
+        return Err(TelemetryError::Parse {
+            context: "Frame seek".to_string(),
+            details: "Recording contains no frames".to_string(),
+        });
+    }
+
+    Ok((time_at(0)?, time_at(total_frames - 1)?))
+}
+
+/// Find the first frame whose `SessionTime` is at or after `session_time`
+///
+/// Times outside the recorded range return a `Parse` error.
+pub(super) fn find_frame_at_time(
+    total_frames: usize,
+    time_at: impl Fn(usize) -> Result<f64>,
+    session_time: f64,
+) -> Result<usize> {
+    let (first, last) = session_time_range(total_frames, &time_at)?;
+    if !(first..=last).contains(&session_time) {
+        return Err(TelemetryError::Parse {
+            context: "Frame seek".to_string(),
+            details: format!("Session time {} out of range ({}..={})", session_time, first, last),
+        });
+    }
+
+    partition_by_time(total_frames, time_at, |time| time < session_time)
+}
+
+/// Index of the first frame whose `SessionTime` does not satisfy `before`,
+/// or `total_frames` when every frame does. `SessionTime` must be non-decreasing.
+pub(super) fn partition_by_time(
+    total_frames: usize,
+    time_at: impl Fn(usize) -> Result<f64>,
+    before: impl Fn(f64) -> bool,
+) -> Result<usize> {
+    let (mut low, mut high) = (0, total_frames);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if before(time_at(mid)?) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(low)
+}
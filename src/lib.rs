@@ -46,13 +46,19 @@ mod error;
 #[cfg(any(test, feature = "benchmark"))]
 pub mod test_utils;
 pub mod types;
-mod yaml_utils;
+pub mod yaml_utils;
 
-// Stream-based telemetry architecture
+// Stream-based telemetry architecture (requires the `runtime` feature)
+#[cfg(feature = "runtime")]
 pub mod connection;
+#[cfg(feature = "runtime")]
 pub mod driver;
+#[cfg(feature = "runtime")]
 pub mod provider;
+#[cfg(feature = "runtime")]
 pub mod providers;
+pub mod retry;
+#[cfg(feature = "runtime")]
 pub mod stream;
 
 // Data source modules
@@ -60,7 +66,7 @@ pub mod ibt;
 pub mod schema;
 
 // Platform-specific modules
-#[cfg(windows)]
+#[cfg(all(windows, feature = "runtime"))]
 pub mod windows;
 
 // Core exports
@@ -73,22 +79,28 @@ pub use types::*;
 pub use ibt::IbtReader;
 
 // Schema exports
+#[cfg(feature = "session")]
 pub use schema::{SessionInfo, SessionInfoParser};
 
 // Windows memory exports
-#[cfg(windows)]
+#[cfg(all(windows, feature = "runtime"))]
 pub use windows::{Connection as WindowsConnection, WaitResult};
 
 // Main API exports
 pub use types::UpdateRate;
 
-pub use connection::live::LiveConnection;
+#[cfg(feature = "runtime")]
+pub use connection::live::{LiveConnection, LiveConnectionBuilder};
+#[cfg(feature = "runtime")]
 pub use connection::replay::ReplayConnection;
+#[cfg(feature = "runtime")]
+pub use connection::{ConnectionStatus, StreamStats, TelemetrySource};
 
 // Re-export derive macros when available
 #[cfg(feature = "derive")]
 pub use pitwall_derive::PitwallFrame;
 
+#[cfg(feature = "runtime")]
 /// Unified entry point for Pitwall telemetry connections.
 ///
 /// This factory provides a consistent API for creating connections to both
@@ -121,6 +133,7 @@ pub use pitwall_derive::PitwallFrame;
 /// ```
 pub struct Pitwall;
 
+#[cfg(feature = "runtime")]
 impl Pitwall {
     /// Connect to live iRacing telemetry.
     ///
@@ -132,6 +145,9 @@ impl Pitwall {
     /// This method is only available on Windows where iRacing runs.
     /// On other platforms, this method returns an `UnsupportedPlatform` error.
     ///
+    /// Use [`LiveConnection::builder`] to wait for iRacing or a loaded session
+    /// instead of failing immediately.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -155,6 +171,60 @@ impl Pitwall {
         LiveConnection::connect().await
     }
 
+    /// Connect to live iRacing telemetry, retrying transient failures.
+    ///
+    /// Each attempt is a [`Pitwall::connect`]; retryable errors (such as iRacing
+    /// not running yet) are retried with the policy's exponential backoff and
+    /// logged with their recovery suggestions. Non-retryable errors, including
+    /// `UnsupportedPlatform` off Windows, are returned immediately.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use pitwall::Pitwall;
+    /// use pitwall::retry::RetryPolicy;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> pitwall::Result<()> {
+    /// let connection = Pitwall::connect_retrying(RetryPolicy::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_retrying(policy: retry::RetryPolicy) -> Result<LiveConnection> {
+        retry::with_backoff_async(LiveConnection::connect, policy).await
+    }
+
+    /// Connect to a pseudo-live source that replays an IBT file in real time.
+    ///
+    /// Intended for developing live-telemetry apps away from a Windows rig: the
+    /// returned connection paces frames at the recording's tick rate and publishes
+    /// session updates just like [`Pitwall::connect`]. Write app code against
+    /// [`TelemetrySource`] and only the constructor needs to change on Windows.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use pitwall::{Pitwall, TelemetrySource};
+    ///
+    /// fn run(connection: &impl TelemetrySource) {
+    ///     println!("Streaming at {}Hz", connection.source_hz());
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> pitwall::Result<()> {
+    /// #[cfg(windows)]
+    /// let connection = Pitwall::connect().await?;
+    /// #[cfg(not(windows))]
+    /// let connection = Pitwall::connect_mock("practice.ibt").await?;
+    ///
+    /// run(&connection);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_mock<P: AsRef<std::path::Path>>(path: P) -> Result<ReplayConnection> {
+        ReplayConnection::open(path).await.map(|connection| connection.with_playback_speed(1.0))
+    }
+
     /// Open an IBT file for replay.
     ///
     /// Loads an iRacing telemetry file (IBT) and provides a connection that behaves
@@ -185,4 +255,32 @@ impl Pitwall {
     pub async fn open<P: AsRef<std::path::Path>>(path: P) -> Result<ReplayConnection> {
         ReplayConnection::open(path).await
     }
+
+    /// Open the most recent recording in iRacing's telemetry directory.
+    ///
+    /// Picks the newest `.ibt` by modification time from
+    /// [`default_telemetry_dir`](ibt::default_telemetry_dir), i.e. the session
+    /// just driven. See [`ibt::latest_in`] to search another directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Platform is not Windows (`UnsupportedPlatform`)
+    /// - The telemetry directory is missing or holds no recordings (`File`)
+    /// - The newest file cannot be opened as an IBT recording
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use pitwall::Pitwall;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> pitwall::Result<()> {
+    /// let connection = Pitwall::open_latest().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open_latest() -> Result<ReplayConnection> {
+        ReplayConnection::open(ibt::latest_recording()?).await
+    }
 }
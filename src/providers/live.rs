@@ -1,9 +1,11 @@
 //! Live telemetry provider for Windows
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
 use tracing::{debug, info, trace, warn};
 
+use crate::connection::{ConnectionStatus, StatsCollector, StreamStats};
 use crate::provider::Provider;
 use crate::types::FramePacket;
 use crate::windows::{Connection, WaitResult};
@@ -18,6 +20,12 @@ pub struct LiveProvider {
 
     /// Cached variable schema
     schema: Arc<VariableSchema>,
+
+    /// Connection state, published on every change
+    status: watch::Sender<ConnectionStatus>,
+
+    /// Frame delivery statistics, updated on every frame read
+    stats: StatsCollector,
 }
 
 #[cfg(windows)]
@@ -42,7 +50,14 @@ impl LiveProvider {
             "Connected to iRacing shared memory"
         );
 
-        // Build schema from variables
+        let schema = Self::build_schema(&connection)?;
+        let (status, _) = watch::channel(Self::status_of(&connection));
+
+        Ok(Self { connection, schema, status, stats: StatsCollector::new() })
+    }
+
+    /// Build the variable schema from the connection's variable headers
+    fn build_schema(connection: &Connection) -> Result<Arc<VariableSchema>> {
         let variables = connection.get_variables();
         let mut variable_map = std::collections::HashMap::new();
 
@@ -50,16 +65,87 @@ impl LiveProvider {
             variable_map.insert(var_info.name.clone(), var_info);
         }
 
-        let frame_size = header.buf_len as usize;
-        let schema = Arc::new(VariableSchema::new(variable_map, frame_size)?);
+        let frame_size = connection.header().buf_len as usize;
+        Ok(Arc::new(VariableSchema::new(variable_map, frame_size)?))
+    }
 
-        Ok(Self { connection, schema })
+    /// Re-open the shared memory mapping and rebuild the schema.
+    ///
+    /// Called when telemetry resumes after iRacing dropped the connected status,
+    /// since a restarted sim or a different car can change the variable layout.
+    /// The existing schema is kept when the layout is unchanged, so frames keep
+    /// sharing it; otherwise frames carry the new schema and subscriptions
+    /// validate their adapters again when they see it. On failure the existing
+    /// mapping is kept and polling continues.
+    fn reconnect(&mut self) {
+        match Connection::try_connect().and_then(|connection| {
+            let schema = Self::build_schema(&connection)?;
+            Ok((connection, schema))
+        }) {
+            Ok((connection, schema)) => {
+                let delta = self.schema.diff(&schema);
+                warn!(
+                    num_vars = schema.variables.len(),
+                    layout_changed = !delta.is_empty(),
+                    "Reconnected to iRacing shared memory after session loss"
+                );
+                self.connection = connection;
+                if !delta.is_empty() {
+                    self.schema = schema;
+                }
+            }
+            Err(e) => warn!("Failed to re-open iRacing shared memory, keeping existing: {}", e),
+        }
     }
 
     /// Get the variable schema
     pub fn schema(&self) -> Arc<VariableSchema> {
         Arc::clone(&self.schema)
     }
+
+    /// Check whether iRacing has a session loaded (not just shared memory mapped)
+    pub fn is_session_active(&self) -> bool {
+        self.connection.is_connected()
+    }
+
+    /// Receiver for connection state changes.
+    ///
+    /// Reports [`ConnectionStatus::Disconnected`] once the provider gives up or is dropped.
+    pub fn status_receiver(&self) -> watch::Receiver<ConnectionStatus> {
+        self.status.subscribe()
+    }
+
+    /// Receiver for frame delivery statistics
+    pub fn stats_receiver(&self) -> watch::Receiver<StreamStats> {
+        self.stats.receiver()
+    }
+
+    fn status_of(connection: &Connection) -> ConnectionStatus {
+        if connection.is_connected() {
+            ConnectionStatus::InSession
+        } else {
+            ConnectionStatus::Connected
+        }
+    }
+
+    /// Publish `status` if it differs from the last published state
+    fn publish_status(&self, status: ConnectionStatus) {
+        self.status.send_if_modified(|current| {
+            if *current == status {
+                return false;
+            }
+            info!(?status, "Connection status changed");
+            *current = status;
+            true
+        });
+    }
+}
+
+#[cfg(windows)]
+impl Drop for LiveProvider {
+    fn drop(&mut self) {
+        self.publish_status(ConnectionStatus::Disconnected);
+    }
 }
 
 #[cfg(windows)]
@@ -73,6 +159,8 @@ impl Provider for LiveProvider {
         // Loop until we get a frame
         // This matches the C++ SDK pattern of persistent checking
         loop {
+            self.publish_status(Self::status_of(&self.connection));
+
             // Check if still connected (like C++ SDK checks status)
             if !self.connection.is_connected() {
                 no_connection_count += 1;
@@ -90,6 +178,7 @@ impl Provider for LiveProvider {
                 // Give up after extended period with no connection
                 if no_connection_count >= MAX_NO_CONNECTION_ATTEMPTS {
                     warn!("Giving up after 5 minutes without iRacing session");
+                    self.publish_status(ConnectionStatus::Disconnected);
                     return Ok(None);
                 }
 
@@ -101,6 +190,7 @@ impl Provider for LiveProvider {
             // Reset counter when we get a connection
             if no_connection_count > 0 {
                 info!("iRacing session detected, resuming telemetry");
+                self.reconnect();
                 no_connection_count = 0;
             }
 
@@ -112,6 +202,7 @@ impl Provider for LiveProvider {
                 let latest_buf_idx = self.connection.find_latest_buffer(header);
                 let tick = header.var_buf[latest_buf_idx].tick_count as u32;
                 let session_version = header.session_info_update as u32;
+                self.stats.record(tick, Instant::now());
 
                 trace!(
                     "Frame: tick={}, session_version={}, size={}",
@@ -153,7 +244,7 @@ impl Provider for LiveProvider {
         debug!("Fetching session YAML from shared memory");
 
         // Get raw YAML from shared memory
-        let raw_yaml = match self.connection.session_info() {
+        let raw_yaml = match self.connection.session_info()? {
             Some(yaml) => yaml,
             None => {
                 debug!("No session info available");
@@ -167,7 +258,7 @@ impl Provider for LiveProvider {
         }
 
         // Preprocess to fix iRacing's YAML issues
-        let cleaned_yaml = yaml_utils::preprocess_iracing_yaml(raw_yaml)?;
+        let cleaned_yaml = yaml_utils::preprocess_iracing_yaml(&raw_yaml)?;
 
         info!("Extracted session YAML ({} bytes)", cleaned_yaml.len());
 
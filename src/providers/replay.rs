@@ -2,30 +2,53 @@
 
 use std::path::Path;
 use std::sync::Arc;
-use tokio::time::{Duration, Interval, interval};
-use tracing::{debug, info, trace};
+use tokio::sync::watch;
+use tokio::time::{Duration, Instant, sleep_until};
+use tracing::{debug, info, trace, warn};
 
 use crate::ibt::IbtReader;
 use crate::provider::Provider;
 use crate::types::FramePacket;
-use crate::{Result, TelemetryError, VariableSchema};
+use crate::{Result, VariableSchema};
+
+/// Slowest paced playback speed; slower requests are clamped to it
+const MIN_PLAYBACK_SPEED: f64 = 0.1;
+
+/// Fastest paced playback speed; faster finite requests are clamped to it
+const MAX_PLAYBACK_SPEED: f64 = 10.0;
+
+/// Seek request sent to a running `ReplayProvider`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySeek {
+    /// Jump to a frame index
+    Frame(usize),
+    /// Jump to the first frame at or after a session time in seconds
+    Time(f64),
+}
 
 /// Replay provider that reads from IBT files
 pub struct ReplayProvider {
     /// IBT file reader
     reader: IbtReader,
 
-    /// Playback speed multiplier (1.0 = normal, 2.0 = double speed)
+    /// Playback speed multiplier (1.0 = normal, 2.0 = double speed, infinite = unpaced)
     speed: f64,
 
-    /// Frame pacing interval
-    interval: Interval,
+    /// Frame time and instant that pacing is measured from; reset on seeks and
+    /// speed changes
+    anchor: Option<(f64, Instant)>,
 
     /// Cached schema
     schema: Arc<VariableSchema>,
 
     /// Native tick rate from IBT
     tick_rate: f64,
+
+    /// Pending seek requests from the owning connection
+    seek_rx: Option<watch::Receiver<Option<ReplaySeek>>>,
+
+    /// Playback speed changes from the owning connection
+    speed_rx: Option<watch::Receiver<f64>>,
 }
 
 impl ReplayProvider {
@@ -37,16 +60,29 @@ impl ReplayProvider {
         let total_frames = reader.total_frames();
         let tick_rate = reader.tick_rate();
 
-        // Get the variable schema from the reader
-        let schema = Arc::new(reader.variables().clone());
+        // Share the reader's parsed schema
+        let schema = reader.schema();
 
         info!("Opened IBT file: {} frames at {}Hz", total_frames, tick_rate);
+        if !reader.has_telemetry() {
+            warn!("IBT file contains session info only; replay will produce no frames");
+        }
 
-        // Calculate frame interval for pacing
-        let frame_interval = Duration::from_secs_f64(1.0 / tick_rate);
-        let interval = interval(frame_interval);
+        Ok(Self {
+            reader,
+            speed: 1.0,
+            anchor: None,
+            schema,
+            tick_rate,
+            seek_rx: None,
+            speed_rx: None,
+        })
+    }
 
-        Ok(Self { reader, speed: 1.0, interval, schema, tick_rate })
+    /// Apply seek requests from `seek_rx` before each frame is read
+    pub fn with_seek_receiver(mut self, seek_rx: watch::Receiver<Option<ReplaySeek>>) -> Self {
+        self.seek_rx = Some(seek_rx);
+        self
     }
 
     /// Get the variable schema
@@ -54,31 +90,116 @@ impl ReplayProvider {
         Arc::clone(&self.schema)
     }
 
+    /// Apply playback speed changes from `speed_rx` before each frame is read
+    pub fn with_speed_receiver(mut self, speed_rx: watch::Receiver<f64>) -> Self {
+        self.speed_rx = Some(speed_rx);
+        self
+    }
+
     /// Set playback speed
+    ///
+    /// Frames are paced by the difference in their `SessionTime`, divided by
+    /// the speed. Positive speeds are clamped to `0.1..=10.0`, with a warning
+    /// when the requested speed is outside that range. A speed of `0.0` (or any
+    /// non-positive or non-finite value) disables pacing so frames are delivered
+    /// as fast as they are consumed.
     pub fn set_speed(&mut self, speed: f64) {
-        self.speed = speed.clamp(0.1, 10.0); // Clamp to reasonable range
-
-        // Update interval based on new speed
-        let frame_duration = Duration::from_secs_f64(1.0 / (self.tick_rate * self.speed));
-        self.interval = interval(frame_duration);
+        self.anchor = None;
+        if speed <= 0.0 || !speed.is_finite() {
+            self.speed = f64::INFINITY;
+            debug!("Playback pacing disabled");
+            return;
+        }
 
+        self.speed = speed.clamp(MIN_PLAYBACK_SPEED, MAX_PLAYBACK_SPEED);
+        if self.speed != speed {
+            warn!("Playback speed {}x is out of range, clamped to {}x", speed, self.speed);
+        }
         debug!("Playback speed set to {}x", self.speed);
     }
 
     /// Seek to a specific frame
     pub fn seek_to_frame(&mut self, frame: usize) -> Result<()> {
-        let total_frames = self.reader.total_frames();
-        if frame >= total_frames {
-            return Err(TelemetryError::connection_failed(format!(
-                "Cannot seek to frame {} (file has {} frames)",
-                frame, total_frames
-            )));
+        debug!("Seeking to frame {}", frame);
+        self.anchor = None;
+        self.reader.seek_to_frame(frame)
+    }
+
+    /// Seek to the first frame at or after `session_time` (in seconds)
+    pub fn seek_to_time(&mut self, session_time: f64) -> Result<()> {
+        debug!("Seeking to session time {}", session_time);
+        self.anchor = None;
+        self.reader.seek_to_time(session_time)
+    }
+
+    /// Get the total number of frames in the replay
+    pub fn total_frames(&self) -> usize {
+        self.reader.total_frames()
+    }
+
+    /// Get the `SessionTime` of the first and last frames
+    pub fn session_time_range(&self) -> Result<(f64, f64)> {
+        self.reader.session_time_range()
+    }
+
+    /// Apply the latest speed change, if one arrived since the last frame
+    fn apply_pending_speed(&mut self) {
+        let Some(speed_rx) = self.speed_rx.as_mut() else {
+            return;
+        };
+
+        if speed_rx.has_changed().unwrap_or(false) {
+            let speed = *speed_rx.borrow_and_update();
+            self.set_speed(speed);
         }
+    }
 
-        // IbtReader tracks position internally
-        // We'll need to reset and read up to the target
-        debug!("Seeking to frame {}", frame);
-        Ok(())
+    /// Apply the latest seek request, if one arrived since the last frame
+    fn apply_pending_seek(&mut self) {
+        let Some(seek_rx) = self.seek_rx.as_mut() else {
+            return;
+        };
+
+        if !seek_rx.has_changed().unwrap_or(false) {
+            return;
+        }
+
+        let request = *seek_rx.borrow_and_update();
+        let result = match request {
+            Some(ReplaySeek::Frame(frame)) => self.seek_to_frame(frame),
+            Some(ReplaySeek::Time(session_time)) => self.seek_to_time(session_time),
+            None => Ok(()),
+        };
+
+        if let Err(e) = result {
+            warn!("Ignoring replay seek {:?}: {}", request, e);
+        }
+    }
+
+    /// Recorded time of frame `index`: its `SessionTime`, or its index over the
+    /// tick rate for files without one
+    fn frame_time(&self, index: usize) -> f64 {
+        self.reader.session_time_at(index).unwrap_or(index as f64 / self.tick_rate)
+    }
+
+    /// Wait until frame time `frame_time` is due at the current speed.
+    ///
+    /// Due times are measured from an anchor frame rather than the previous
+    /// frame, so pacing does not drift. The first frame after a seek or speed
+    /// change becomes the new anchor and is delivered at once, as is a frame
+    /// whose `SessionTime` goes backwards.
+    async fn pace(&mut self, frame_time: f64) {
+        if !self.speed.is_finite() {
+            return;
+        }
+
+        let now = Instant::now();
+        let (anchor_time, anchor_instant) = *self.anchor.get_or_insert((frame_time, now));
+        let delay = (frame_time - anchor_time) / self.speed;
+        match Duration::try_from_secs_f64(delay) {
+            Ok(delay) => sleep_until(anchor_instant + delay).await,
+            Err(_) => self.anchor = Some((frame_time, now)),
+        }
     }
 
     /// Get current playback time in seconds
@@ -95,6 +216,9 @@ impl ReplayProvider {
 #[async_trait::async_trait]
 impl Provider for ReplayProvider {
     async fn next_frame(&mut self) -> Result<Option<FramePacket>> {
+        self.apply_pending_speed();
+        self.apply_pending_seek();
+
         // Check if we've reached the end
         let total_frames = self.reader.total_frames();
         if self.reader.current_frame() >= total_frames {
@@ -102,8 +226,9 @@ impl Provider for ReplayProvider {
             return Ok(None);
         }
 
-        // Wait for next frame timing (pacing)
-        self.interval.tick().await;
+        // Wait until the frame is due at the playback speed (pacing)
+        let frame_time = self.frame_time(self.reader.current_frame());
+        self.pace(frame_time).await;
 
         // Read next frame data directly from IBT reader
         let (frame_data, tick, session_version) = match self.reader.read_next_frame()? {
@@ -227,4 +352,32 @@ mod tests {
             session.weekend_info.track_name
         );
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_replay_provider_paces_by_session_time_and_speed() {
+        // SessionTime advances 0.1s per frame while the header claims 60Hz
+        let mut bytes = test_utils::synthetic_ibt_bytes_at_rate(5, 10);
+        bytes[8..12].copy_from_slice(&60i32.to_le_bytes());
+        let path = std::env::temp_dir()
+            .join(format!("pitwall-replay-session-time-pacing-{}.ibt", std::process::id()));
+        std::fs::write(&path, bytes).expect("Failed to write IBT file");
+        let mut provider = ReplayProvider::new(&path).expect("Failed to create ReplayProvider");
+        std::fs::remove_file(&path).expect("Failed to remove IBT file");
+        provider.set_speed(2.0);
+
+        provider.next_frame().await.unwrap().expect("First frame is delivered at once");
+        let start = Instant::now();
+        for _ in 0..4 {
+            provider.next_frame().await.unwrap().expect("Frame should be delivered");
+        }
+
+        // Four 0.1s steps at double speed, not four 60Hz ticks
+        let elapsed = start.elapsed();
+        assert!(
+            (Duration::from_millis(200)..Duration::from_millis(205)).contains(&elapsed),
+            "Expected ~200ms of playback, took {:?}",
+            elapsed
+        );
+        assert!(provider.next_frame().await.unwrap().is_none());
+    }
 }
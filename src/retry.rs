@@ -0,0 +1,196 @@
+//! Automatic retry for operations that fail with retryable errors
+//!
+//! Errors are classified with [`TelemetryError::is_retryable`]; anything else is
+//! returned straight away. Each failed attempt is logged together with the
+//! error's [`recovery_suggestions`](TelemetryError::recovery_suggestions).
+//!
+//! ```rust,no_run
+//! use pitwall::retry::{RetryPolicy, with_backoff};
+//! use pitwall::IbtReader;
+//!
+//! # fn main() -> pitwall::Result<()> {
+//! let reader = with_backoff(|| IbtReader::open("session.ibt"), RetryPolicy::default())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+use tracing::warn;
+
+use crate::{Result, TelemetryError};
+
+/// Attempt limits and exponential backoff between attempts
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (values below 1 are treated as 1)
+    pub max_attempts: u32,
+    /// Delay after the first failure
+    pub initial_delay: Duration,
+    /// Upper bound on any single delay
+    pub max_delay: Duration,
+    /// Factor applied to the delay after each failure
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay to wait after the given failed attempt (1-based).
+    pub fn delay_after(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        Duration::from_secs_f64(delay.min(self.max_delay.as_secs_f64()))
+    }
+
+    /// Log a failed attempt and return the delay before the next one, or `None`
+    /// when the error should be returned to the caller.
+    fn next_delay(&self, attempt: u32, error: &TelemetryError) -> Option<Duration> {
+        if !error.is_retryable() || attempt >= self.max_attempts.max(1) {
+            return None;
+        }
+
+        let delay = self.delay_after(attempt);
+        warn!(
+            attempt,
+            max_attempts = self.max_attempts,
+            ?delay,
+            suggestions = ?error.recovery_suggestions(),
+            "Retrying after error: {}",
+            error
+        );
+        Some(delay)
+    }
+}
+
+/// Run `f` until it succeeds, fails with a non-retryable error, or the policy's
+/// attempts run out. Blocks the current thread between attempts.
+///
+/// The last error is returned when every attempt fails.
+pub fn with_backoff<F, T>(mut f: F, policy: RetryPolicy) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(error) => match policy.next_delay(attempt, &error) {
+                Some(delay) => std::thread::sleep(delay),
+                None => return Err(error),
+            },
+        }
+        attempt += 1;
+    }
+}
+
+/// Async variant of [`with_backoff`] that sleeps on the tokio timer.
+#[cfg(feature = "runtime")]
+pub async fn with_backoff_async<F, Fut, T>(mut f: F, policy: RetryPolicy) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(error) => match policy.next_delay(attempt, &error) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error),
+            },
+        }
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quick(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy { max_attempts, initial_delay: Duration::ZERO, ..Default::default() }
+    }
+
+    #[test]
+    fn retries_only_retryable_errors() {
+        let mut calls = 0;
+        let result = with_backoff(
+            || {
+                calls += 1;
+                if calls < 3 {
+                    Err(TelemetryError::connection_failed("not yet"))
+                } else {
+                    Ok(calls)
+                }
+            },
+            quick(5),
+        );
+        assert_eq!(result.unwrap(), 3);
+
+        let mut calls = 0;
+        let result: Result<()> = with_backoff(
+            || {
+                calls += 1;
+                Err(TelemetryError::FieldNotFound { field: "Speed".to_string() })
+            },
+            quick(5),
+        );
+        assert!(result.is_err());
+        assert_eq!(calls, 1, "Non-retryable errors are returned immediately");
+
+        let mut calls = 0;
+        let result: Result<()> = with_backoff(
+            || {
+                calls += 1;
+                Err(TelemetryError::connection_failed("never"))
+            },
+            quick(4),
+        );
+        assert!(result.is_err());
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn delays_grow_exponentially_up_to_the_cap() {
+        let policy = RetryPolicy {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            ..Default::default()
+        };
+        assert_eq!(policy.delay_after(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_after(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_after(3), Duration::from_millis(350));
+    }
+
+    #[cfg(feature = "runtime")]
+    #[tokio::test]
+    async fn async_variant_retries() {
+        let mut calls = 0;
+        let result = with_backoff_async(
+            || {
+                calls += 1;
+                let attempt = calls;
+                async move {
+                    if attempt < 2 {
+                        Err(TelemetryError::connection_failed("busy"))
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+            quick(3),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(calls, 2);
+    }
+}
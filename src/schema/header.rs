@@ -54,7 +54,7 @@
 //! # Performance Characteristics
 //!
 //! This implementation is optimized for the <1ms latency requirement:
-//! - Zero-copy parsing using `read_unaligned` for robustness
+//! - Parsing with `read_unaligned` for robustness, fields converted from little-endian
 //! - Fast validation path for 60Hz updates (`validate_fast()`)
 //! - Comprehensive validation for initial connection (`validate_comprehensive()`)
 //! - Corruption detection for production resilience
@@ -133,16 +133,7 @@ impl IRSDKHeader {
     pub fn parse_from_memory(memory: &[u8]) -> Result<Self> {
         trace!(memory_len = memory.len(), "Parsing iRacing header from memory");
 
-        // Fast path: validate minimum size first
-        const HEADER_SIZE: usize = mem::size_of::<IRSDKHeader>();
-        if memory.len() < HEADER_SIZE {
-            return Err(TelemetryError::Memory { offset: memory.len(), source: None });
-        }
-
-        // Zero-copy parsing: directly read from memory without copying
-        // Uses native byte order like other working Rust implementations (simetry, iracing-telem)
-        // Safety: We've validated the memory length above and use read_unaligned for robustness
-        let header = unsafe { std::ptr::read_unaligned(memory.as_ptr() as *const IRSDKHeader) };
+        let header = Self::read_le(memory)?;
 
         debug!(
             ver = header.ver,
@@ -159,6 +150,42 @@ impl IRSDKHeader {
         Ok(header)
     }
 
+    /// Read the header without validating it.
+    ///
+    /// iRacing writes the header little-endian. `read_unaligned` uses the target's
+    /// byte order, so every field is converted with `from_le`: a no-op on the
+    /// little-endian targets iRacing runs on, and correct on a big-endian target
+    /// parsing a relayed header.
+    fn read_le(memory: &[u8]) -> Result<Self> {
+        // Fast path: validate minimum size first
+        const HEADER_SIZE: usize = mem::size_of::<IRSDKHeader>();
+        if memory.len() < HEADER_SIZE {
+            return Err(TelemetryError::Memory { offset: memory.len(), source: None });
+        }
+
+        // Safety: We've validated the memory length above and use read_unaligned for robustness
+        let raw = unsafe { std::ptr::read_unaligned(memory.as_ptr() as *const IRSDKHeader) };
+
+        Ok(IRSDKHeader {
+            ver: i32::from_le(raw.ver),
+            status: i32::from_le(raw.status),
+            tick_rate: i32::from_le(raw.tick_rate),
+            session_info_update: i32::from_le(raw.session_info_update),
+            session_info_len: i32::from_le(raw.session_info_len),
+            session_info_offset: i32::from_le(raw.session_info_offset),
+            num_vars: i32::from_le(raw.num_vars),
+            var_header_offset: i32::from_le(raw.var_header_offset),
+            num_buf: i32::from_le(raw.num_buf),
+            buf_len: i32::from_le(raw.buf_len),
+            pad1: raw.pad1.map(i32::from_le),
+            var_buf: raw.var_buf.map(|buf| IRSDKVarBuf {
+                tick_count: i32::from_le(buf.tick_count),
+                buf_offset: i32::from_le(buf.buf_offset),
+                pad: buf.pad.map(i32::from_le),
+            }),
+        })
+    }
+
     /// Validate header fields for correctness (basic validation)
     pub fn validate(&self) -> Result<()> {
         // Check SDK version
@@ -390,12 +417,32 @@ pub struct BufferInfo {
     pub buffers: [IRSDKVarBuf; 4],
 }
 
-#[cfg(all(test, windows))]
+#[cfg(test)]
 mod tests {
     use super::*;
     use proptest::prelude::*;
     use std::mem;
 
+    #[test]
+    fn shared_memory_and_ibt_parsers_agree_on_header_fields() {
+        use crate::ibt::format::IbtHeader;
+
+        let bytes = crate::test_utils::synthetic_ibt_bytes(4);
+        let memory = IRSDKHeader::read_le(&bytes).unwrap();
+        let ibt = IbtHeader::parse_from_reader(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(memory.ver, ibt.version);
+        assert_eq!(memory.status, ibt.status);
+        assert_eq!(memory.tick_rate, ibt.tick_rate);
+        assert_eq!(memory.session_info_update, ibt.session_info_update);
+        assert_eq!(memory.session_info_len, ibt.session_info_len);
+        assert_eq!(memory.session_info_offset, ibt.session_info_offset);
+        assert_eq!(memory.num_vars, ibt.num_vars);
+        assert_eq!(memory.var_header_offset, ibt.var_header_offset);
+        assert_eq!(memory.num_buf, ibt.num_buf);
+        assert_eq!(memory.buf_len, ibt.buf_len);
+    }
+
     // Property test strategies for generating valid header data
     prop_compose! {
         fn arb_valid_header()(
@@ -13,15 +13,17 @@
 //!
 //! # Feature-Specific Implementation
 //!
-//! Schema discovery is conditionally compiled based on the live feature flag
-//! for Windows-specific iRacing integration.
+//! Variable discovery is conditionally compiled for Windows-specific iRacing
+//! integration. Header parsing only reads bytes, so it builds on every platform
+//! and is tested against the IBT header parser.
 
-#[cfg(windows)]
 pub mod header;
 
 #[cfg(windows)]
 pub mod variables;
 
+#[cfg(feature = "session")]
 pub mod session;
 
+#[cfg(feature = "session")]
 pub use session::{SessionInfo, SessionInfoParser};
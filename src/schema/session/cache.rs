@@ -31,11 +31,69 @@ impl SessionInfoCache {
     }
 }
 
+/// Keys whose values are always quoted by default (driver-entered or comma-leading values)
+const DEFAULT_FORCE_QUOTE_KEYS: &[&str] = &[
+    "AbbrevName",
+    "TeamName",
+    "UserName",
+    "Initials",
+    "DriverSetupName",
+    "CarDesignStr", // Car livery color codes - can start with comma
+];
+
+/// Options controlling how [`SessionInfoParser`] repairs iRacing's YAML before parsing
+///
+/// The defaults match the parser's historical behaviour; the extra fixups are
+/// opt-in because they can change how otherwise valid values are typed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreprocessOptions {
+    /// Remove control characters other than `\n`, `\r` and `\t`
+    pub strip_control_chars: bool,
+    /// Quote unquoted values that contain `": "` or end with `':'`
+    pub quote_values_with_colons: bool,
+    /// Join lines without a `key:` back onto the previous line, repairing values
+    /// that contain embedded newlines
+    pub join_wrapped_lines: bool,
+    /// Keys whose unquoted values are always single-quoted (without the trailing colon)
+    pub force_quote_keys: Vec<String>,
+}
+
+impl Default for PreprocessOptions {
+    fn default() -> Self {
+        Self {
+            strip_control_chars: true,
+            quote_values_with_colons: false,
+            join_wrapped_lines: false,
+            force_quote_keys: DEFAULT_FORCE_QUOTE_KEYS.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+}
+
+/// Counts of the repairs applied by [`SessionInfoParser::preprocess_with_fixups`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PreprocessFixups {
+    /// Control characters removed
+    pub control_chars_removed: usize,
+    /// Values wrapped in single quotes
+    pub values_quoted: usize,
+    /// Wrapped lines joined back onto their key
+    pub lines_joined: usize,
+}
+
+impl PreprocessFixups {
+    /// Total number of repairs applied
+    pub fn total(&self) -> usize {
+        self.control_chars_removed + self.values_quoted + self.lines_joined
+    }
+}
+
 /// Session info parser with YAML preprocessing for iRacing compatibility
 #[derive(Debug, Clone)]
 pub struct SessionInfoParser {
     /// Current cached session info
     cache: Option<SessionInfoCache>,
+    /// YAML repair options
+    options: PreprocessOptions,
 }
 
 impl Default for SessionInfoParser {
@@ -47,7 +105,17 @@ impl Default for SessionInfoParser {
 impl SessionInfoParser {
     /// Create new session info parser
     pub fn new() -> Self {
-        Self { cache: None }
+        Self::with_options(PreprocessOptions::default())
+    }
+
+    /// Create a parser with custom YAML preprocessing
+    pub fn with_options(options: PreprocessOptions) -> Self {
+        Self { cache: None, options }
+    }
+
+    /// Preprocessing options in use
+    pub fn options(&self) -> &PreprocessOptions {
+        &self.options
     }
 
     /// Parse session info from shared memory with caching
@@ -59,11 +127,10 @@ impl SessionInfoParser {
         session_version: u32,
     ) -> Result<SessionInfo> {
         // Check cache validity first
-        if let Some(cached) = &self.cache {
-            if cached.is_valid(session_version) {
-                debug!(version = session_version, "Using cached session info");
-                return Ok(cached.session_info.clone());
-            }
+        if let Some(cached) = self.cache.as_ref().filter(|cached| cached.is_valid(session_version))
+        {
+            debug!(version = session_version, "Using cached session info");
+            return Ok(cached.session_info.clone());
         }
 
         debug!(
@@ -88,35 +155,16 @@ impl SessionInfoParser {
     }
 
     /// Extract YAML string from shared memory
+    ///
+    /// Bounds are checked by [`crate::yaml_utils::extract_yaml`]. Unlike that
+    /// function, an empty session info string is an error here.
     pub fn extract_yaml_from_memory(
         &self,
         memory: &[u8],
         offset: i32,
         length: i32,
     ) -> Result<String> {
-        if offset < 0 || length <= 0 {
-            return Err(TelemetryError::Parse {
-                context: "Session info extraction".to_string(),
-                details: format!("Invalid offset {} or length {}", offset, length),
-            }
-            .into());
-        }
-
-        let start = offset as usize;
-        let end = start + (length as usize);
-
-        if end > memory.len() {
-            return Err(TelemetryError::Memory { offset: end, source: None }.into());
-        }
-
-        // Extract bytes and convert to string
-        let yaml_bytes = &memory[start..end];
-
-        // Find null terminator or use full length
-        let null_pos = yaml_bytes.iter().position(|&b| b == 0).unwrap_or(yaml_bytes.len());
-
-        // Convert to UTF-8 string
-        let yaml_str = String::from_utf8_lossy(&yaml_bytes[..null_pos]).to_string();
+        let yaml_str = crate::yaml_utils::extract_yaml(memory, offset, length)?;
 
         if yaml_str.trim().is_empty() {
             return Err(TelemetryError::Parse {
@@ -132,79 +180,101 @@ impl SessionInfoParser {
     /// Preprocess iRacing YAML to fix compatibility issues with unescaped characters
     /// Based on iRacing forum discussion: <https://forums.iracing.com/discussion/comment/374646#Comment_374646>
     pub fn preprocess_iracing_yaml(&self, yaml: &str) -> Result<String> {
-        const PROBLEMATIC_KEYS: &[&str] = &[
-            "AbbrevName:",
-            "TeamName:",
-            "UserName:",
-            "Initials:",
-            "DriverSetupName:",
-            "CarDesignStr:", // Car livery color codes - can start with comma
-        ];
-
-        // First pass: Remove all control characters (except \n, \r, \t)
+        self.preprocess_with_fixups(yaml).map(|(yaml, _)| yaml)
+    }
+
+    /// Preprocess iRacing YAML and report which repairs were applied
+    pub fn preprocess_with_fixups(&self, yaml: &str) -> Result<(String, PreprocessFixups)> {
+        let options = &self.options;
+        let mut fixups = PreprocessFixups::default();
+
+        // Handle edge case where input is just whitespace/newlines
+        if yaml.trim().is_empty() {
+            return Ok((yaml.to_string(), fixups));
+        }
+
+        // First pass: Remove control characters (except \n, \r, \t)
         let mut cleaned = String::with_capacity(yaml.len());
         for ch in yaml.chars() {
-            if ch.is_control() && ch != '\n' && ch != '\r' && ch != '\t' {
-                // Skip control characters except basic whitespace
+            if options.strip_control_chars && ch.is_control() && !matches!(ch, '\n' | '\r' | '\t') {
+                fixups.control_chars_removed += 1;
                 continue;
             }
             cleaned.push(ch);
         }
 
-        let lines: Vec<&str> = cleaned.lines().collect();
-        let mut result_lines = Vec::with_capacity(lines.len());
-
-        for line in lines {
-            let mut processed_line = line.to_string();
-
-            // Check if this line contains any problematic keys
-            for &key in PROBLEMATIC_KEYS {
-                if let Some(colon_pos) = line.find(key) {
-                    let after_colon = colon_pos + key.len();
-                    if let Some(value_start) =
-                        line[after_colon..].find(|c: char| !c.is_whitespace())
-                    {
-                        let actual_value_start = after_colon + value_start;
-                        let value = line[actual_value_start..].trim();
-
-                        if !value.is_empty() && !value.starts_with('\'') && !value.starts_with('"')
-                        {
-                            // Need to quote this value
-                            let escaped_value = value.replace('\'', "''");
-                            processed_line = format!(
-                                "{}{} '{}'",
-                                &line[..after_colon],
-                                &line[after_colon..actual_value_start],
-                                escaped_value
-                            );
-                        }
-                    }
-                    break; // Only process first match per line
+        // Second pass: re-attach lines that were split by a newline inside a value
+        let mut lines: Vec<String> = Vec::new();
+        for line in cleaned.lines() {
+            let trimmed = line.trim();
+            let is_continuation = options.join_wrapped_lines
+                && !trimmed.is_empty()
+                && !trimmed.contains(':')
+                && !trimmed.starts_with('-')
+                && !trimmed.starts_with('#')
+                && trimmed != "..."
+                && lines.last().is_some_and(|previous| key_value_split(previous).is_some());
+            match lines.last_mut() {
+                Some(previous) if is_continuation => {
+                    previous.push(' ');
+                    previous.push_str(trimmed);
+                    fixups.lines_joined += 1;
                 }
+                _ => lines.push(line.to_string()),
             }
-
-            result_lines.push(processed_line);
         }
 
-        let result = result_lines.join("\n");
+        // Third pass: quote values that would otherwise break the YAML parser
+        for line in &mut lines {
+            let Some((key, after_colon)) = key_value_split(line) else {
+                continue;
+            };
+            let Some(value_start) = line[after_colon..].find(|c: char| !c.is_whitespace()) else {
+                continue;
+            };
+            let actual_value_start = after_colon + value_start;
+            let value = line[actual_value_start..].trim();
 
-        // Handle edge case where input is just whitespace/newlines
-        if yaml.trim().is_empty() {
-            return Ok(yaml.to_string());
+            if value.starts_with('\'') || value.starts_with('"') {
+                continue;
+            }
+
+            let forced = options.force_quote_keys.iter().any(|forced| forced == key);
+            let has_colon =
+                options.quote_values_with_colons && (value.contains(": ") || value.ends_with(':'));
+            if forced || has_colon {
+                // Need to quote this value
+                let escaped_value = value.replace('\'', "''");
+                *line = format!(
+                    "{}{} '{}'",
+                    &line[..after_colon],
+                    &line[after_colon..actual_value_start],
+                    escaped_value
+                );
+                fixups.values_quoted += 1;
+            }
         }
 
-        Ok(result)
+        Ok((lines.join("\n"), fixups))
     }
 
     /// Parse YAML to SessionInfo struct (with automatic preprocessing)
     pub fn parse(&self, yaml: &str) -> Result<SessionInfo> {
+        self.parse_with_fixups(yaml).map(|(session_info, _)| session_info)
+    }
+
+    /// Parse YAML to SessionInfo, also returning the preprocessing repairs applied
+    pub fn parse_with_fixups(&self, yaml: &str) -> Result<(SessionInfo, PreprocessFixups)> {
         // Preprocess the YAML to handle iRacing's quirks (control characters, unquoted values)
-        let preprocessed = self.preprocess_iracing_yaml(yaml)?;
+        let (preprocessed, fixups) = self.preprocess_with_fixups(yaml)?;
+        if fixups.total() > 0 {
+            debug!(?fixups, "Repaired session YAML before parsing");
+        }
 
         match serde_yaml_ng::from_str::<SessionInfo>(&preprocessed) {
             Ok(session_info) => {
                 self.validate_session_info(&session_info)?;
-                Ok(session_info)
+                Ok((session_info, fixups))
             }
             Err(e) => Err(TelemetryError::Parse {
                 context: "Session YAML deserialization".to_string(),
@@ -214,6 +284,30 @@ impl SessionInfoParser {
         }
     }
 
+    /// Parse YAML to SessionInfo, failing if it contains fields the structs don't model
+    ///
+    /// Intended for CI checks against fresh iRacing builds: the error lists every
+    /// unexpected field path so schema drift is caught instead of silently dropped.
+    /// Only available when the `schema-discovery` feature is enabled.
+    #[cfg(feature = "schema-discovery")]
+    pub fn parse_strict(&self, yaml: &str) -> Result<SessionInfo> {
+        let session_info = self.parse(yaml)?;
+
+        let unknown = session_info.collect_unknown_fields();
+        if !unknown.is_empty() {
+            let mut paths: Vec<&str> = unknown.iter().map(|field| field.path.as_str()).collect();
+            paths.sort_unstable();
+            return Err(TelemetryError::schema_validation_error(
+                format!("Unknown session info fields: {}", paths.join(", ")),
+                None,
+                None,
+            )
+            .into());
+        }
+
+        Ok(session_info)
+    }
+
     /// Validate parsed session info for completeness
     pub fn validate_session_info(&self, session_info: &SessionInfo) -> Result<()> {
         if session_info.weekend_info.track_name.is_empty() {
@@ -256,3 +350,64 @@ impl SessionInfoParser {
         self.cache = None;
     }
 }
+
+/// Split a `key: value` line into its key and the byte offset just past the colon
+///
+/// Leading indentation and a list marker (`- `) are not part of the key.
+fn key_value_split(line: &str) -> Option<(&str, usize)> {
+    let colon_pos = line.find(':')?;
+    let key = line[..colon_pos].trim_start();
+    let key = key.strip_prefix("- ").unwrap_or(key).trim();
+    let is_key = !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_');
+    is_key.then_some((key, colon_pos + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yaml_preprocessing_options_repair_colons_and_wrapped_names() {
+        let yaml = "Drivers:\n- CarIdx: 0\n  UserName: Jane\n   Doe\n  CarScreenName: GT3: Evo\n  ClubName: Club\x07\n";
+
+        // Defaults only quote the known driver-entered keys
+        let (_, fixups) = SessionInfoParser::new().preprocess_with_fixups(yaml).unwrap();
+        assert_eq!(
+            fixups,
+            PreprocessFixups { control_chars_removed: 1, values_quoted: 1, lines_joined: 0 }
+        );
+
+        let mut options = PreprocessOptions {
+            quote_values_with_colons: true,
+            join_wrapped_lines: true,
+            ..Default::default()
+        };
+        options.force_quote_keys.push("ClubName".to_string());
+        let parser = SessionInfoParser::with_options(options);
+
+        let (result, fixups) = parser.preprocess_with_fixups(yaml).unwrap();
+        assert!(result.contains("UserName:  'Jane Doe'"), "{}", result);
+        assert!(result.contains("CarScreenName:  'GT3: Evo'"), "{}", result);
+        assert!(result.contains("ClubName:  'Club'"), "{}", result);
+        assert_eq!(fixups.lines_joined, 1);
+        assert_eq!(fixups.values_quoted, 3);
+        assert_eq!(fixups.total(), 5);
+
+        let drivers: serde_yaml_ng::Value = serde_yaml_ng::from_str(&result).unwrap();
+        assert_eq!(drivers["Drivers"][0]["CarScreenName"].as_str(), Some("GT3: Evo"));
+    }
+
+    #[cfg(feature = "schema-discovery")]
+    #[test]
+    fn parse_strict_lists_unknown_field_paths() {
+        let yaml = "WeekendInfo:\n  TrackName: spa\n  TrackDisplayName: Spa\n  TrackNewThing: 1\nSessionInfo:\n  Sessions:\n  - SessionNum: 0\n";
+        let parser = SessionInfoParser::new();
+
+        assert!(parser.parse(yaml).is_ok());
+        let error = parser.parse_strict(yaml).unwrap_err().to_string();
+        assert!(error.contains("WeekendInfo.TrackNewThing"), "{}", error);
+
+        let known = yaml.replace("  TrackNewThing: 1\n", "");
+        assert!(parser.parse_strict(&known).is_ok());
+    }
+}
@@ -24,6 +24,36 @@ pub struct CameraInfo {
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
 
+impl CameraInfo {
+    /// Find a camera group by name (e.g. `"TV1"`), ignoring ASCII case
+    pub fn group_by_name(&self, name: &str) -> Option<&CameraGroup> {
+        self.groups
+            .as_ref()?
+            .iter()
+            .find(|group| group.group_name.as_deref().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+    }
+
+    /// Find a camera by its `GroupNum` and `CameraNum`
+    pub fn camera_by_number(&self, group: i32, cam: i32) -> Option<&Camera> {
+        self.groups
+            .as_ref()?
+            .iter()
+            .find(|g| g.group_num == Some(group))?
+            .cameras
+            .as_ref()?
+            .iter()
+            .find(|camera| camera.camera_num == Some(cam))
+    }
+
+    /// Every camera paired with the group it belongs to, in session YAML order
+    pub fn all_cameras(&self) -> impl Iterator<Item = (&CameraGroup, &Camera)> {
+        self.groups
+            .iter()
+            .flatten()
+            .flat_map(|group| group.cameras.iter().flatten().map(move |camera| (group, camera)))
+    }
+}
+
 /// Camera group information
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
@@ -63,3 +93,46 @@ pub struct Camera {
     #[cfg_attr(feature = "tauri", specta(skip))]
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CAMERA_YAML: &str = r#"
+Groups:
+- GroupNum: 1
+  GroupName: Nose
+  Cameras:
+  - CameraNum: 1
+    CameraName: CamNose
+- GroupNum: 10
+  GroupName: TV1
+  Cameras:
+  - CameraNum: 1
+    CameraName: CamTV1
+  - CameraNum: 2
+    CameraName: CamTV2
+- GroupNum: 22
+  GroupName: Scenic
+  IsScenic: true
+"#;
+
+    #[test]
+    fn camera_lookups() {
+        let info: CameraInfo = serde_yaml_ng::from_str(CAMERA_YAML).unwrap();
+
+        assert_eq!(info.group_by_name("tv1").and_then(|g| g.group_num), Some(10));
+        assert!(info.group_by_name("Blimp").is_none());
+
+        let camera = info.camera_by_number(10, 2).expect("TV1 camera 2");
+        assert_eq!(camera.camera_name.as_deref(), Some("CamTV2"));
+        assert!(info.camera_by_number(10, 3).is_none());
+        assert!(info.camera_by_number(22, 1).is_none());
+
+        let all: Vec<(i32, i32)> = info
+            .all_cameras()
+            .map(|(group, camera)| (group.group_num.unwrap(), camera.camera_num.unwrap()))
+            .collect();
+        assert_eq!(all, vec![(1, 1), (10, 1), (10, 2)]);
+    }
+}
@@ -0,0 +1,199 @@
+//! Livery design string parsing
+//!
+//! iRacing encodes car, helmet and suit liveries as a pattern number followed by
+//! hex RGB colors, e.g. `CarDesignStr: 1,ff0000,ffffff,000000`. Car numbers add
+//! a style after the font, e.g. `CarNumberDesignStr: 0,0,ffffff,777777,000000`.
+//! Extra trailing values (such as a rim color) are ignored. These strings are known to be
+//! malformed in AI races, so parsing reports why a string was rejected.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::Driver;
+
+/// An RGB color from a design string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    /// Parse a six-digit hex color, with or without a leading `#`
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Color { r: channel(0)?, g: channel(2)?, b: channel(4)? })
+    }
+}
+
+/// Parsed livery design: a pattern number and its three colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct CarDesign {
+    /// Paint pattern number
+    pub pattern: u32,
+    /// Primary, secondary and tertiary colors
+    pub colors: [Color; 3],
+}
+
+/// Parsed car number design: font, style and three colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct CarNumberDesign {
+    /// Number font
+    pub font: u32,
+    /// Number style
+    pub style: u32,
+    /// Primary, secondary and tertiary colors
+    pub colors: [Color; 3],
+}
+
+/// Why a design string could not be parsed
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DesignParseError {
+    #[error("No design string present")]
+    Missing,
+
+    #[error("Invalid design pattern '{value}'")]
+    InvalidPattern { value: String },
+
+    #[error("Design string has no color {index}")]
+    MissingColor { index: usize },
+
+    #[error("Invalid design color {index} '{value}'")]
+    InvalidColor { index: usize, value: String },
+}
+
+impl CarDesign {
+    /// Parse a comma- or semicolon-delimited design string
+    pub fn parse(input: &str) -> Result<CarDesign, DesignParseError> {
+        let mut parts = split(input);
+        let pattern = parse_number(parts.next())?;
+        let colors = parse_colors(&mut parts)?;
+
+        Ok(CarDesign { pattern, colors })
+    }
+}
+
+impl CarNumberDesign {
+    /// Parse a comma- or semicolon-delimited car number design string
+    pub fn parse(input: &str) -> Result<CarNumberDesign, DesignParseError> {
+        let mut parts = split(input);
+        let font = parse_number(parts.next())?;
+        let style = parse_number(parts.next())?;
+        let colors = parse_colors(&mut parts)?;
+
+        Ok(CarNumberDesign { font, style, colors })
+    }
+}
+
+fn split(input: &str) -> impl Iterator<Item = &str> {
+    input.split([',', ';']).map(str::trim)
+}
+
+fn parse_number(part: Option<&str>) -> Result<u32, DesignParseError> {
+    match part {
+        None | Some("") => Err(DesignParseError::Missing),
+        Some(value) => value
+            .parse::<u32>()
+            .map_err(|_| DesignParseError::InvalidPattern { value: value.to_string() }),
+    }
+}
+
+fn parse_colors<'a>(
+    parts: &mut impl Iterator<Item = &'a str>,
+) -> Result<[Color; 3], DesignParseError> {
+    let mut colors = [Color { r: 0, g: 0, b: 0 }; 3];
+    for (index, color) in colors.iter_mut().enumerate() {
+        let value = parts.next().ok_or(DesignParseError::MissingColor { index })?;
+        *color = Color::from_hex(value)
+            .ok_or_else(|| DesignParseError::InvalidColor { index, value: value.to_string() })?;
+    }
+
+    Ok(colors)
+}
+
+impl Driver {
+    /// Parsed car livery from `CarDesignStr`
+    pub fn design(&self) -> Result<CarDesign, DesignParseError> {
+        parse_optional(&self.car_design_str)
+    }
+
+    /// Parsed helmet design from `HelmetDesignStr`
+    pub fn helmet_design(&self) -> Result<CarDesign, DesignParseError> {
+        parse_optional(&self.helmet_design_str)
+    }
+
+    /// Parsed suit design from `SuitDesignStr`
+    pub fn suit_design(&self) -> Result<CarDesign, DesignParseError> {
+        parse_optional(&self.suit_design_str)
+    }
+
+    /// Parsed car number design from `CarNumberDesignStr`
+    pub fn car_number_design(&self) -> Result<CarNumberDesign, DesignParseError> {
+        CarNumberDesign::parse(
+            self.car_number_design_str.as_deref().ok_or(DesignParseError::Missing)?,
+        )
+    }
+}
+
+fn parse_optional(field: &Option<String>) -> Result<CarDesign, DesignParseError> {
+    CarDesign::parse(field.as_deref().ok_or(DesignParseError::Missing)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_design_strings() {
+        let design = CarDesign::parse("1,ff0000,FFFFFF,000000,ed1c24").unwrap();
+        assert_eq!(design.pattern, 1);
+        assert_eq!(
+            design.colors,
+            [
+                Color { r: 255, g: 0, b: 0 },
+                Color { r: 255, g: 255, b: 255 },
+                Color { r: 0, g: 0, b: 0 },
+            ]
+        );
+
+        let semicolons = CarDesign::parse("22; #2e3192; ffffff; ed1c24").unwrap();
+        assert_eq!(semicolons.pattern, 22);
+        assert_eq!(semicolons.colors[0], Color { r: 0x2e, g: 0x31, b: 0x92 });
+
+        let number = CarNumberDesign::parse("0,1,ffffff,777777,000000").unwrap();
+        assert_eq!((number.font, number.style), (0, 1));
+        assert_eq!(number.colors[1], Color { r: 0x77, g: 0x77, b: 0x77 });
+    }
+
+    #[test]
+    fn reports_why_design_strings_fail() {
+        assert_eq!(CarDesign::parse(""), Err(DesignParseError::Missing));
+        assert_eq!(
+            CarDesign::parse("x,ff0000,ffffff,000000"),
+            Err(DesignParseError::InvalidPattern { value: "x".to_string() })
+        );
+        assert_eq!(CarDesign::parse("0,ff0000"), Err(DesignParseError::MissingColor { index: 1 }));
+        assert_eq!(
+            CarDesign::parse("0,ff0000,fffff,000000"),
+            Err(DesignParseError::InvalidColor { index: 1, value: "fffff".to_string() })
+        );
+
+        assert_eq!(
+            CarNumberDesign::parse("0,ffffff,777777,000000"),
+            Err(DesignParseError::InvalidPattern { value: "ffffff".to_string() })
+        );
+
+        let driver = Driver::default();
+        assert_eq!(driver.design(), Err(DesignParseError::Missing));
+        assert_eq!(driver.car_number_design(), Err(DesignParseError::Missing));
+    }
+}
@@ -0,0 +1,173 @@
+//! Change detection between session info updates
+//!
+//! iRacing re-publishes the whole session YAML whenever anything changes. This module
+//! compares two parsed snapshots so consumers can react to specific changes (drivers
+//! joining or leaving, session transitions, weather) without re-scanning everything.
+
+use serde::Serialize;
+
+use super::{SessionInfo, WeekendInfo};
+
+/// Changes between two session info snapshots
+#[derive(Default, Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct SessionDiff {
+    /// Car indices present in the new snapshot but not the old one
+    pub drivers_added: Vec<i32>,
+    /// Car indices present in the old snapshot but not the new one
+    pub drivers_removed: Vec<i32>,
+    /// `(old, new)` current session number when it changed
+    pub session_num_change: Option<(i32, i32)>,
+    /// Weather and track condition fields that changed
+    pub weather_changes: Vec<FieldChange>,
+}
+
+impl SessionDiff {
+    /// Returns true when no tracked field changed
+    pub fn is_empty(&self) -> bool {
+        self.drivers_added.is_empty()
+            && self.drivers_removed.is_empty()
+            && self.session_num_change.is_none()
+            && self.weather_changes.is_empty()
+    }
+
+    /// Returns true when any driver joined or left
+    pub fn drivers_changed(&self) -> bool {
+        !self.drivers_added.is_empty() || !self.drivers_removed.is_empty()
+    }
+
+    /// Returns true when the current session number changed
+    pub fn session_changed(&self) -> bool {
+        self.session_num_change.is_some()
+    }
+
+    /// Returns true when any weather or track condition field changed
+    pub fn weather_changed(&self) -> bool {
+        !self.weather_changes.is_empty()
+    }
+}
+
+/// A single changed session info field
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct FieldChange {
+    /// Field name as it appears in the session YAML (e.g. `TrackAirTemp`)
+    pub field: String,
+    /// Value in the old snapshot
+    pub old: Option<String>,
+    /// Value in the new snapshot
+    pub new: Option<String>,
+}
+
+type WeatherField = (&'static str, fn(&WeekendInfo) -> &Option<String>);
+
+/// Weather and track condition fields compared by [`SessionInfo::diff`]
+const WEATHER_FIELDS: [WeatherField; 10] = [
+    ("TrackWeatherType", |w| &w.track_weather_type),
+    ("TrackSkies", |w| &w.track_skies),
+    ("TrackSurfaceTemp", |w| &w.track_surface_temp),
+    ("TrackAirTemp", |w| &w.track_air_temp),
+    ("TrackAirPressure", |w| &w.track_air_pressure),
+    ("TrackWindVel", |w| &w.track_wind_vel),
+    ("TrackWindDir", |w| &w.track_wind_dir),
+    ("TrackRelativeHumidity", |w| &w.track_relative_humidity),
+    ("TrackFogLevel", |w| &w.track_fog_level),
+    ("TrackPrecipitation", |w| &w.track_precipitation),
+];
+
+impl SessionInfo {
+    /// Compare this snapshot against a newer one
+    ///
+    /// Drivers are matched by `car_idx`. Returned car indices are sorted.
+    pub fn diff(&self, other: &SessionInfo) -> SessionDiff {
+        let old_cars = car_indices(self);
+        let new_cars = car_indices(other);
+
+        let drivers_added =
+            new_cars.iter().filter(|idx| !old_cars.contains(idx)).copied().collect();
+        let drivers_removed =
+            old_cars.iter().filter(|idx| !new_cars.contains(idx)).copied().collect();
+
+        let old_session = self.session_info.current_session_num;
+        let new_session = other.session_info.current_session_num;
+        let session_num_change = (old_session != new_session).then_some((old_session, new_session));
+
+        let weather_changes = WEATHER_FIELDS
+            .iter()
+            .filter_map(|(field, get)| {
+                let old = get(&self.weekend_info);
+                let new = get(&other.weekend_info);
+                (old != new).then(|| FieldChange {
+                    field: field.to_string(),
+                    old: old.clone(),
+                    new: new.clone(),
+                })
+            })
+            .collect();
+
+        SessionDiff { drivers_added, drivers_removed, session_num_change, weather_changes }
+    }
+}
+
+/// Sorted, de-duplicated car indices from the drivers list
+fn car_indices(info: &SessionInfo) -> Vec<i32> {
+    let mut indices: Vec<i32> = info
+        .driver_info
+        .as_ref()
+        .and_then(|driver_info| driver_info.drivers.as_ref())
+        .map(|drivers| drivers.iter().map(|driver| driver.car_idx).collect())
+        .unwrap_or_default();
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::session::{Driver, DriverInfoData};
+
+    fn session_with(car_indices: &[i32], session_num: i32, air_temp: &str) -> SessionInfo {
+        let drivers = car_indices
+            .iter()
+            .map(|&car_idx| Driver {
+                car_idx,
+                user_name: format!("Driver {}", car_idx),
+                ..Default::default()
+            })
+            .collect();
+
+        let mut info = SessionInfo {
+            driver_info: Some(DriverInfoData { drivers: Some(drivers), ..Default::default() }),
+            ..Default::default()
+        };
+        info.session_info.current_session_num = session_num;
+        info.weekend_info.track_air_temp = Some(air_temp.to_string());
+        info
+    }
+
+    #[test]
+    fn diff_reports_driver_session_and_weather_changes() {
+        let old = session_with(&[0, 1, 2], 0, "20.69 C");
+        let new = session_with(&[0, 2, 5], 1, "22.10 C");
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.drivers_added, vec![5]);
+        assert_eq!(diff.drivers_removed, vec![1]);
+        assert_eq!(diff.session_num_change, Some((0, 1)));
+        assert_eq!(
+            diff.weather_changes,
+            vec![FieldChange {
+                field: "TrackAirTemp".to_string(),
+                old: Some("20.69 C".to_string()),
+                new: Some("22.10 C".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_of_identical_snapshots_is_empty() {
+        let info = session_with(&[0, 1], 2, "20.69 C");
+        assert!(info.diff(&info.clone()).is_empty());
+    }
+}
@@ -98,6 +98,55 @@ pub struct DriverInfoData {
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
 
+impl DriverInfoData {
+    /// Find a driver by car index
+    pub fn driver_by_car_idx(&self, idx: i32) -> Option<&Driver> {
+        self.drivers.as_ref()?.iter().find(|driver| driver.car_idx == idx)
+    }
+
+    /// The driver this telemetry belongs to (from `DriverCarIdx`)
+    pub fn current_driver(&self) -> Option<&Driver> {
+        self.driver_by_car_idx(self.driver_car_idx?)
+    }
+
+    /// The pace car (from `PaceCarIdx`, falling back to `CarIsPaceCar`)
+    pub fn pace_car(&self) -> Option<&Driver> {
+        match self.pace_car_idx {
+            Some(idx) if idx >= 0 => self.driver_by_car_idx(idx),
+            _ => self.drivers.as_ref()?.iter().find(|driver| driver.car_is_pace_car == Some(1)),
+        }
+    }
+
+    /// Usable fuel in liters: the tank size (`DriverCarFuelMaxLtr`) limited by the
+    /// series restriction (`DriverCarMaxFuelPct`, a 0-1 fraction)
+    ///
+    /// Returns `None` when the tank size is unknown or zero, as it is for electric cars.
+    pub fn fuel_capacity_liters(&self) -> Option<f64> {
+        let max_pct = self.driver_car_max_fuel_pct.unwrap_or(1.0);
+        Some(self.tank_liters()? * max_pct)
+    }
+
+    /// Convert telemetry `FuelLevelPct` (a 0-1 fraction of the full tank) to liters
+    pub fn fuel_liters_from_level(&self, fuel_level_pct: f32) -> Option<f64> {
+        Some(self.tank_liters()? * f64::from(fuel_level_pct))
+    }
+
+    /// Convert liters (e.g. telemetry `FuelLevel`) to a `FuelLevelPct` fraction of the full tank
+    pub fn fuel_level_from_liters(&self, liters: f32) -> Option<f32> {
+        Some((f64::from(liters) / self.tank_liters()?) as f32)
+    }
+
+    /// Mass in kilograms of `liters` of fuel (from `DriverCarFuelKgPerLtr`)
+    pub fn fuel_kg_from_liters(&self, liters: f32) -> Option<f64> {
+        let density = self.driver_car_fuel_kg_per_ltr.filter(|density| *density > 0.0)?;
+        Some(f64::from(liters) * density)
+    }
+
+    fn tank_liters(&self) -> Option<f64> {
+        self.driver_car_fuel_max_ltr.filter(|liters| *liters > 0.0)
+    }
+}
+
 /// Driver tire compound information
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
@@ -209,18 +258,18 @@ pub struct Driver {
     pub is_spectator: Option<i32>,
     /// Car design string (livery colors)
     ///
-    /// **Note**: Known to contain malformed data in AI races. Parse failures
-    /// will result in None. Application should handle missing design strings gracefully.
+    /// **Note**: Known to contain malformed data in AI races. Use
+    /// [`Driver::design`] for a parsed form that reports why parsing failed.
     pub car_design_str: Option<String>,
     /// Helmet design string
     ///
-    /// **Note**: Known to contain malformed data in AI races. Parse failures
-    /// will result in None. Application should handle missing design strings gracefully.
+    /// **Note**: Known to contain malformed data in AI races. Use
+    /// [`Driver::helmet_design`] for a parsed form that reports why parsing failed.
     pub helmet_design_str: Option<String>,
     /// Suit design string
     ///
-    /// **Note**: Known to contain malformed data in AI races. Parse failures
-    /// will result in None. Application should handle missing design strings gracefully.
+    /// **Note**: Known to contain malformed data in AI races. Use
+    /// [`Driver::suit_design`] for a parsed form that reports why parsing failed.
     pub suit_design_str: Option<String>,
     /// Body type (avatar)
     pub body_type: Option<i32>,
@@ -235,8 +284,8 @@ pub struct Driver {
     pub flair_name: Option<String>,
     /// Car number design string
     ///
-    /// **Note**: Known to contain malformed data in AI races. Parse failures
-    /// will result in None. Application should handle missing design strings gracefully.
+    /// **Note**: Known to contain malformed data in AI races. Use
+    /// [`Driver::car_number_design`] for a parsed form that reports why parsing failed.
     pub car_number_design_str: Option<String>,
     /// Car sponsor 1
     #[serde(rename = "CarSponsor_1")]
@@ -255,3 +304,83 @@ pub struct Driver {
     #[cfg_attr(feature = "tauri", specta(skip))]
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
+
+impl Driver {
+    /// Name for display, falling back from `UserName` to `AbbrevName` to `Car {car_idx}`
+    pub fn display_name(&self) -> String {
+        if !self.user_name.trim().is_empty() {
+            return self.user_name.clone();
+        }
+
+        match self.abbrev_name.as_deref().map(str::trim) {
+            Some(abbrev) if !abbrev.is_empty() => abbrev.to_string(),
+            _ => format!("Car {}", self.car_idx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn driver(car_idx: i32, user_name: &str) -> Driver {
+        Driver { car_idx, user_name: user_name.to_string(), ..Default::default() }
+    }
+
+    #[test]
+    fn driver_lookup_helpers() {
+        let mut pace_car = driver(0, "Pace Car");
+        pace_car.car_is_pace_car = Some(1);
+        let info = DriverInfoData {
+            driver_car_idx: Some(2),
+            drivers: Some(vec![pace_car, driver(1, "Alex Rivera"), driver(2, "Sam Okafor")]),
+            ..Default::default()
+        };
+
+        assert_eq!(info.driver_by_car_idx(1).map(|d| d.user_name.as_str()), Some("Alex Rivera"));
+        assert!(info.driver_by_car_idx(7).is_none());
+        assert_eq!(info.current_driver().map(|d| d.car_idx), Some(2));
+        assert_eq!(info.pace_car().map(|d| d.car_idx), Some(0));
+
+        let info = DriverInfoData { pace_car_idx: Some(1), ..info };
+        assert_eq!(info.pace_car().map(|d| d.car_idx), Some(1));
+        assert!(DriverInfoData::default().current_driver().is_none());
+    }
+
+    #[test]
+    fn fuel_conversions() {
+        let info = DriverInfoData {
+            driver_car_fuel_kg_per_ltr: Some(0.75),
+            driver_car_fuel_max_ltr: Some(110.0),
+            driver_car_max_fuel_pct: Some(0.6),
+            ..Default::default()
+        };
+
+        assert!((info.fuel_capacity_liters().unwrap() - 66.0).abs() < 1e-9);
+        assert!((info.fuel_liters_from_level(0.25).unwrap() - 27.5).abs() < 1e-9);
+        assert!((info.fuel_level_from_liters(27.5).unwrap() - 0.25).abs() < 1e-6);
+        assert!((info.fuel_kg_from_liters(10.0).unwrap() - 7.5).abs() < 1e-9);
+
+        let electric = DriverInfoData {
+            driver_car_fuel_kg_per_ltr: Some(0.0),
+            driver_car_fuel_max_ltr: Some(0.0),
+            ..Default::default()
+        };
+        assert_eq!(electric.fuel_capacity_liters(), None);
+        assert_eq!(electric.fuel_level_from_liters(1.0), None);
+        assert_eq!(electric.fuel_kg_from_liters(1.0), None);
+    }
+
+    #[test]
+    fn display_name_falls_back() {
+        let mut d = driver(12, "Alex Rivera");
+        assert_eq!(d.display_name(), "Alex Rivera");
+
+        d.user_name.clear();
+        d.abbrev_name = Some("Rivera, A".to_string());
+        assert_eq!(d.display_name(), "Rivera, A");
+
+        d.abbrev_name = None;
+        assert_eq!(d.display_name(), "Car 12");
+    }
+}
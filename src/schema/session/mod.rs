@@ -61,26 +61,37 @@ use std::collections::HashMap;
 // Submodules
 pub mod cache;
 pub mod camera;
+pub mod design;
+pub mod diff;
 #[cfg(feature = "schema-discovery")]
 pub mod discovery;
 pub mod driver;
+pub mod quantity;
 pub mod radio;
 pub mod session_data;
+pub mod setup;
+pub mod summary;
 pub mod timing;
+pub mod weather;
 pub mod weekend;
 
 // Re-exports for backward compatibility
-pub use cache::{SessionInfoCache, SessionInfoParser};
+pub use cache::{PreprocessFixups, PreprocessOptions, SessionInfoCache, SessionInfoParser};
 pub use camera::{Camera, CameraGroup, CameraInfo};
+pub use design::{CarDesign, CarNumberDesign, Color, DesignParseError};
+pub use diff::{FieldChange, SessionDiff};
 #[cfg(feature = "schema-discovery")]
 pub use discovery::{
     UnknownField, UnknownFieldType, collect_leaf_fields, value_to_example, value_to_type,
 };
 pub use driver::{Driver, DriverInfoData, DriverTire};
+pub use quantity::Quantity;
 pub use radio::{Frequency, Radio, RadioInfo};
-pub use session_data::{QualifyResult, QualifyResultsInfo, Session, SessionInfoData};
+pub use session_data::{QualifyResult, QualifyResultsInfo, Session, SessionInfoData, SessionType};
+pub use setup::{CarSetup, SetupSection, SetupTires, TireSetup};
 pub use timing::{Sector, SplitTimeInfo};
-pub use weekend::{TelemetryOptions, WeekendInfo, WeekendOptions};
+pub use weather::{CompassPoint, RubberState, Weather};
+pub use weekend::{RestartType, StartType, TelemetryOptions, WeekendInfo, WeekendOptions};
 
 /// Session information extracted and parsed from iRacing's YAML session data
 /// This matches the actual structure that iRacing outputs
@@ -101,7 +112,7 @@ pub struct SessionInfo {
     /// Split timing information
     #[serde(default)]
     pub split_time_info: Option<SplitTimeInfo>,
-    /// Car setup information
+    /// Car setup information (see [`SessionInfo::car_setup_typed`] for a typed view)
     #[serde(default)]
     #[cfg_attr(feature = "tauri", specta(skip))]
     pub car_setup: Option<serde_yaml_ng::Value>,
@@ -657,7 +668,7 @@ SessionState: Racing
         }
     }
 
-    #[cfg(windows)]
+    #[cfg(all(windows, feature = "runtime"))]
     #[test]
     #[ignore = "iracing_required"]
     fn parses_live_iracing_session_info() {
@@ -680,11 +691,14 @@ SessionState: Racing
 
         // Get and parse session info
         let parser = SessionInfoParser::new();
-        let raw_yaml = connection.session_info().expect("Failed to get session info from iRacing");
+        let raw_yaml = connection
+            .session_info()
+            .expect("Failed to read session info")
+            .expect("Failed to get session info from iRacing");
 
         // Preprocess the YAML to handle control characters
         let preprocessed_yaml =
-            parser.preprocess_iracing_yaml(raw_yaml).expect("Failed to preprocess YAML");
+            parser.preprocess_iracing_yaml(&raw_yaml).expect("Failed to preprocess YAML");
 
         let session_info =
             parser.parse(&preprocessed_yaml).expect("Failed to parse live session info");
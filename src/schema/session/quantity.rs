@@ -0,0 +1,189 @@
+//! Numeric quantities with units from session info strings
+//!
+//! iRacing reports many session values as `"<number> <unit>"` strings, for example
+//! `TrackLength: 6.1441 km` or `TrackAirTemp: 20.69 C`. [`Quantity`] parses these
+//! into a value and unit, and converts between the units iRacing commonly emits.
+
+use serde::{Deserialize, Serialize};
+
+use super::WeekendInfo;
+
+/// A numeric value with its unit, parsed from a session info string
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct Quantity {
+    /// Numeric value
+    pub value: f64,
+    /// Unit as written by iRacing (e.g. `km`, `C`, `m/s`); empty when unitless
+    pub unit: String,
+}
+
+impl Quantity {
+    /// Parse a `"<number> <unit>"` string such as `"6.1441 km"` or `"31 %"`.
+    ///
+    /// The space is optional (`"31%"` also parses). Returns `None` when the
+    /// string does not start with a number.
+    pub fn parse(input: &str) -> Option<Quantity> {
+        let input = input.trim();
+        let split = input
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+            .unwrap_or(input.len());
+        let (number, unit) = input.split_at(split);
+
+        let value = number.parse::<f64>().ok()?;
+        Some(Quantity { value, unit: unit.trim().to_string() })
+    }
+
+    /// Value in meters, if this is a length (`km`, `m`, `mi`, `ft`)
+    pub fn meters(&self) -> Option<f64> {
+        match self.unit.as_str() {
+            "km" => Some(self.value * 1000.0),
+            "m" => Some(self.value),
+            "mi" => Some(self.value * 1609.344),
+            "ft" => Some(self.value * 0.3048),
+            _ => None,
+        }
+    }
+
+    /// Value in degrees Celsius, if this is a temperature (`C`, `F`)
+    pub fn celsius(&self) -> Option<f64> {
+        match self.unit.as_str() {
+            "C" => Some(self.value),
+            "F" => Some((self.value - 32.0) * 5.0 / 9.0),
+            _ => None,
+        }
+    }
+
+    /// Value in meters per second, if this is a speed (`m/s`, `km/h`, `kph`, `mph`)
+    pub fn meters_per_second(&self) -> Option<f64> {
+        match self.unit.as_str() {
+            "m/s" => Some(self.value),
+            "km/h" | "kph" => Some(self.value / 3.6),
+            "mph" => Some(self.value * 0.44704),
+            _ => None,
+        }
+    }
+
+    /// Value in radians, if this is an angle (`rad`, `deg`)
+    pub fn radians(&self) -> Option<f64> {
+        match self.unit.as_str() {
+            "rad" => Some(self.value),
+            "deg" => Some(self.value.to_radians()),
+            _ => None,
+        }
+    }
+
+    /// Value as a percentage, if the unit is `%`
+    pub fn percent(&self) -> Option<f64> {
+        (self.unit == "%").then_some(self.value)
+    }
+
+    /// Value in kilograms, if this is a mass (`kg`, `lb`)
+    pub fn kilograms(&self) -> Option<f64> {
+        match self.unit.as_str() {
+            "kg" => Some(self.value),
+            "lb" | "lbs" => Some(self.value * 0.453_592_37),
+            _ => None,
+        }
+    }
+}
+
+impl WeekendInfo {
+    /// Track length in meters (from `TrackLength`)
+    pub fn track_length_meters(&self) -> Option<f64> {
+        Quantity::parse(&self.track_length)?.meters()
+    }
+
+    /// Track altitude in meters (from `TrackAltitude`)
+    pub fn altitude_meters(&self) -> Option<f64> {
+        parse_optional(&self.track_altitude)?.meters()
+    }
+
+    /// Air temperature in degrees Celsius (from `TrackAirTemp`)
+    pub fn air_temp_celsius(&self) -> Option<f64> {
+        parse_optional(&self.track_air_temp)?.celsius()
+    }
+
+    /// Track surface temperature in degrees Celsius (from `TrackSurfaceTemp`)
+    pub fn surface_temp_celsius(&self) -> Option<f64> {
+        parse_optional(&self.track_surface_temp)?.celsius()
+    }
+
+    /// Wind speed in meters per second (from `TrackWindVel`)
+    pub fn wind_speed_mps(&self) -> Option<f64> {
+        parse_optional(&self.track_wind_vel)?.meters_per_second()
+    }
+
+    /// Wind direction in radians (from `TrackWindDir`)
+    pub fn wind_direction_radians(&self) -> Option<f64> {
+        parse_optional(&self.track_wind_dir)?.radians()
+    }
+
+    /// Relative humidity percentage (from `TrackRelativeHumidity`)
+    pub fn relative_humidity_percent(&self) -> Option<f64> {
+        parse_optional(&self.track_relative_humidity)?.percent()
+    }
+
+    /// Pit lane speed limit in meters per second (from `TrackPitSpeedLimit`)
+    pub fn pit_speed_limit_mps(&self) -> Option<f64> {
+        parse_optional(&self.track_pit_speed_limit)?.meters_per_second()
+    }
+}
+
+fn parse_optional(field: &Option<String>) -> Option<Quantity> {
+    Quantity::parse(field.as_deref()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_iracing_quantity_strings() {
+        assert_eq!(
+            Quantity::parse("6.1441 km"),
+            Some(Quantity { value: 6.1441, unit: "km".to_string() })
+        );
+        assert_eq!(Quantity::parse("31 %"), Some(Quantity { value: 31.0, unit: "%".to_string() }));
+        assert_eq!(Quantity::parse("31%"), Some(Quantity { value: 31.0, unit: "%".to_string() }));
+        assert_eq!(
+            Quantity::parse("-0.25 rad"),
+            Some(Quantity { value: -0.25, unit: "rad".to_string() })
+        );
+        assert_eq!(Quantity::parse("12"), Some(Quantity { value: 12.0, unit: String::new() }));
+        assert_eq!(Quantity::parse("unlimited"), None);
+        assert_eq!(Quantity::parse(""), None);
+    }
+
+    #[test]
+    fn converts_common_units() {
+        let km = Quantity::parse("6.1441 km").unwrap();
+        assert!((km.meters().unwrap() - 6144.1).abs() < 1e-9);
+        assert_eq!(km.celsius(), None, "Length is not a temperature");
+
+        let fahrenheit = Quantity::parse("212 F").unwrap();
+        assert!((fahrenheit.celsius().unwrap() - 100.0).abs() < 1e-9);
+
+        let kph = Quantity::parse("60.00 kph").unwrap();
+        assert!((kph.meters_per_second().unwrap() - 16.666_666).abs() < 1e-5);
+
+        assert_eq!(Quantity::parse("88.2 kg").unwrap().kilograms(), Some(88.2));
+    }
+
+    #[test]
+    fn weekend_info_typed_accessors() {
+        let weekend = WeekendInfo {
+            track_length: "6.1441 km".to_string(),
+            track_air_temp: Some("20.69 C".to_string()),
+            track_wind_vel: Some("4.33 m/s".to_string()),
+            track_relative_humidity: Some("31 %".to_string()),
+            ..Default::default()
+        };
+
+        assert!((weekend.track_length_meters().unwrap() - 6144.1).abs() < 1e-9);
+        assert_eq!(weekend.air_temp_celsius(), Some(20.69));
+        assert_eq!(weekend.wind_speed_mps(), Some(4.33));
+        assert_eq!(weekend.relative_humidity_percent(), Some(31.0));
+        assert_eq!(weekend.surface_temp_celsius(), None);
+    }
+}
@@ -26,6 +26,14 @@ pub struct RadioInfo {
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
 
+impl RadioInfo {
+    /// The radio currently selected for transmitting (from `SelectedRadioNum`)
+    pub fn active_radio(&self) -> Option<&Radio> {
+        let selected = self.selected_radio_num?;
+        self.radios.as_ref()?.iter().find(|radio| radio.radio_num == Some(selected))
+    }
+}
+
 /// Individual radio configuration
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
@@ -52,6 +60,24 @@ pub struct Radio {
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
 
+impl Radio {
+    /// The frequency this radio is tuned to (from `TunedToFrequencyNum`)
+    pub fn tuned_frequency(&self) -> Option<&Frequency> {
+        let tuned = self.tuned_to_frequency_num?;
+        self.frequencies.as_ref()?.iter().find(|freq| freq.frequency_num == Some(tuned))
+    }
+
+    /// Returns true when the radio is scanning its scannable frequencies
+    pub fn is_scanning(&self) -> bool {
+        self.scanning_is_on == Some(1)
+    }
+
+    /// Frequencies included when scanning (`CanScan: 1`)
+    pub fn scannable_frequencies(&self) -> impl Iterator<Item = &Frequency> {
+        self.frequencies.iter().flatten().filter(|freq| freq.can_scan == Some(1))
+    }
+}
+
 /// Radio frequency configuration
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
@@ -88,3 +114,47 @@ pub struct Frequency {
     #[cfg_attr(feature = "tauri", specta(skip))]
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RADIO_YAML: &str = r#"
+SelectedRadioNum: 0
+Radios:
+- RadioNum: 0
+  HopCount: 2
+  NumFrequencies: 3
+  TunedToFrequencyNum: 1
+  ScanningIsOn: 1
+  Frequencies:
+  - FrequencyNum: 0
+    FrequencyName: "@ALLTEAMS"
+    CanScan: 0
+  - FrequencyNum: 1
+    FrequencyName: "@DRIVERS"
+    CanScan: 1
+  - FrequencyNum: 2
+    FrequencyName: "@CLUB"
+    CanScan: 1
+"#;
+
+    #[test]
+    fn active_radio_and_tuned_frequency() {
+        let mut info: RadioInfo = serde_yaml_ng::from_str(RADIO_YAML).unwrap();
+
+        let active = info.active_radio().expect("radio 0 is selected");
+        assert!(active.is_scanning());
+        assert_eq!(
+            active.tuned_frequency().and_then(|freq| freq.frequency_name.as_deref()),
+            Some("@DRIVERS")
+        );
+        let scannable: Vec<i32> =
+            active.scannable_frequencies().filter_map(|freq| freq.frequency_num).collect();
+        assert_eq!(scannable, vec![1, 2]);
+
+        info.selected_radio_num = Some(3);
+        assert!(info.active_radio().is_none());
+        assert!(Radio::default().tuned_frequency().is_none());
+    }
+}
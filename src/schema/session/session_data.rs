@@ -4,10 +4,10 @@
 //! and individual session information.
 
 use serde::{Deserialize, Serialize};
-
-#[cfg(feature = "schema-discovery")]
 use std::collections::HashMap;
 
+use super::DriverInfoData;
+
 /// Session information data from iRacing
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
@@ -26,6 +26,24 @@ pub struct SessionInfoData {
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
 
+impl SessionInfoData {
+    /// The session currently running (from `CurrentSessionNum`)
+    pub fn current_session(&self) -> Option<&Session> {
+        self.sessions.iter().find(|session| session.session_num == self.current_session_num)
+    }
+
+    /// Make `session_num` the current session.
+    ///
+    /// Returns false, leaving the data unchanged, when no session has that number.
+    pub fn select_session(&mut self, session_num: i32) -> bool {
+        let exists = self.sessions.iter().any(|session| session.session_num == session_num);
+        if exists {
+            self.current_session_num = session_num;
+        }
+        exists
+    }
+}
+
 /// Individual session data
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
@@ -80,6 +98,62 @@ pub struct Session {
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
 
+impl Session {
+    /// Typed session type (from `SessionType`)
+    pub fn kind(&self) -> SessionType {
+        SessionType::from(self.session_type.as_str())
+    }
+}
+
+/// Kind of session within a weekend, parsed from `SessionType`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum SessionType {
+    /// Practice session
+    Practice,
+    /// Qualifying with all cars on track
+    OpenQualify,
+    /// Qualifying alone on track
+    LoneQualify,
+    /// Race
+    Race,
+    /// Test drive outside a hosted or official session
+    OfflineTesting,
+    /// Warmup before the race
+    Warmup,
+    /// Any other value, kept verbatim
+    Other(String),
+}
+
+impl SessionType {
+    /// Returns true for open and lone qualifying
+    pub fn is_qualifying(&self) -> bool {
+        matches!(self, SessionType::OpenQualify | SessionType::LoneQualify)
+    }
+
+    /// Returns true for races
+    pub fn is_race(&self) -> bool {
+        matches!(self, SessionType::Race)
+    }
+}
+
+impl From<&str> for SessionType {
+    /// Case and whitespace are ignored, so `"Open Qualify"` and `"open qualify"` match.
+    fn from(value: &str) -> Self {
+        let key: String =
+            value.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect();
+        match key.as_str() {
+            "practice" => SessionType::Practice,
+            "openqualify" => SessionType::OpenQualify,
+            "lonequalify" => SessionType::LoneQualify,
+            "race" => SessionType::Race,
+            "offlinetesting" => SessionType::OfflineTesting,
+            "warmup" => SessionType::Warmup,
+            _ => SessionType::Other(value.trim().to_string()),
+        }
+    }
+}
+
 /// Qualifying results information
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
@@ -96,7 +170,54 @@ pub struct QualifyResultsInfo {
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
 
+impl QualifyResultsInfo {
+    /// Results in grid order (ascending overall position).
+    ///
+    /// Results without a position are placed last.
+    pub fn sorted_by_position(&self) -> Vec<&QualifyResult> {
+        let mut results: Vec<&QualifyResult> = self.results.iter().flatten().collect();
+        results.sort_by_key(|result| (result.position.is_none(), result.position));
+        results
+    }
+
+    /// Fastest result per car class, keyed by `CarClassID`.
+    ///
+    /// Each result's class is looked up through its driver in `drivers`. Results
+    /// without a valid lap time (iRacing reports `-1`) or without a matching driver
+    /// are ignored.
+    pub fn fastest_by_class(&self, drivers: &DriverInfoData) -> HashMap<i32, &QualifyResult> {
+        let mut fastest: HashMap<i32, &QualifyResult> = HashMap::new();
+
+        for result in self.results.iter().flatten() {
+            let Some(time) = result.fastest_time.filter(|time| *time > 0.0) else {
+                continue;
+            };
+            let Some(class_id) = result
+                .car_idx
+                .and_then(|idx| drivers.driver_by_car_idx(idx))
+                .and_then(|driver| driver.car_class_id)
+            else {
+                continue;
+            };
+
+            fastest
+                .entry(class_id)
+                .and_modify(|best| {
+                    if best.fastest_time.is_none_or(|best_time| time < best_time) {
+                        *best = result;
+                    }
+                })
+                .or_insert(result);
+        }
+
+        fastest
+    }
+}
+
 /// Individual qualifying result
+///
+/// Results refer to drivers by `car_idx`; use [`DriverInfoData::driver_by_car_idx`]
+/// to resolve the [`Driver`](super::Driver) (name, car, class) for a result.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
 #[serde(rename_all = "PascalCase")]
@@ -106,7 +227,7 @@ pub struct QualifyResult {
     pub position: Option<i32>,
     /// Class position
     pub class_position: Option<i32>,
-    /// Car index
+    /// Car index, matching [`Driver::car_idx`](super::Driver::car_idx)
     pub car_idx: Option<i32>,
     /// Fastest lap number
     pub fastest_lap: Option<i32>,
@@ -119,3 +240,90 @@ pub struct QualifyResult {
     #[cfg_attr(feature = "tauri", specta(skip))]
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::session::Driver;
+
+    fn result(position: Option<i32>, car_idx: i32, fastest_time: f64) -> QualifyResult {
+        QualifyResult {
+            position,
+            car_idx: Some(car_idx),
+            fastest_time: Some(fastest_time),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[allow(clippy::needless_update)] // `unknown_fields` exists with schema-discovery
+    fn qualifying_grid_and_class_poles() {
+        let info = QualifyResultsInfo {
+            results: Some(vec![
+                result(Some(2), 3, 92.1),
+                result(None, 4, -1.0),
+                result(Some(0), 1, 90.5),
+                result(Some(1), 2, 91.0),
+            ]),
+            ..Default::default()
+        };
+
+        let grid: Vec<Option<i32>> =
+            info.sorted_by_position().iter().map(|result| result.car_idx).collect();
+        assert_eq!(grid, vec![Some(1), Some(2), Some(3), Some(4)]);
+
+        // Cars 1 and 3 are class 10, car 2 is class 20, car 4 has no lap
+        let drivers = DriverInfoData {
+            drivers: Some(
+                [(1, 10), (2, 20), (3, 10), (4, 20)]
+                    .into_iter()
+                    .map(|(car_idx, class)| Driver {
+                        car_idx,
+                        car_class_id: Some(class),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        let poles = info.fastest_by_class(&drivers);
+        assert_eq!(poles.len(), 2);
+        assert_eq!(poles[&10].car_idx, Some(1));
+        assert_eq!(poles[&20].car_idx, Some(2));
+    }
+
+    #[test]
+    fn current_session_and_kind() {
+        let yaml = r#"
+CurrentSessionNum: 2
+Sessions:
+ - SessionNum: 0
+   SessionType: Practice
+ - SessionNum: 1
+   SessionType: Open Qualify
+ - SessionNum: 2
+   SessionType: Race
+"#;
+        let info: SessionInfoData = serde_yaml_ng::from_str(yaml).unwrap();
+
+        let current = info.current_session().unwrap();
+        assert_eq!(current.session_num, 2);
+        assert!(current.kind().is_race());
+        assert_eq!(info.sessions[1].kind(), SessionType::OpenQualify);
+
+        assert_eq!(SessionType::from("lone qualify"), SessionType::LoneQualify);
+        assert_eq!(SessionType::from("Offline Testing"), SessionType::OfflineTesting);
+        assert_eq!(SessionType::from("Warmup"), SessionType::Warmup);
+        assert_eq!(SessionType::from(" Time Trial "), SessionType::Other("Time Trial".into()));
+
+        let mut selected = info.clone();
+        assert!(selected.select_session(1));
+        assert!(selected.current_session().unwrap().kind().is_qualifying());
+        assert!(!selected.select_session(7));
+        assert_eq!(selected.current_session_num, 1);
+
+        let missing = SessionInfoData { current_session_num: 5, ..info };
+        assert!(missing.current_session().is_none());
+    }
+}
@@ -0,0 +1,192 @@
+//! Car setup information
+//!
+//! The `CarSetup` section mirrors the garage screen, so its layout differs from car
+//! to car. Only groups whose keys are stable across cars (tires, chassis, dampers,
+//! aero) are typed; anything else is kept in flattened maps, keyed as it appears
+//! in the session YAML.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{Quantity, SessionInfo};
+
+/// Typed view of the `CarSetup` section
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+#[serde(rename_all = "PascalCase")]
+#[serde(default)]
+pub struct CarSetup {
+    /// Incremented each time the setup is changed in the garage
+    pub update_count: Option<i32>,
+    /// Tire pressures, temperatures and wear (`Tires`, or `TiresAero` on some cars)
+    #[serde(alias = "TiresAero")]
+    pub tires: Option<SetupTires>,
+    /// Chassis settings grouped by corner or axle
+    pub chassis: Option<SetupSection>,
+    /// Damper settings grouped by corner or axle
+    pub dampers: Option<SetupSection>,
+    /// Aero settings, on cars that list them in their own section
+    pub aero: Option<SetupSection>,
+    /// Car-specific sections (e.g. `BrakesDriveUnit`, `InCarDials`)
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "tauri", specta(skip))]
+    pub other: HashMap<String, serde_yaml_ng::Value>,
+}
+
+/// Tire group of the car setup
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+#[serde(rename_all = "PascalCase")]
+#[serde(default)]
+pub struct SetupTires {
+    /// Left front tire
+    pub left_front: Option<TireSetup>,
+    /// Left rear tire
+    pub left_rear: Option<TireSetup>,
+    /// Right front tire
+    pub right_front: Option<TireSetup>,
+    /// Right rear tire
+    pub right_rear: Option<TireSetup>,
+    /// Other groups in this section (e.g. `AeroSettings` under `TiresAero`)
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "tauri", specta(skip))]
+    pub other: HashMap<String, serde_yaml_ng::Value>,
+}
+
+/// Setup and last-run readings for one tire
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+#[serde(rename_all = "PascalCase")]
+#[serde(default)]
+pub struct TireSetup {
+    /// Cold pressure set in the garage (e.g. `"152 kPa"`)
+    pub starting_pressure: Option<String>,
+    /// Hot pressure at the end of the last run
+    pub last_hot_pressure: Option<String>,
+    /// Outer/middle/inner carcass temperatures from the last run (e.g. `"35C, 35C, 35C"`),
+    /// reported by left-side tires
+    #[serde(rename = "LastTempsOMI")]
+    pub last_temps_omi: Option<String>,
+    /// Inner/middle/outer carcass temperatures from the last run, reported by right-side tires
+    #[serde(rename = "LastTempsIMO")]
+    pub last_temps_imo: Option<String>,
+    /// Remaining tread across the tire (e.g. `"100%, 100%, 100%"`)
+    pub tread_remaining: Option<String>,
+    /// Car-specific tire settings
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(feature = "tauri", specta(skip))]
+    pub other: HashMap<String, serde_yaml_ng::Value>,
+}
+
+impl TireSetup {
+    /// Starting pressure as a [`Quantity`]
+    pub fn starting_pressure_quantity(&self) -> Option<Quantity> {
+        Quantity::parse(self.starting_pressure.as_deref()?)
+    }
+
+    /// Last hot pressure as a [`Quantity`]
+    pub fn last_hot_pressure_quantity(&self) -> Option<Quantity> {
+        Quantity::parse(self.last_hot_pressure.as_deref()?)
+    }
+}
+
+/// A setup section whose groups vary by car (e.g. `Chassis`, `Dampers`)
+///
+/// Groups are usually corners or axles (`LeftFront`, `Rear`) holding
+/// `"<value> <unit>"` strings, but some cars put settings directly in the section.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct SetupSection {
+    /// Groups and settings keyed as they appear in the session YAML
+    #[serde(flatten)]
+    #[cfg_attr(feature = "tauri", specta(skip))]
+    pub entries: HashMap<String, serde_yaml_ng::Value>,
+}
+
+impl SetupSection {
+    /// Look up a setting inside a group, e.g. `get("LeftFront", "RideHeight")`
+    pub fn get(&self, group: &str, key: &str) -> Option<&str> {
+        self.entries.get(group)?.get(key)?.as_str()
+    }
+
+    /// Look up a setting stored directly in the section
+    pub fn setting(&self, key: &str) -> Option<&str> {
+        self.entries.get(key)?.as_str()
+    }
+}
+
+impl SessionInfo {
+    /// Parse the untyped `car_setup` value into a [`CarSetup`]
+    ///
+    /// Returns `None` when the session carries no setup or it is not a mapping.
+    pub fn car_setup_typed(&self) -> Option<CarSetup> {
+        serde_yaml_ng::from_value(self.car_setup.clone()?).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SETUP_YAML: &str = r#"
+WeekendInfo:
+  TrackName: spa
+SessionInfo:
+  Sessions: []
+CarSetup:
+  UpdateCount: 3
+  TiresAero:
+    LeftFront:
+      StartingPressure: 152 kPa
+      LastHotPressure: 165 kPa
+      LastTempsOMI: 80C, 82C, 85C
+      TreadRemaining: 98%, 97%, 97%
+    RightFront:
+      StartingPressure: 150 kPa
+      LastTempsIMO: 86C, 83C, 81C
+    AeroSettings:
+      RearWingAngle: 7 deg
+  Chassis:
+    LeftFront:
+      RideHeight: 52.0 mm
+      Camber: -3.5 deg
+    BrakePressureBias: 54.0%
+  BrakesDriveUnit:
+    BrakeSpec:
+      PadCompound: Medium
+"#;
+
+    #[test]
+    fn car_setup_types_stable_groups_and_keeps_the_rest() {
+        let info = SessionInfo::parse(SETUP_YAML).unwrap();
+        let setup = info.car_setup_typed().expect("setup should parse");
+
+        assert_eq!(setup.update_count, Some(3));
+
+        let tires = setup.tires.as_ref().expect("TiresAero maps to tires");
+        let left_front = tires.left_front.as_ref().unwrap();
+        assert_eq!(left_front.starting_pressure_quantity().unwrap().value, 152.0);
+        assert_eq!(left_front.tread_remaining.as_deref(), Some("98%, 97%, 97%"));
+        assert_eq!(left_front.last_temps_omi.as_deref(), Some("80C, 82C, 85C"));
+        let right_front = tires.right_front.as_ref().unwrap();
+        assert_eq!(right_front.last_temps_imo.as_deref(), Some("86C, 83C, 81C"));
+        assert_eq!(right_front.last_temps_omi, None);
+        assert!(tires.other.contains_key("AeroSettings"));
+
+        let chassis = setup.chassis.as_ref().unwrap();
+        assert_eq!(chassis.get("LeftFront", "Camber"), Some("-3.5 deg"));
+        assert_eq!(chassis.setting("BrakePressureBias"), Some("54.0%"));
+        assert_eq!(chassis.get("RightRear", "Camber"), None);
+
+        assert!(setup.dampers.is_none());
+        assert!(setup.other.contains_key("BrakesDriveUnit"));
+    }
+
+    #[test]
+    fn car_setup_typed_is_none_without_setup() {
+        assert!(SessionInfo::default().car_setup_typed().is_none());
+    }
+}
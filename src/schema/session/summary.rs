@@ -0,0 +1,101 @@
+//! One-line session summaries for logs and CLI output
+
+use std::fmt;
+
+use super::SessionInfo;
+
+impl SessionInfo {
+    /// One-line description of the session, e.g.
+    /// `Watkins Glen — Race — 34 drivers — 20°C air / 35°C track`.
+    ///
+    /// Parts with no data are left out. The track falls back from its display
+    /// name to its internal name, and the driver count leaves out the pace car
+    /// and spectators.
+    pub fn summary(&self) -> String {
+        let weekend = &self.weekend_info;
+        let track = [weekend.track_display_name.as_str(), weekend.track_name.as_str()]
+            .into_iter()
+            .find(|name| !name.is_empty())
+            .unwrap_or("Unknown track");
+        let mut parts = vec![track.to_string()];
+
+        let session = self.session_info.current_session().and_then(|session| {
+            Some(session.session_type.as_str())
+                .filter(|kind| !kind.is_empty())
+                .or(session.session_name.as_deref())
+        });
+        parts.extend(session.map(str::to_string));
+
+        let drivers = self.driver_info.as_ref().and_then(|info| info.drivers.as_ref());
+        if let Some(drivers) = drivers {
+            let count = drivers
+                .iter()
+                .filter(|driver| {
+                    driver.car_is_pace_car != Some(1) && driver.is_spectator != Some(1)
+                })
+                .count();
+            parts.push(format!("{} driver{}", count, if count == 1 { "" } else { "s" }));
+        }
+
+        let air = weekend.air_temp_celsius().map(|temp| format!("{:.0}°C air", temp));
+        let track_temp = weekend.surface_temp_celsius().map(|temp| format!("{:.0}°C track", temp));
+        let temps: Vec<String> = air.into_iter().chain(track_temp).collect();
+        if !temps.is_empty() {
+            parts.push(temps.join(" / "));
+        }
+
+        parts.join(" — ")
+    }
+}
+
+impl fmt::Display for SessionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.summary())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::session::{Driver, DriverInfoData, Session, WeekendInfo};
+
+    #[test]
+    fn summary_joins_available_parts() {
+        let driver = |car_idx, pace_car| Driver {
+            car_idx,
+            car_is_pace_car: Some(pace_car),
+            ..Default::default()
+        };
+        let mut info = SessionInfo {
+            weekend_info: WeekendInfo {
+                track_name: "watkinsglen 2021 fullcourse".to_string(),
+                track_display_name: "Watkins Glen".to_string(),
+                track_surface_temp: Some("35.30 C".to_string()),
+                track_air_temp: Some("68.00 F".to_string()),
+                ..Default::default()
+            },
+            driver_info: Some(DriverInfoData {
+                drivers: Some(vec![driver(0, 1), driver(1, 0), driver(2, 0)]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        info.session_info.current_session_num = 2;
+        info.session_info.sessions = vec![Session {
+            session_num: 2,
+            session_type: "Race".to_string(),
+            ..Default::default()
+        }];
+
+        assert_eq!(info.summary(), "Watkins Glen — Race — 2 drivers — 20°C air / 35°C track");
+        assert_eq!(info.to_string(), info.summary());
+
+        info.weekend_info.track_display_name.clear();
+        info.weekend_info.track_air_temp = None;
+        info.session_info.current_session_num = 0;
+        info.driver_info = None;
+        assert_eq!(info.summary(), "watkinsglen 2021 fullcourse — 35°C track");
+
+        assert_eq!(SessionInfo::default().summary(), "Unknown track");
+    }
+}
@@ -41,3 +41,73 @@ pub struct Sector {
     #[cfg_attr(feature = "tauri", specta(skip))]
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
+
+impl SplitTimeInfo {
+    /// Sector boundaries as `(start_pct, end_pct)` pairs in track order.
+    ///
+    /// Each sector ends where the next begins; the last sector ends at `1.0`.
+    /// Sectors without a start percentage are ignored.
+    pub fn sector_bounds(&self) -> Vec<(f32, f32)> {
+        let mut starts: Vec<f32> = self
+            .sectors
+            .iter()
+            .flatten()
+            .filter_map(|sector| sector.sector_start_pct.map(|pct| pct as f32))
+            .collect();
+        starts.sort_by(f32::total_cmp);
+
+        let ends = starts.iter().skip(1).copied().chain(std::iter::once(1.0));
+        starts.iter().copied().zip(ends).collect()
+    }
+
+    /// Index into [`sector_bounds`](Self::sector_bounds) for a telemetry `LapDistPct`.
+    ///
+    /// Returns `None` when no sectors are defined or `pct` is outside `0.0..=1.0`.
+    /// A `pct` before the first sector start wraps to the last sector.
+    pub fn sector_for_pct(&self, pct: f32) -> Option<usize> {
+        if !(0.0..=1.0).contains(&pct) {
+            return None;
+        }
+
+        let bounds = self.sector_bounds();
+        let after = bounds.iter().take_while(|(start, _)| *start <= pct).count();
+        match after {
+            0 => bounds.len().checked_sub(1),
+            n => Some(n - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split_times(yaml: &str) -> SplitTimeInfo {
+        serde_yaml_ng::from_str(yaml).expect("valid SplitTimeInfo YAML")
+    }
+
+    #[test]
+    fn sector_bounds_and_lookup() {
+        let info = split_times(
+            "Sectors:\n\
+             - SectorNum: 0\n  SectorStartPct: 0.000000\n\
+             - SectorNum: 2\n  SectorStartPct: 0.700000\n\
+             - SectorNum: 1\n  SectorStartPct: 0.350000\n",
+        );
+
+        assert_eq!(info.sector_bounds(), vec![(0.0, 0.35), (0.35, 0.7), (0.7, 1.0)]);
+        assert_eq!(info.sector_for_pct(0.0), Some(0));
+        assert_eq!(info.sector_for_pct(0.349), Some(0));
+        assert_eq!(info.sector_for_pct(0.35), Some(1));
+        assert_eq!(info.sector_for_pct(0.99), Some(2));
+        assert_eq!(info.sector_for_pct(1.0), Some(2));
+        assert_eq!(info.sector_for_pct(-0.1), None, "LapDistPct is -1 when off the world");
+    }
+
+    #[test]
+    fn sector_lookup_without_sectors() {
+        let info = SplitTimeInfo::default();
+        assert!(info.sector_bounds().is_empty());
+        assert_eq!(info.sector_for_pct(0.5), None);
+    }
+}
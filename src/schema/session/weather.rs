@@ -0,0 +1,223 @@
+//! Typed weather and track state snapshot
+//!
+//! Weather values are spread across several `WeekendInfo` string fields, and the
+//! rubber state lives on each `Session`. [`Weather`] collects them into parsed
+//! numbers using [`Quantity`](super::Quantity).
+
+use serde::{Deserialize, Serialize};
+use std::f64::consts::TAU;
+use std::fmt;
+
+use super::{SessionInfo, WeekendInfo};
+
+/// Parsed weather and track conditions
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct Weather {
+    /// Air temperature in degrees Celsius
+    pub air_temp_c: Option<f64>,
+    /// Track surface temperature in degrees Celsius
+    pub track_temp_c: Option<f64>,
+    /// Relative humidity percentage
+    pub humidity_pct: Option<f64>,
+    /// Wind speed in meters per second
+    pub wind_speed_ms: Option<f64>,
+    /// Wind direction in radians
+    pub wind_dir_rad: Option<f64>,
+    /// Rubber state of the current session (only set by [`SessionInfo::weather`])
+    pub rubber_state: Option<RubberState>,
+}
+
+/// Track rubber state as reported in `SessionTrackRubberState`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum RubberState {
+    /// Rubber carried over from the previous session
+    CarryOver,
+    /// Freshly cleaned track
+    Clean,
+    LowUsage,
+    ModeratelyLowUsage,
+    ModerateUsage,
+    ModeratelyHighUsage,
+    HighUsage,
+    /// Unrecognized value, kept verbatim
+    Other(String),
+}
+
+impl RubberState {
+    /// Parse iRacing's rubber state string (e.g. `"moderately low usage"`)
+    pub fn parse(value: &str) -> RubberState {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "carry over" => RubberState::CarryOver,
+            "clean" => RubberState::Clean,
+            "low usage" => RubberState::LowUsage,
+            "moderately low usage" => RubberState::ModeratelyLowUsage,
+            "moderate usage" => RubberState::ModerateUsage,
+            "moderately high usage" => RubberState::ModeratelyHighUsage,
+            "high usage" => RubberState::HighUsage,
+            _ => RubberState::Other(value.to_string()),
+        }
+    }
+}
+
+/// Eight-point compass direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum CompassPoint {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl CompassPoint {
+    const ALL: [CompassPoint; 8] = [
+        CompassPoint::N,
+        CompassPoint::NE,
+        CompassPoint::E,
+        CompassPoint::SE,
+        CompassPoint::S,
+        CompassPoint::SW,
+        CompassPoint::W,
+        CompassPoint::NW,
+    ];
+
+    /// Nearest compass point to a bearing in radians, clockwise from north.
+    ///
+    /// Bearings outside `0..2π` are wrapped. Returns `None` for non-finite input.
+    pub fn from_radians(radians: f64) -> Option<CompassPoint> {
+        if !radians.is_finite() {
+            return None;
+        }
+        let sector = (radians.rem_euclid(TAU) / (TAU / 8.0)).round() as usize % 8;
+        Some(Self::ALL[sector])
+    }
+}
+
+impl fmt::Display for CompassPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl WeekendInfo {
+    /// Compass point the wind blows from, from `TrackWindDir`
+    pub fn wind_direction_compass(&self) -> Option<CompassPoint> {
+        self.wind_direction_radians().and_then(CompassPoint::from_radians)
+    }
+
+    /// Wind velocity as `(east, north)` components in meters per second.
+    ///
+    /// `TrackWindDir` is the bearing the wind blows from, so the components point
+    /// the opposite way: a north wind gives a negative north component.
+    pub fn wind_vector_ms(&self) -> Option<(f64, f64)> {
+        let speed = self.wind_speed_mps()?;
+        let direction = self.wind_direction_radians()?;
+        Some((-speed * direction.sin(), -speed * direction.cos()))
+    }
+
+    /// Typed snapshot of the weather fields.
+    ///
+    /// `rubber_state` is always `None` here because it is reported per session;
+    /// use [`SessionInfo::weather`] to include it.
+    pub fn weather(&self) -> Weather {
+        Weather {
+            air_temp_c: self.air_temp_celsius(),
+            track_temp_c: self.surface_temp_celsius(),
+            humidity_pct: self.relative_humidity_percent(),
+            wind_speed_ms: self.wind_speed_mps(),
+            wind_dir_rad: self.wind_direction_radians(),
+            rubber_state: None,
+        }
+    }
+}
+
+impl SessionInfo {
+    /// Typed weather snapshot including the current session's rubber state
+    pub fn weather(&self) -> Weather {
+        let rubber_state = self
+            .session_info
+            .sessions
+            .iter()
+            .find(|session| session.session_num == self.session_info.current_session_num)
+            .and_then(|session| session.session_track_rubber_state.as_deref())
+            .map(RubberState::parse);
+
+        Weather { rubber_state, ..self.weekend_info.weather() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::session::Session;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn weather_parses_weekend_and_session_fields() {
+        let session = |session_num, rubber: &str| Session {
+            session_num,
+            session_track_rubber_state: Some(rubber.to_string()),
+            ..Default::default()
+        };
+
+        let mut info = SessionInfo {
+            weekend_info: WeekendInfo {
+                track_surface_temp: Some("35.69 C".to_string()),
+                track_air_temp: Some("68.00 F".to_string()),
+                track_wind_vel: Some("4.33 m/s".to_string()),
+                track_wind_dir: Some("4.19 rad".to_string()),
+                track_relative_humidity: Some("31 %".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        info.session_info.current_session_num = 1;
+        info.session_info.sessions = vec![session(0, "clean"), session(1, "moderately low usage")];
+
+        let weather = info.weather();
+        assert!((weather.air_temp_c.unwrap() - 20.0).abs() < 1e-9);
+        assert_eq!(weather.track_temp_c, Some(35.69));
+        assert_eq!(weather.humidity_pct, Some(31.0));
+        assert_eq!(weather.wind_speed_ms, Some(4.33));
+        assert_eq!(weather.wind_dir_rad, Some(4.19));
+        assert_eq!(weather.rubber_state, Some(RubberState::ModeratelyLowUsage));
+
+        assert_eq!(info.weekend_info.weather().rubber_state, None);
+    }
+
+    #[test]
+    fn wind_direction_maps_to_compass_and_vector() {
+        let weekend = |dir: &str| WeekendInfo {
+            track_wind_vel: Some("2.00 m/s".to_string()),
+            track_wind_dir: Some(dir.to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(weekend("0.00 rad").wind_direction_compass(), Some(CompassPoint::N));
+        assert_eq!(weekend("0.79 rad").wind_direction_compass(), Some(CompassPoint::NE));
+        assert_eq!(weekend("4.19 rad").wind_direction_compass(), Some(CompassPoint::SW));
+        assert_eq!(weekend("6.20 rad").wind_direction_compass(), Some(CompassPoint::N));
+        assert_eq!(CompassPoint::from_radians(-FRAC_PI_2), Some(CompassPoint::W));
+        assert_eq!(CompassPoint::from_radians(f64::NAN), None);
+        assert_eq!(CompassPoint::SE.to_string(), "SE");
+
+        // Wind from the east moves air westwards
+        let (east, north) = weekend(&format!("{FRAC_PI_2} rad")).wind_vector_ms().unwrap();
+        assert!((east + 2.0).abs() < 1e-9 && north.abs() < 1e-9);
+
+        assert_eq!(WeekendInfo::default().wind_vector_ms(), None);
+        assert_eq!(WeekendInfo::default().wind_direction_compass(), None);
+    }
+
+    #[test]
+    fn rubber_state_keeps_unknown_values() {
+        assert_eq!(RubberState::parse("Carry Over"), RubberState::CarryOver);
+        assert_eq!(RubberState::parse("marbles"), RubberState::Other("marbles".to_string()));
+    }
+}
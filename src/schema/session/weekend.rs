@@ -232,3 +232,82 @@ pub struct WeekendOptions {
     #[cfg_attr(feature = "tauri", specta(skip))]
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
 }
+
+/// Race start procedure, from `StandingStart`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum StartType {
+    /// Field takes the green flag at speed behind the pace car
+    Rolling,
+    /// Field starts from a standstill on the grid
+    Standing,
+}
+
+/// Restart procedure after a caution, as reported in `Restarts`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum RestartType {
+    /// Field restarts in a single line
+    SingleFile,
+    /// Double-file restart with lapped cars sent to the back
+    DoubleFileBack,
+    /// Double-file restart with lapped cars kept at the front
+    DoubleFileFront,
+    /// Unrecognized value, kept verbatim
+    Other(String),
+}
+
+impl RestartType {
+    /// Parse iRacing's restart string (e.g. `"double file back"`)
+    pub fn parse(value: &str) -> RestartType {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "single file" => RestartType::SingleFile,
+            "double file back" => RestartType::DoubleFileBack,
+            "double file front" => RestartType::DoubleFileFront,
+            _ => RestartType::Other(value.to_string()),
+        }
+    }
+}
+
+impl WeekendOptions {
+    /// Rolling or standing start
+    pub fn start_type(&self) -> Option<StartType> {
+        self.standing_start.map(|standing| match standing {
+            0 => StartType::Rolling,
+            _ => StartType::Standing,
+        })
+    }
+
+    /// Restart procedure after cautions
+    pub fn restart_type(&self) -> Option<RestartType> {
+        self.restarts.as_deref().map(RestartType::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekend_options_expose_start_and_restart_types() {
+        let options: WeekendOptions = serde_yaml_ng::from_str(
+            "NumStarters: 24\nQualifyScoring: best lap\nStandingStart: 1\nRestarts: double file back\n",
+        )
+        .unwrap();
+
+        assert_eq!(options.num_starters, Some(24));
+        assert_eq!(options.start_type(), Some(StartType::Standing));
+        assert_eq!(options.restart_type(), Some(RestartType::DoubleFileBack));
+
+        let options: WeekendOptions =
+            serde_yaml_ng::from_str("StandingStart: 0\nRestarts: Single File\n").unwrap();
+        assert_eq!(options.start_type(), Some(StartType::Rolling));
+        assert_eq!(options.restart_type(), Some(RestartType::SingleFile));
+
+        assert_eq!(WeekendOptions::default().start_type(), None);
+        assert_eq!(
+            RestartType::parse("triple file"),
+            RestartType::Other("triple file".to_string())
+        );
+    }
+}
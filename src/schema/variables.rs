@@ -110,11 +110,14 @@ impl IRSDKVarHeader {
             return Err(TelemetryError::Memory { offset, source: None });
         }
 
-        // Zero-copy parsing: directly read from memory
         // Safety: We've validated the memory length above and use read_unaligned for robustness
-        let header = unsafe {
+        let mut header = unsafe {
             std::ptr::read_unaligned(memory.as_ptr().add(offset) as *const IRSDKVarHeader)
         };
+        // iRacing writes little-endian; no-op on little-endian targets
+        header.var_type = i32::from_le(header.var_type);
+        header.offset = i32::from_le(header.offset);
+        header.count = i32::from_le(header.count);
 
         // Validate basic header fields
         header.validate()?;
@@ -169,9 +172,18 @@ impl IRSDKVarHeader {
 
     /// Convert to VariableInfo for schema building
     pub fn to_variable_info(&self) -> VariableInfo {
+        self.to_variable_info_with(&|_| None)
+    }
+
+    /// Convert to VariableInfo, consulting `type_map` before the built-in type mapping
+    pub fn to_variable_info_with(
+        &self,
+        type_map: &dyn Fn(i32) -> Option<VariableType>,
+    ) -> VariableInfo {
         VariableInfo {
             name: Self::c_string_to_string(&self.name),
-            data_type: Self::map_variable_type(self.var_type),
+            data_type: type_map(self.var_type)
+                .unwrap_or_else(|| Self::map_variable_type(self.var_type)),
             offset: self.offset as usize,
             count: self.count as usize,
             count_as_time: self.count_as_time(),
@@ -192,6 +204,21 @@ pub fn parse_variable_schema(
     num_vars: i32,
     var_header_offset: i32,
     buffer_length: i32,
+) -> Result<VariableSchema> {
+    parse_variable_schema_with(memory, num_vars, var_header_offset, buffer_length, &|_| None)
+}
+
+/// Parse variable schema from shared memory with a custom type mapping.
+///
+/// `type_map` is consulted first for every `var_type` code; returning `None`
+/// falls back to the built-in mapping (unknown codes become `Int32`). Use it
+/// for modded telemetry with type codes beyond the SDK's 0-5.
+pub fn parse_variable_schema_with(
+    memory: &[u8],
+    num_vars: i32,
+    var_header_offset: i32,
+    buffer_length: i32,
+    type_map: &dyn Fn(i32) -> Option<VariableType>,
 ) -> Result<VariableSchema> {
     debug!(num_vars, var_header_offset, buffer_length, "Parsing variable schema from memory");
 
@@ -229,7 +256,7 @@ pub fn parse_variable_schema(
 
         match IRSDKVarHeader::parse_from_memory(memory, header_offset) {
             Ok(var_header) => {
-                let var_info = var_header.to_variable_info();
+                let var_info = var_header.to_variable_info_with(type_map);
 
                 // Skip variables with empty names or invalid properties (common with padding/unused slots)
                 if var_info.name.is_empty() || var_info.count == 0 {
@@ -263,6 +290,7 @@ pub fn parse_variable_schema(
 
     // Build schema with validation
     let schema = VariableSchema::new(variables, buffer_length as usize)?;
+    schema.validate_no_overlap()?;
 
     Ok(schema)
 }
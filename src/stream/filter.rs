@@ -0,0 +1,201 @@
+//! Frame filtering and downsampling utilities
+
+use futures::{Stream, StreamExt, future};
+use pin_project_lite::pin_project;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Extension trait to add frame filtering to any Stream
+pub trait FilterExt: Stream {
+    /// Yield the first item and then every `n`th item after it
+    ///
+    /// An `n` of 0 is treated as 1 (every item).
+    fn every(self, n: usize) -> impl Stream<Item = Self::Item>
+    where
+        Self: Sized,
+    {
+        let n = n.max(1);
+        let mut skip = 0usize;
+        self.filter(move |_| {
+            let keep = skip == 0;
+            skip = if keep { n - 1 } else { skip - 1 };
+            future::ready(keep)
+        })
+    }
+
+    /// Yield only items whose key differs from the previously yielded item's key
+    ///
+    /// The first item is always yielded.
+    fn on_change<K>(self, key: fn(&Self::Item) -> K) -> impl Stream<Item = Self::Item>
+    where
+        Self: Sized,
+        K: PartialEq,
+    {
+        let mut last: Option<K> = None;
+        self.filter(move |item| {
+            let current = key(item);
+            let changed = last.as_ref() != Some(&current);
+            if changed {
+                last = Some(current);
+            }
+            future::ready(changed)
+        })
+    }
+
+    /// Yield sliding windows of the last `n` items, oldest first
+    ///
+    /// Nothing is yielded until `n` items have arrived. An `n` of 0 is treated as 1.
+    fn windows(self, n: usize) -> impl Stream<Item = Vec<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        let n = n.max(1);
+        let mut window = VecDeque::with_capacity(n);
+        self.filter_map(move |item| {
+            if window.len() == n {
+                window.pop_front();
+            }
+            window.push_back(item);
+            future::ready((window.len() == n).then(|| window.iter().cloned().collect()))
+        })
+    }
+
+    /// Yield at most `max` items, then end
+    ///
+    /// Unlike `take`, the returned stream records why it ended; see
+    /// [`Limited::end`].
+    fn limit(self, max: usize) -> Limited<Self>
+    where
+        Self: Sized,
+    {
+        Limited::new(self, max)
+    }
+}
+
+impl<T: Stream> FilterExt for T {}
+
+/// Why a [`Limited`] stream ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamEnd {
+    /// The item limit was reached
+    LimitReached,
+    /// The source ended first (end of file, or the connection closed)
+    SourceEnded,
+}
+
+pin_project! {
+    /// A stream combinator that yields a bounded number of items
+    ///
+    /// The inner stream is dropped as soon as the limit is reached, releasing
+    /// whatever it holds rather than waiting for the combinator to be dropped.
+    pub struct Limited<S> {
+        #[pin]
+        stream: Option<S>,
+        remaining: usize,
+        end: Option<StreamEnd>,
+    }
+}
+
+impl<S: Stream> Limited<S> {
+    /// Create a stream yielding at most `max` items of `stream`
+    pub fn new(stream: S, max: usize) -> Self {
+        if max == 0 {
+            return Self { stream: None, remaining: 0, end: Some(StreamEnd::LimitReached) };
+        }
+        Self { stream: Some(stream), remaining: max, end: None }
+    }
+
+    /// Why the stream ended, or `None` while it is still running
+    pub fn end(&self) -> Option<StreamEnd> {
+        self.end
+    }
+}
+
+impl<S: Stream> Stream for Limited<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let Some(stream) = this.stream.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+
+        match stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                *this.remaining -= 1;
+                if *this.remaining == 0 {
+                    this.stream.set(None);
+                    *this.end = Some(StreamEnd::LimitReached);
+                }
+                Poll::Ready(Some(item))
+            }
+            Poll::Ready(None) => {
+                this.stream.set(None);
+                *this.end = Some(StreamEnd::SourceEnded);
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.stream {
+            Some(stream) => {
+                let (lower, upper) = stream.size_hint();
+                let upper = upper.map_or(self.remaining, |upper| upper.min(self.remaining));
+                (lower.min(self.remaining), Some(upper))
+            }
+            None => (0, Some(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    #[tokio::test]
+    async fn every_yields_first_then_each_nth_item() {
+        let items: Vec<u32> = stream::iter(0..10).every(3).collect().await;
+        assert_eq!(items, vec![0, 3, 6, 9]);
+
+        let items: Vec<u32> = stream::iter(0..3).every(0).collect().await;
+        assert_eq!(items, vec![0, 1, 2], "n = 0 behaves like n = 1");
+    }
+
+    #[tokio::test]
+    async fn on_change_skips_repeated_keys() {
+        let items: Vec<(u32, i32)> = stream::iter([(0, 1), (1, 1), (2, 2), (3, 2), (4, 1)])
+            .on_change(|(_, gear)| *gear)
+            .collect()
+            .await;
+        assert_eq!(items, vec![(0, 1), (2, 2), (4, 1)]);
+    }
+
+    #[tokio::test]
+    async fn windows_slide_one_item_at_a_time() {
+        let windows: Vec<Vec<u32>> = stream::iter(0..5).windows(3).collect().await;
+        assert_eq!(windows, vec![vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4]]);
+    }
+
+    #[tokio::test]
+    async fn limit_reports_why_it_ended() {
+        let mut limited = stream::iter(0..10).limit(3);
+        assert_eq!(limited.end(), None);
+        let items: Vec<u32> = limited.by_ref().collect().await;
+        assert_eq!(items, vec![0, 1, 2]);
+        assert_eq!(limited.end(), Some(StreamEnd::LimitReached));
+
+        let mut limited = stream::iter(0..2).limit(3);
+        let items: Vec<u32> = limited.by_ref().collect().await;
+        assert_eq!(items, vec![0, 1]);
+        assert_eq!(limited.end(), Some(StreamEnd::SourceEnded));
+
+        let limited = stream::iter(0..2).limit(0);
+        assert_eq!(limited.end(), Some(StreamEnd::LimitReached));
+        assert_eq!(limited.count().await, 0);
+    }
+}
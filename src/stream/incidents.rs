@@ -0,0 +1,161 @@
+//! Incident detection for telemetry frame streams
+
+use futures::{Stream, StreamExt, future};
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::types::{FramePacket, IncidentPenalty, IncidentReport, decode_incident};
+
+/// An incident picked up by the player's car
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IncidentEvent {
+    /// Car index of the player (from `PlayerCarIdx`), or `-1` when the source lacks it
+    pub car_idx: i32,
+    /// Tick of the frame the incident was reported on
+    pub tick: u32,
+    /// `SessionTime` of that frame, for seeking a replay to the incident
+    pub session_time: Option<f64>,
+    /// What happened
+    pub report: IncidentReport,
+    /// Incident points charged for it
+    pub penalty: IncidentPenalty,
+    /// `PlayerCarMyIncidentCount` on the same frame
+    ///
+    /// iRacing may update the count a few frames after the report, so this can
+    /// still exclude the incident itself. `None` when the source has no count.
+    pub total_after: Option<i32>,
+}
+
+/// Turn a stream of frames into one [`IncidentEvent`] per new incident
+///
+/// Watches `PlayerIncidents` and emits when it changes to a new report (a rising
+/// edge). A report already showing on the first frame is not emitted. The
+/// "ongoing" reports iRacing shows while an off-track or wall contact continues
+/// are not new incidents and emit nothing. Frames should not be throttled, since
+/// a report can be shown for a single tick.
+pub fn incident_updates<S>(frames: S) -> impl Stream<Item = IncidentEvent>
+where
+    S: Stream<Item = Arc<FramePacket>>,
+{
+    let mut tracker = IncidentTracker::default();
+    frames.filter_map(move |packet| future::ready(tracker.update(&packet)))
+}
+
+#[derive(Default)]
+struct IncidentTracker {
+    /// Raw `PlayerIncidents` of the previous frame
+    previous: Option<u32>,
+}
+
+impl IncidentTracker {
+    fn update(&mut self, packet: &FramePacket) -> Option<IncidentEvent> {
+        let bits = packet.get_bitfield("PlayerIncidents").ok()?;
+        // The first frame only sets the baseline; its report may be long past
+        let previous = self.previous.replace(bits.value())?;
+        if previous == bits.value() {
+            return None;
+        }
+
+        let incident = decode_incident(bits);
+        let new_incident = !matches!(
+            incident.report,
+            IncidentReport::NoReport
+                | IncidentReport::OffTrackOngoing
+                | IncidentReport::CollisionWithWorldOngoing
+        );
+        if !new_incident {
+            return None;
+        }
+
+        Some(IncidentEvent {
+            car_idx: packet.get_i32("PlayerCarIdx").unwrap_or(-1),
+            tick: packet.tick,
+            session_time: packet.get_f64("SessionTime").ok(),
+            report: incident.report,
+            penalty: incident.penalty,
+            total_after: packet.get_i32("PlayerCarMyIncidentCount").ok(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VariableType;
+    use crate::test_utils::{PacketBuilder, test_schema};
+    use crate::types::irsdk_flags::incident as inc;
+    use futures::stream;
+
+    fn frames(rows: &[(u32, u32, i32)]) -> Vec<Arc<FramePacket>> {
+        let schema = Arc::new(test_schema(
+            &[
+                ("PlayerIncidents", VariableType::BitField, 0, 1),
+                ("PlayerCarIdx", VariableType::Int32, 4, 1),
+                ("PlayerCarMyIncidentCount", VariableType::Int32, 8, 1),
+            ],
+            12,
+        ));
+
+        rows.iter()
+            .map(|&(tick, incidents, count)| {
+                let packet = PacketBuilder::new(&schema)
+                    .set("PlayerIncidents", incidents.to_le_bytes())
+                    .set("PlayerCarIdx", 5i32.to_le_bytes())
+                    .set("PlayerCarMyIncidentCount", count.to_le_bytes())
+                    .build(tick);
+                Arc::new(packet)
+            })
+            .collect()
+    }
+
+    fn flags(report: u8, penalty: u8) -> u32 {
+        u32::from(report) | (u32::from(penalty) << 8)
+    }
+
+    #[tokio::test]
+    async fn emits_on_each_new_report() {
+        let off_track = flags(inc::REP_OFF_TRACK, inc::PEN_1X);
+        let rows = [
+            (0, 0, 0),
+            (1, off_track, 0),
+            // Held report and its ongoing continuation are the same incident
+            (2, off_track, 1),
+            (3, flags(inc::REP_OFF_TRACK_ONGOING, inc::PEN_0X), 1),
+            (4, 0, 1),
+            // Car contact straight after a clean frame
+            (10, flags(inc::REP_CONTACT_WITH_CAR, inc::PEN_0X), 1),
+            (11, flags(inc::REP_COLLISION_WITH_CAR, inc::PEN_4X), 5),
+        ];
+
+        let events: Vec<IncidentEvent> =
+            incident_updates(stream::iter(frames(&rows))).collect().await;
+
+        let summary: Vec<_> = events
+            .iter()
+            .map(|event| {
+                (event.tick, event.report.clone(), event.penalty.clone(), event.total_after)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, IncidentReport::OffTrack, IncidentPenalty::OneX, Some(0)),
+                (10, IncidentReport::ContactWithCar, IncidentPenalty::ZeroX, Some(1)),
+                (11, IncidentReport::CollisionWithCar, IncidentPenalty::FourX, Some(5)),
+            ]
+        );
+        assert!(events.iter().all(|event| event.car_idx == 5 && event.session_time.is_none()));
+    }
+
+    #[tokio::test]
+    async fn report_on_the_first_frame_is_only_a_baseline() {
+        let contact = flags(inc::REP_CONTACT_WITH_WORLD, inc::PEN_0X);
+        let rows = [(0, contact, 2), (1, contact, 2), (2, 0, 2), (3, contact, 2)];
+
+        let events: Vec<IncidentEvent> =
+            incident_updates(stream::iter(frames(&rows))).collect().await;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].tick, 3);
+    }
+}
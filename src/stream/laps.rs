@@ -0,0 +1,182 @@
+//! Lap completion detection for telemetry frame streams
+
+use futures::{Stream, StreamExt, future};
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::types::FramePacket;
+
+/// Ticks to wait for `LapLastLapTime` to update after a lap ticks over.
+///
+/// iRacing increments `LapCompleted` a few frames before it publishes the new lap
+/// time; if the time has not changed after this many ticks (two seconds at 60Hz),
+/// the summary is emitted with whatever value is current.
+const LAP_TIME_SETTLE_TICKS: u32 = 120;
+
+/// A completed lap
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct LapSummary {
+    /// Lap number that was completed (from `LapCompleted`)
+    pub lap: i32,
+    /// Lap time in seconds (from `LapLastLapTime`); iRacing reports `-1` for laps without a time
+    pub lap_time: f32,
+    /// `FuelLevel` consumed over the lap, in liters
+    ///
+    /// `None` for the first lap seen (it may have started before the stream did), when
+    /// the car was refueled during the lap, or when the source has no `FuelLevel`.
+    pub fuel_used: Option<f32>,
+}
+
+/// Turn a stream of frames into one [`LapSummary`] per completed lap
+///
+/// Laps are detected from `LapCompleted`, falling back to `Lap - 1` for sources
+/// without it. Only single-lap increments emit; resets and jumps (session changes,
+/// seeking a replay) re-baseline silently. Frames should not be throttled so that
+/// the lap time has a chance to settle before the summary is emitted.
+pub fn lap_completions<S>(frames: S) -> impl Stream<Item = LapSummary>
+where
+    S: Stream<Item = Arc<FramePacket>>,
+{
+    let mut tracker = LapTracker::default();
+    frames.filter_map(move |packet| future::ready(tracker.update(&packet)))
+}
+
+/// A lap that ticked over but whose time has not been published yet
+struct PendingLap {
+    lap: i32,
+    fuel_used: Option<f32>,
+    previous_lap_time: Option<f32>,
+    deadline: u32,
+}
+
+#[derive(Default)]
+struct LapTracker {
+    completed: Option<i32>,
+    lap_start_fuel: Option<f32>,
+    lap_time: Option<f32>,
+    pending: Option<PendingLap>,
+}
+
+impl LapTracker {
+    fn update(&mut self, packet: &FramePacket) -> Option<LapSummary> {
+        let completed = packet
+            .get_i32("LapCompleted")
+            .or_else(|_| packet.get_i32("Lap").map(|lap| lap - 1))
+            .ok()?;
+        let fuel = packet.get_f32("FuelLevel").ok();
+        let lap_time = packet.get_f32("LapLastLapTime").ok();
+
+        let mut summary = None;
+        match self.completed {
+            Some(previous) if completed == previous + 1 => {
+                // A lap that never settled is flushed before tracking the new one
+                summary = self.pending.take().map(|pending| pending.finish(self.lap_time));
+                let fuel_used = match (self.lap_start_fuel, fuel) {
+                    (Some(start), Some(end)) if end <= start => Some(start - end),
+                    _ => None,
+                };
+                self.pending = Some(PendingLap {
+                    lap: completed,
+                    fuel_used,
+                    previous_lap_time: self.lap_time,
+                    deadline: packet.tick.wrapping_add(LAP_TIME_SETTLE_TICKS),
+                });
+                self.lap_start_fuel = fuel;
+            }
+            Some(previous) if completed == previous => {}
+            // First frame, a reset or a jump: start over without emitting
+            _ => {
+                self.pending = None;
+                self.lap_start_fuel = None;
+            }
+        }
+        self.completed = Some(completed);
+        self.lap_time = lap_time;
+
+        if summary.is_some() {
+            return summary;
+        }
+
+        let settled = self.pending.as_ref().is_some_and(|pending| {
+            lap_time != pending.previous_lap_time || packet.tick >= pending.deadline
+        });
+        if settled { self.pending.take().map(|pending| pending.finish(lap_time)) } else { None }
+    }
+}
+
+impl PendingLap {
+    fn finish(self, lap_time: Option<f32>) -> LapSummary {
+        LapSummary { lap: self.lap, lap_time: lap_time.unwrap_or(-1.0), fuel_used: self.fuel_used }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VariableType;
+    use crate::test_utils::{PacketBuilder, test_schema};
+    use futures::stream;
+
+    fn frames(rows: &[(u32, i32, f32, f32)]) -> Vec<Arc<FramePacket>> {
+        let schema = Arc::new(test_schema(
+            &[
+                ("LapCompleted", VariableType::Int32, 0, 1),
+                ("LapLastLapTime", VariableType::Float32, 4, 1),
+                ("FuelLevel", VariableType::Float32, 8, 1),
+            ],
+            12,
+        ));
+
+        rows.iter()
+            .map(|&(tick, completed, lap_time, fuel)| {
+                let packet = PacketBuilder::new(&schema)
+                    .set("LapCompleted", completed.to_le_bytes())
+                    .set("LapLastLapTime", lap_time.to_le_bytes())
+                    .set("FuelLevel", fuel.to_le_bytes())
+                    .build(tick);
+                Arc::new(packet)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn emits_once_per_lap_after_the_time_settles() {
+        let rows = [
+            (0, 3, 91.0, 40.0),
+            // Lap 4 completes; the time updates two frames later
+            (10, 4, 91.0, 37.5),
+            (11, 4, 91.0, 37.5),
+            (12, 4, 90.5, 37.5),
+            (13, 4, 90.5, 37.4),
+            // Lap 5 completes after a refuel; the time never changes, so the deadline fires
+            (20, 5, 90.5, 60.0),
+            (20 + LAP_TIME_SETTLE_TICKS, 5, 90.5, 59.9),
+            (20 + LAP_TIME_SETTLE_TICKS + 1, 5, 90.5, 59.9),
+            // Session reset re-baselines without emitting
+            (300, 0, -1.0, 59.0),
+        ];
+
+        let laps: Vec<LapSummary> = lap_completions(stream::iter(frames(&rows))).collect().await;
+
+        assert_eq!(
+            laps,
+            vec![
+                LapSummary { lap: 4, lap_time: 90.5, fuel_used: None },
+                LapSummary { lap: 5, lap_time: 90.5, fuel_used: None },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn measures_fuel_from_the_start_of_the_lap() {
+        let rows =
+            [(0, 1, 0.0, 50.0), (10, 2, 92.0, 48.0), (20, 2, 92.0, 47.0), (30, 3, 91.5, 45.5)];
+
+        let laps: Vec<LapSummary> = lap_completions(stream::iter(frames(&rows))).collect().await;
+
+        assert_eq!(laps.len(), 2);
+        assert_eq!(laps[0].fuel_used, None, "First lap started before the stream");
+        assert_eq!(laps[1], LapSummary { lap: 3, lap_time: 91.5, fuel_used: Some(2.5) });
+    }
+}
@@ -1,5 +1,21 @@
 //! Stream utilities for telemetry processing
 
+pub mod filter;
+pub mod incidents;
+pub mod laps;
+pub mod progress;
+pub mod race_state;
+pub mod revalidate;
+pub mod session;
 pub mod throttle;
 
-pub use throttle::ThrottleExt;
+pub use filter::{FilterExt, Limited, StreamEnd};
+pub use incidents::{IncidentEvent, incident_updates};
+pub use laps::{LapSummary, lap_completions};
+pub use progress::{ReplayProgress, replay_progress};
+pub use race_state::{RaceState, race_state_updates};
+#[cfg(windows)]
+pub(crate) use revalidate::Revalidator;
+pub use revalidate::adapt_revalidating;
+pub use session::session_changes;
+pub use throttle::{ThrottleExt, apply_rate};
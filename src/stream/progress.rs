@@ -0,0 +1,76 @@
+//! Playback progress for replay frame streams
+
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::types::FramePacket;
+
+/// Position of a replay within its file
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct ReplayProgress {
+    /// Index of the frame most recently delivered
+    pub current_frame: usize,
+    /// Number of frames in the file
+    pub total_frames: usize,
+    /// Share of the file played, from just above `0.0` to `1.0` on the last frame
+    pub fraction: f64,
+    /// `SessionTime` of the current frame, if the file records it
+    pub session_time: Option<f64>,
+}
+
+/// Turn a stream of replayed frames into [`ReplayProgress`] updates
+///
+/// Replay packets carry their frame index as the tick, so progress follows
+/// seeks. One update is emitted per frame; throttle the result for UI use.
+pub fn replay_progress<S>(frames: S, total_frames: usize) -> impl Stream<Item = ReplayProgress>
+where
+    S: Stream<Item = Arc<FramePacket>>,
+{
+    frames.map(move |packet| {
+        let current_frame = packet.tick as usize;
+        let fraction = if total_frames == 0 {
+            1.0
+        } else {
+            ((current_frame + 1) as f64 / total_frames as f64).min(1.0)
+        };
+
+        ReplayProgress {
+            current_frame,
+            total_frames,
+            fraction,
+            session_time: packet.get_f64("SessionTime").ok(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VariableType;
+    use crate::test_utils::{PacketBuilder, test_schema};
+    use futures::stream;
+
+    #[tokio::test]
+    async fn reports_position_and_session_time() {
+        let schema = Arc::new(test_schema(&[("SessionTime", VariableType::Float64, 0, 1)], 8));
+        let frames = [0u32, 1, 3].map(|tick| {
+            let session_time = f64::from(tick) / 60.0;
+            Arc::new(
+                PacketBuilder::new(&schema)
+                    .set("SessionTime", session_time.to_le_bytes())
+                    .build(tick),
+            )
+        });
+
+        let progress: Vec<ReplayProgress> =
+            replay_progress(stream::iter(frames), 4).collect().await;
+
+        let positions: Vec<_> =
+            progress.iter().map(|update| (update.current_frame, update.fraction)).collect();
+        assert_eq!(positions, vec![(0, 0.25), (1, 0.5), (3, 1.0)]);
+        assert_eq!(progress[1].session_time, Some(1.0 / 60.0));
+        assert!(progress.iter().all(|update| update.total_frames == 4));
+    }
+}
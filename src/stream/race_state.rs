@@ -0,0 +1,186 @@
+//! Race phase tracking from `SessionFlags`
+
+use futures::{Stream, StreamExt, future};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::types::{FramePacket, SessionFlags, decode_session_flags};
+
+/// Ticks a new phase must hold before it is emitted.
+///
+/// iRacing briefly sets and clears flag bits around phase changes (half a second
+/// at 60Hz absorbs the flicker without noticeably delaying real transitions).
+const RACE_STATE_DEBOUNCE_TICKS: u32 = 30;
+
+/// Session phase derived from successive `SessionFlags` frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum RaceState {
+    /// Racing under green, or no caution or checkered flag shown
+    Green,
+    /// Full-course caution is out
+    FullCourseYellow,
+    /// Caution flag is being waved as the field is neutralised
+    CautionWaving,
+    /// Field is on the final lap under caution before the restart
+    OneToGreen,
+    /// Checkered flag is shown
+    Checkered,
+    /// Checkered flag has been taken and cleared
+    Cooldown,
+}
+
+impl RaceState {
+    /// Phase shown by `flags`, given the phase before them.
+    ///
+    /// The checkered flag wins over any caution. Once the checkered has been
+    /// shown, the session stays in `Cooldown` until a green flag starts a new run.
+    fn classify(flags: &SessionFlags, previous: Option<RaceState>) -> RaceState {
+        let finished = matches!(previous, Some(RaceState::Checkered | RaceState::Cooldown));
+        if flags.checkered {
+            RaceState::Checkered
+        } else if finished && !flags.green {
+            RaceState::Cooldown
+        } else if flags.one_lap_to_green {
+            RaceState::OneToGreen
+        } else if flags.caution_waving {
+            RaceState::CautionWaving
+        } else if flags.caution {
+            RaceState::FullCourseYellow
+        } else {
+            RaceState::Green
+        }
+    }
+}
+
+/// Turn a stream of frames into [`RaceState`] transitions.
+///
+/// Emits the starting phase once it has held for 30 ticks, then each change that
+/// holds as long, so a single-frame flicker in the flag bits emits nothing.
+/// Frames without `SessionFlags` are skipped. Frames should not be heavily
+/// throttled, or short phases may be missed.
+pub fn race_state_updates<S>(frames: S) -> impl Stream<Item = RaceState>
+where
+    S: Stream<Item = Arc<FramePacket>>,
+{
+    let mut tracker = RaceStateTracker::default();
+    frames.filter_map(move |packet| future::ready(tracker.update(&packet)))
+}
+
+#[derive(Default)]
+struct RaceStateTracker {
+    current: Option<RaceState>,
+    /// Phase waiting to be confirmed, and the tick it was first seen
+    candidate: Option<(RaceState, u32)>,
+}
+
+impl RaceStateTracker {
+    fn update(&mut self, packet: &FramePacket) -> Option<RaceState> {
+        let flags = decode_session_flags(packet.get_bitfield("SessionFlags").ok()?);
+        let state = RaceState::classify(&flags, self.current);
+
+        if Some(state) == self.current {
+            self.candidate = None;
+            return None;
+        }
+
+        match self.candidate {
+            Some((candidate, since)) if candidate == state => {
+                if packet.tick.wrapping_sub(since) < RACE_STATE_DEBOUNCE_TICKS {
+                    return None;
+                }
+                self.candidate = None;
+                self.current = Some(state);
+                Some(state)
+            }
+            _ => {
+                self.candidate = Some((state, packet.tick));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VariableType;
+    use crate::test_utils::{PacketBuilder, test_schema};
+    use crate::types::irsdk_flags::session_flags as sf;
+    use futures::stream;
+
+    fn frames(rows: &[(u32, u32)]) -> Vec<Arc<FramePacket>> {
+        let schema = Arc::new(test_schema(&[("SessionFlags", VariableType::BitField, 0, 1)], 4));
+
+        rows.iter()
+            .map(|&(tick, flags)| {
+                Arc::new(
+                    PacketBuilder::new(&schema)
+                        .set("SessionFlags", flags.to_le_bytes())
+                        .build(tick),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn follows_a_caution_period_through_to_cooldown() {
+        let rows = [
+            (0, sf::GREEN),
+            (30, sf::GREEN),
+            // A one-frame caution flicker is ignored
+            (40, sf::CAUTION),
+            (41, sf::GREEN),
+            (100, sf::CAUTION_WAVING | sf::CAUTION),
+            (130, sf::CAUTION_WAVING | sf::CAUTION),
+            (200, sf::CAUTION),
+            (230, sf::CAUTION),
+            (300, sf::CAUTION | sf::ONE_LAP_TO_GREEN),
+            (330, sf::CAUTION | sf::ONE_LAP_TO_GREEN),
+            (400, sf::GREEN),
+            (430, sf::GREEN),
+            (500, sf::CHECKERED | sf::CAUTION),
+            (530, sf::CHECKERED),
+            (600, 0),
+            (630, 0),
+            // Cautions after the finish do not leave cooldown
+            (700, sf::CAUTION),
+            (730, sf::CAUTION),
+        ];
+
+        let states: Vec<RaceState> =
+            race_state_updates(stream::iter(frames(&rows))).collect().await;
+
+        assert_eq!(
+            states,
+            vec![
+                RaceState::Green,
+                RaceState::CautionWaving,
+                RaceState::FullCourseYellow,
+                RaceState::OneToGreen,
+                RaceState::Green,
+                RaceState::Checkered,
+                RaceState::Cooldown,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn green_flag_restarts_after_cooldown() {
+        let rows = [
+            (0, sf::CHECKERED),
+            (30, sf::CHECKERED),
+            (60, 0),
+            (90, 0),
+            (120, sf::GREEN),
+            (150, sf::GREEN),
+            (160, 0),
+            (190, 0),
+        ];
+
+        let states: Vec<RaceState> =
+            race_state_updates(stream::iter(frames(&rows))).collect().await;
+
+        assert_eq!(states, vec![RaceState::Checkered, RaceState::Cooldown, RaceState::Green]);
+    }
+}
@@ -0,0 +1,84 @@
+//! Frame adaptation that follows schema changes
+
+use futures::{Stream, StreamExt, future};
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::adapters::{AdapterValidation, FrameAdapter};
+use crate::types::FramePacket;
+use crate::{Result, VariableSchema};
+
+/// An extraction plan kept in step with the schema of incoming packets.
+///
+/// Schema changes are detected by `Arc` identity and compared with
+/// [`VariableSchema::diff`]; the plan is only rebuilt with `rebuild` when a
+/// field it reads changed. While the current schema cannot satisfy the plan,
+/// [`plan_for`](Self::plan_for) returns `None`.
+pub(crate) struct Revalidator<V, F> {
+    current: Option<(Arc<VariableSchema>, Option<V>)>,
+    rebuild: F,
+}
+
+impl<V, F> Revalidator<V, F>
+where
+    V: AsRef<AdapterValidation>,
+    F: FnMut(&VariableSchema) -> Result<V>,
+{
+    /// Start with no plan; the first packet's schema builds one
+    pub(crate) fn new(rebuild: F) -> Self {
+        Self { current: None, rebuild }
+    }
+
+    /// Start from `plan`, already validated against `schema`
+    #[cfg_attr(not(windows), allow(dead_code))] // Used by the Windows live connection
+    pub(crate) fn with_plan(schema: Arc<VariableSchema>, plan: V, rebuild: F) -> Self {
+        Self { current: Some((schema, Some(plan))), rebuild }
+    }
+
+    /// Get the plan for `schema`, rebuilding it first if the schema changed
+    pub(crate) fn plan_for(&mut self, schema: &Arc<VariableSchema>) -> Option<&V> {
+        let stale = self.current.as_ref().is_none_or(|(current, _)| !Arc::ptr_eq(current, schema));
+
+        if stale {
+            // Keep the existing plan when none of its fields moved
+            let previous = self.current.take().and_then(|(current, plan)| {
+                let plan = plan?;
+                let affected = plan.as_ref().is_affected_by(&current.diff(schema));
+                if affected {
+                    warn!("Telemetry schema changed, re-validating adapter");
+                }
+                (!affected).then_some(plan)
+            });
+
+            let plan = previous.or_else(|| match (self.rebuild)(schema) {
+                Ok(plan) => Some(plan),
+                Err(e) => {
+                    warn!("Adapter incompatible with current schema, skipping frames: {}", e);
+                    None
+                }
+            });
+            self.current = Some((Arc::clone(schema), plan));
+        }
+
+        self.current.as_ref().and_then(|(_, plan)| plan.as_ref())
+    }
+}
+
+/// Adapt frames to `T`, re-running `validate_schema` whenever the packet schema changes.
+///
+/// Live telemetry can change variable layout after a reconnect (e.g. a different car),
+/// so a validation computed once at subscription time may point at stale offsets.
+/// The adapter is only re-validated when a field it reads changed. Frames whose
+/// schema fails validation are skipped until a compatible schema arrives.
+pub fn adapt_revalidating<T, S>(frames: S) -> impl Stream<Item = T>
+where
+    T: FrameAdapter,
+    S: Stream<Item = Arc<FramePacket>>,
+{
+    let mut plan = Revalidator::new(|schema: &VariableSchema| T::validate_schema(schema));
+
+    frames.filter_map(move |packet| {
+        let item = plan.plan_for(&packet.schema).map(|validation| T::adapt(&packet, validation));
+        future::ready(item)
+    })
+}
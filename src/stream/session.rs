@@ -0,0 +1,46 @@
+//! Session info change detection for session update streams
+
+use futures::{Stream, StreamExt, future};
+use std::sync::Arc;
+
+use crate::SessionInfo;
+use crate::schema::session::SessionDiff;
+
+/// Turn a stream of session snapshots into a stream of non-empty [`SessionDiff`]s
+///
+/// The first snapshot is the baseline and produces no diff. Re-parses that leave
+/// every tracked field unchanged are dropped.
+pub fn session_changes<S>(sessions: S) -> impl Stream<Item = SessionDiff>
+where
+    S: Stream<Item = Arc<SessionInfo>>,
+{
+    let mut previous: Option<Arc<SessionInfo>> = None;
+    sessions.filter_map(move |current| {
+        let diff = previous.as_ref().map(|previous| previous.diff(&current));
+        previous = Some(current);
+        future::ready(diff.filter(|diff| !diff.is_empty()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    #[tokio::test]
+    async fn session_changes_skips_baseline_and_no_op_updates() {
+        let snapshot = |session_num: i32| {
+            let mut info = SessionInfo::default();
+            info.session_info.current_session_num = session_num;
+            Arc::new(info)
+        };
+        let updates = vec![snapshot(0), snapshot(0), snapshot(1), snapshot(1), snapshot(2)];
+
+        let changes: Vec<Option<(i32, i32)>> = session_changes(stream::iter(updates))
+            .map(|diff| diff.session_num_change)
+            .collect()
+            .await;
+
+        assert_eq!(changes, vec![Some((0, 1)), Some((1, 2))]);
+    }
+}
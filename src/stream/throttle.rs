@@ -1,12 +1,15 @@
 //! Stream throttling utilities
 
-use futures::{Stream, ready};
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt, ready};
 use pin_project_lite::pin_project;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::Duration;
 use tokio::time::{Interval, interval};
 
+use crate::UpdateRate;
+
 /// Extension trait to add throttling to any Stream
 pub trait ThrottleExt: Stream {
     /// Throttle the stream to emit at most once per interval
@@ -19,6 +22,18 @@ pub trait ThrottleExt: Stream {
     {
         Throttle::new(self, duration)
     }
+
+    /// Emit the freshest item once per interval
+    ///
+    /// The stream is drained continuously so the item emitted at each tick is
+    /// the most recent one. If nothing arrived during an interval, the next item
+    /// is emitted as soon as it arrives.
+    fn latest_at(self, duration: Duration) -> LatestAt<Self>
+    where
+        Self: Sized,
+    {
+        LatestAt::new(self, duration)
+    }
 }
 
 impl<T: Stream> ThrottleExt for T {}
@@ -73,3 +88,93 @@ impl<S: Stream> Stream for Throttle<S> {
         }
     }
 }
+
+pin_project! {
+    /// A stream combinator that emits the latest item at a bounded rate
+    pub struct LatestAt<S: Stream> {
+        #[pin]
+        stream: S,
+        interval: Interval,
+        latest: Option<S::Item>,
+        due: bool,
+        done: bool,
+    }
+}
+
+impl<S: Stream> LatestAt<S> {
+    /// Create a new latest-at stream
+    pub fn new(stream: S, duration: Duration) -> Self {
+        let mut interval = interval(duration);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        Self { stream, interval, latest: None, due: false, done: false }
+    }
+}
+
+impl<S: Stream> Stream for LatestAt<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Keep draining between ticks so `latest` is always the newest item
+        while !*this.done {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => *this.latest = Some(item),
+                Poll::Ready(None) => *this.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if !*this.due && this.interval.poll_tick(cx).is_ready() {
+            *this.due = true;
+        }
+
+        if *this.due && this.latest.is_some() {
+            *this.due = false;
+            return Poll::Ready(this.latest.take());
+        }
+
+        if *this.done {
+            // Flush the final item rather than waiting for another tick
+            return Poll::Ready(this.latest.take());
+        }
+
+        Poll::Pending
+    }
+}
+
+/// Apply an [`UpdateRate`] to a stream, normalized against the source frequency
+pub fn apply_rate<S>(stream: S, rate: UpdateRate, source_hz: f64) -> BoxStream<'static, S::Item>
+where
+    S: Stream + Send + 'static,
+    S::Item: Send,
+{
+    let rate = rate.normalize(source_hz);
+    match (rate, rate.interval()) {
+        (UpdateRate::Max(_), Some(interval)) => stream.throttle(interval).boxed(),
+        (UpdateRate::LatestAt(_), Some(interval)) => stream.latest_at(interval).boxed(),
+        _ => stream.boxed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    #[tokio::test(start_paused = true)]
+    async fn latest_at_coalesces_to_freshest_item() {
+        // Items every 10ms, sampled every 35ms
+        let source = stream::iter(0..10).then(|i| async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            i
+        });
+        let sampled: Vec<i32> =
+            Box::pin(source.latest_at(Duration::from_millis(35))).collect().await;
+
+        // First tick fires immediately and waits for the first item; later ticks
+        // take whatever arrived most recently, and the final item is flushed.
+        assert_eq!(sampled, vec![0, 2, 6, 9]);
+    }
+}
@@ -6,6 +6,9 @@
 #![cfg(any(test, feature = "benchmark"))]
 
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::{FramePacket, VariableInfo, VariableSchema, VariableType};
 
 /// Guidance shown when telemetry fixtures are missing from the repository checkout.
 pub const FIXTURE_INSTALL_GUIDANCE: &str = "Telemetry fixtures are stored under test-data/. Install Git LFS and run `git lfs pull` to download them.";
@@ -141,11 +144,11 @@ pub fn get_smallest_ibt_test_file() -> Option<PathBuf> {
     let mut smallest_size = u64::MAX;
 
     for file in test_files {
-        if let Ok(metadata) = std::fs::metadata(&file) {
-            if metadata.len() < smallest_size {
-                smallest_size = metadata.len();
-                smallest_file = Some(file);
-            }
+        if let Ok(metadata) = std::fs::metadata(&file)
+            && metadata.len() < smallest_size
+        {
+            smallest_size = metadata.len();
+            smallest_file = Some(file);
         }
     }
 
@@ -193,6 +196,192 @@ pub fn require_smallest_ibt_fixture() -> Result<PathBuf, FixtureError> {
     })
 }
 
+/// Tick rate written into synthetic IBT headers.
+pub const SYNTHETIC_TICK_RATE: i32 = 60;
+
+/// Frame size in bytes of synthetic IBT recordings.
+///
+/// Layout: `SessionTime` (f64 @ 0), `Speed` (f32 @ 8), `Gear` (i32 @ 12).
+pub const SYNTHETIC_FRAME_SIZE: usize = 16;
+
+/// Build an in-memory IBT recording with `frame_count` deterministic frames.
+///
+/// Fixture-free tests use this instead of the Git LFS recordings. Frame `i` holds
+/// `SessionTime = i / 60`, `Speed = i` and `Gear = i % 6`, and the file carries a
+/// minimal session YAML document.
+pub fn synthetic_ibt_bytes(frame_count: usize) -> Vec<u8> {
+    synthetic_ibt_bytes_at_rate(frame_count, SYNTHETIC_TICK_RATE)
+}
+
+/// Build a synthetic IBT recording with a custom header tick rate.
+///
+/// Same layout as [`synthetic_ibt_bytes`], with `SessionTime = i / tick_rate`.
+pub fn synthetic_ibt_bytes_at_rate(frame_count: usize, tick_rate: i32) -> Vec<u8> {
+    // (irsdk type, offset, name, unit)
+    let variables = [(5, 0, "SessionTime", "s"), (4, 8, "Speed", "m/s"), (2, 12, "Gear", "")];
+
+    build_synthetic_ibt(&variables, SYNTHETIC_FRAME_SIZE, frame_count, tick_rate, |i, frame| {
+        let session_time = i as f64 / tick_rate as f64;
+        frame[0..8].copy_from_slice(&session_time.to_le_bytes());
+        frame[8..12].copy_from_slice(&(i as f32).to_le_bytes());
+        frame[12..16].copy_from_slice(&((i % 6) as i32).to_le_bytes());
+    })
+}
+
+/// Build a 60Hz synthetic IBT recording of consecutive laps driven at constant speed.
+///
+/// Layout: `SessionTime` (f64 @ 0), `Lap` (i32 @ 8), `LapDistPct` (f32 @ 12). Lap
+/// numbers start at 1 and lap `n` takes `lap_times[n - 1]` seconds.
+pub fn synthetic_lap_ibt_bytes(lap_times: &[f64]) -> Vec<u8> {
+    let variables = [(5, 0, "SessionTime", "s"), (2, 8, "Lap", ""), (4, 12, "LapDistPct", "%")];
+    let hz = SYNTHETIC_TICK_RATE as f64;
+    let frame_count = (lap_times.iter().sum::<f64>() * hz).floor() as usize;
+
+    build_synthetic_ibt(&variables, 16, frame_count, SYNTHETIC_TICK_RATE, |i, frame| {
+        let session_time = i as f64 / hz;
+        let mut lap_start = 0.0;
+        let mut lap = 0;
+        while lap + 1 < lap_times.len() && session_time >= lap_start + lap_times[lap] {
+            lap_start += lap_times[lap];
+            lap += 1;
+        }
+        let dist_pct = ((session_time - lap_start) / lap_times[lap]) as f32;
+
+        frame[0..8].copy_from_slice(&session_time.to_le_bytes());
+        frame[8..12].copy_from_slice(&(lap as i32 + 1).to_le_bytes());
+        frame[12..16].copy_from_slice(&dist_pct.to_le_bytes());
+    })
+}
+
+/// Lay out an IBT file with one scalar variable per `(irsdk type, offset, name, unit)`
+/// and `frame_count` frames filled by `write_frame(index, frame)`.
+fn build_synthetic_ibt(
+    variables: &[(i32, i32, &str, &str)],
+    frame_size: usize,
+    frame_count: usize,
+    tick_rate: i32,
+    write_frame: impl Fn(usize, &mut [u8]),
+) -> Vec<u8> {
+    const HEADER_SIZE: usize = 144;
+    const DISK_HEADER_SIZE: usize = 32;
+    const VAR_HEADER_SIZE: usize = 144;
+
+    let yaml = "WeekendInfo:\n TrackName: synthetic\n TrackDisplayName: Synthetic Raceway\n TrackLength: 1.00 km\nSessionInfo:\n CurrentSessionNum: 0\n Sessions:\n - SessionNum: 0\n   SessionLaps: unlimited\n   SessionTime: unlimited\n   SessionType: Practice\nSplitTimeInfo:\n Sectors:\n - SectorNum: 0\n   SectorStartPct: 0.000000\n - SectorNum: 1\n   SectorStartPct: 0.500000\n";
+
+    let var_header_offset = HEADER_SIZE + DISK_HEADER_SIZE;
+    let session_info_offset = var_header_offset + variables.len() * VAR_HEADER_SIZE;
+    let frame_data_start = session_info_offset + yaml.len();
+
+    let mut data = vec![0u8; frame_data_start + frame_count * frame_size];
+    fn put_i32(data: &mut [u8], at: usize, value: i32) {
+        data[at..at + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    // irsdk_header
+    put_i32(&mut data, 0, 2);
+    put_i32(&mut data, 4, 1);
+    put_i32(&mut data, 8, tick_rate);
+    put_i32(&mut data, 12, 1);
+    put_i32(&mut data, 16, yaml.len() as i32);
+    put_i32(&mut data, 20, session_info_offset as i32);
+    put_i32(&mut data, 24, variables.len() as i32);
+    put_i32(&mut data, 28, var_header_offset as i32);
+    put_i32(&mut data, 32, 1);
+    put_i32(&mut data, 36, frame_size as i32);
+
+    // irsdk_diskSubHeader
+    let end_time = frame_count as f64 / tick_rate as f64;
+    data[HEADER_SIZE + 16..HEADER_SIZE + 24].copy_from_slice(&end_time.to_le_bytes());
+    put_i32(&mut data, HEADER_SIZE + 28, frame_count as i32);
+
+    // irsdk_varHeader array
+    for (i, (var_type, offset, name, unit)) in variables.iter().enumerate() {
+        let base = var_header_offset + i * VAR_HEADER_SIZE;
+        put_i32(&mut data, base, *var_type);
+        put_i32(&mut data, base + 4, *offset);
+        put_i32(&mut data, base + 8, 1);
+        data[base + 16..base + 16 + name.len()].copy_from_slice(name.as_bytes());
+        data[base + 112..base + 112 + unit.len()].copy_from_slice(unit.as_bytes());
+    }
+
+    data[session_info_offset..frame_data_start].copy_from_slice(yaml.as_bytes());
+
+    for (i, frame) in data[frame_data_start..].chunks_exact_mut(frame_size).enumerate() {
+        write_frame(i, frame);
+    }
+
+    data
+}
+
+/// Write a synthetic IBT recording to the system temp directory.
+///
+/// `name` must be unique per test so parallel tests do not share a file.
+pub fn write_synthetic_ibt(name: &str, frame_count: usize) -> PathBuf {
+    write_synthetic_ibt_at_rate(name, frame_count, SYNTHETIC_TICK_RATE)
+}
+
+/// Write a synthetic IBT recording with a custom header tick rate to the temp directory.
+pub fn write_synthetic_ibt_at_rate(name: &str, frame_count: usize, tick_rate: i32) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pitwall-{}-{}.ibt", name, std::process::id()));
+    std::fs::write(&path, synthetic_ibt_bytes_at_rate(frame_count, tick_rate))
+        .expect("Failed to write synthetic IBT file");
+    path
+}
+
+/// Build a schema from `(name, type, offset, count)` entries.
+///
+/// Units and descriptions are left empty. Panics if the layout does not fit in
+/// `frame_size`.
+pub fn test_schema(
+    variables: &[(&str, VariableType, usize, usize)],
+    frame_size: usize,
+) -> VariableSchema {
+    let variables = variables
+        .iter()
+        .map(|&(name, data_type, offset, count)| {
+            let info = VariableInfo {
+                name: name.to_string(),
+                data_type,
+                offset,
+                count,
+                count_as_time: false,
+                units: String::new(),
+                description: String::new(),
+            };
+            (name.to_string(), info)
+        })
+        .collect();
+
+    VariableSchema::new(variables, frame_size).expect("Test schema should be valid")
+}
+
+/// Build a [`FramePacket`] over a zeroed frame, writing each variable's bytes at its offset.
+pub struct PacketBuilder {
+    schema: Arc<VariableSchema>,
+    data: Vec<u8>,
+}
+
+impl PacketBuilder {
+    /// Start a zeroed frame of `schema.frame_size` bytes
+    pub fn new(schema: &Arc<VariableSchema>) -> Self {
+        Self { schema: Arc::clone(schema), data: vec![0; schema.frame_size] }
+    }
+
+    /// Write `bytes` at the offset of variable `name`; panics if it is not in the schema
+    pub fn set(mut self, name: &str, bytes: impl AsRef<[u8]>) -> Self {
+        let bytes = bytes.as_ref();
+        let offset =
+            self.schema.get_variable(name).expect("Variable should be in the schema").offset;
+        self.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        self
+    }
+
+    /// Finish the packet at `tick` with session version 1
+    pub fn build(self, tick: u32) -> FramePacket {
+        FramePacket::new(self.data, tick, 1, self.schema)
+    }
+}
+
 /// Require a file inside `test-data/` by name.
 #[cfg(all(test, windows))]
 pub fn require_test_data_file(file_name: &str) -> Result<PathBuf, FixtureError> {
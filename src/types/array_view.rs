@@ -0,0 +1,98 @@
+//! Zero-copy views over array variables
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use super::{VarData, VariableInfo};
+
+/// Array variable read in place from a frame buffer.
+///
+/// Reading an array through [`VarData`] (`Vec<T>` or `[T; N]`) decodes every
+/// element into a new allocation. A view shares the frame's `Arc<[u8]>` instead
+/// and decodes elements only when they are accessed, so adapters can hold the
+/// 64-element `CarIdx*` arrays for the cost of a reference count increment.
+///
+/// The whole variable is bounds-checked and the element type is checked when the
+/// view is created, so element access cannot fail.
+pub struct ArrayView<T> {
+    data: Arc<[u8]>,
+    /// Layout of element 0, with no name, units or description
+    element: VariableInfo,
+    len: usize,
+    _element: PhantomData<fn() -> T>,
+}
+
+impl<T: VarData> ArrayView<T> {
+    /// View the array variable `info` within `data`.
+    ///
+    /// Returns `Memory` when the variable extends past the end of `data` and
+    /// `TypeConversion` when its elements cannot be read as `T`.
+    pub fn new(data: Arc<[u8]>, info: &VariableInfo) -> crate::Result<Self> {
+        info.check_bounds(data.len())?;
+
+        let element = info.element_layout();
+        T::from_bytes(&data, &element)?;
+
+        Ok(Self { data, element, len: info.count, _element: PhantomData })
+    }
+
+    /// Get the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when the variable has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decode the element at `index`, or `None` past the end of the array.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let element = VariableInfo {
+            offset: self.element.offset + index * self.element.data_type.size(),
+            ..self.element.clone()
+        };
+        T::from_bytes(&self.data, &element).ok()
+    }
+
+    /// Decode each element in order.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..self.len).filter_map(|index| self.get(index))
+    }
+
+    /// Decode every element into a `Vec`, as `Vec<T>::from_bytes` would.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Raw little-endian bytes of the whole array.
+    pub fn as_bytes(&self) -> &[u8] {
+        let start = self.element.offset;
+        &self.data[start..start + self.len * self.element.data_type.size()]
+    }
+}
+
+impl<T> Clone for ArrayView<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: Arc::clone(&self.data),
+            element: self.element.clone(),
+            len: self.len,
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for ArrayView<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayView")
+            .field("data_type", &self.element.data_type)
+            .field("offset", &self.element.offset)
+            .field("len", &self.len)
+            .finish()
+    }
+}
@@ -1,9 +1,8 @@
 //! BitField type for handling iRacing bitfield variables
 
-use serde::{Deserialize, Serialize};
-
 /// BitField type for handling iRacing bitfield variables.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
 pub struct BitField(pub u32);
 
@@ -27,6 +26,20 @@ impl BitField {
     pub fn value(&self) -> u32 {
         self.0
     }
+
+    /// Iterate over the indices of set bits, lowest first.
+    pub fn set_bits(&self) -> impl Iterator<Item = u32> {
+        let value = self.0;
+        (0..32).filter(move |bit| value & (1 << bit) != 0)
+    }
+
+    /// Names of the flags in `table` that are set, in table order.
+    ///
+    /// Tables for known bitfields live in [`irsdk_flags`](super::irsdk_flags), e.g.
+    /// `bits.decode_named(irsdk_flags::session_flags::NAMES)`.
+    pub fn decode_named(&self, table: &[(u32, &'static str)]) -> Vec<&'static str> {
+        table.iter().filter(|(flag, _)| self.has_flag(*flag)).map(|(_, name)| *name).collect()
+    }
 }
 
 /// Compare tick counters in u32 space with wraparound using half-range rule.
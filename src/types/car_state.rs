@@ -0,0 +1,130 @@
+//! Per-car view joining the `CarIdx*` telemetry arrays with driver info
+//!
+//! iRacing publishes opponent state as parallel arrays indexed by car index
+//! (`CarIdxPosition`, `CarIdxLapDistPct`, ...). [`FramePacket::per_car_view`]
+//! zips them into one [`CarState`] per car and attaches the session's [`Driver`].
+
+use std::collections::HashMap;
+
+use super::{FramePacket, Gear, TrackSurface, VarData};
+use crate::schema::session::{Driver, DriverInfoData};
+
+/// Telemetry for one car at one frame, joined with its driver
+///
+/// Fields are `None` when the source does not publish the corresponding array
+/// (IBT recordings carry few `CarIdx*` variables).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CarState<'a> {
+    /// Car index shared by the arrays and the drivers list
+    pub car_idx: usize,
+    /// Driver entry for this car
+    pub driver: &'a Driver,
+    /// Overall race position (from `CarIdxPosition`; 0 before the car is classified)
+    pub position: Option<i32>,
+    /// Position within the car's class (from `CarIdxClassPosition`)
+    pub class_position: Option<i32>,
+    /// Lap the car is on (from `CarIdxLap`)
+    pub lap: Option<i32>,
+    /// Laps completed (from `CarIdxLapCompleted`)
+    pub lap_completed: Option<i32>,
+    /// Distance around the current lap, 0-1 (from `CarIdxLapDistPct`)
+    pub lap_dist_pct: Option<f32>,
+    /// Current gear (from `CarIdxGear`)
+    pub gear: Option<Gear>,
+    /// Last lap time in seconds (from `CarIdxLastLapTime`)
+    pub last_lap_time: Option<f32>,
+    /// Where the car is (from `CarIdxTrackSurface`)
+    pub track_surface: Option<TrackSurface>,
+    /// Whether the car is between the pit cones (from `CarIdxOnPitRoad`)
+    pub on_pit_road: Option<bool>,
+}
+
+impl FramePacket {
+    /// Join the `CarIdx*` arrays of this frame with `driver_info`, one entry per car.
+    ///
+    /// Slots without a driver entry are skipped, as are cars `CarIdxTrackSurface`
+    /// reports as not in the world (disconnected or not yet joined). Entries are
+    /// ordered by car index; sort by [`CarState::position`] for standings.
+    pub fn per_car_view<'a>(&self, driver_info: &'a DriverInfoData) -> Vec<CarState<'a>> {
+        let Some(drivers) = driver_info.drivers.as_ref() else {
+            return Vec::new();
+        };
+
+        let position = self.car_array::<i32>("CarIdxPosition");
+        let class_position = self.car_array::<i32>("CarIdxClassPosition");
+        let lap = self.car_array::<i32>("CarIdxLap");
+        let lap_completed = self.car_array::<i32>("CarIdxLapCompleted");
+        let lap_dist_pct = self.car_array::<f32>("CarIdxLapDistPct");
+        let gear = self.car_array::<Gear>("CarIdxGear");
+        let last_lap_time = self.car_array::<f32>("CarIdxLastLapTime");
+        let track_surface = self.car_array::<TrackSurface>("CarIdxTrackSurface");
+        let on_pit_road = self.car_array::<bool>("CarIdxOnPitRoad");
+
+        let mut cars: Vec<CarState<'a>> = drivers
+            .iter()
+            .filter_map(|driver| {
+                let car_idx = usize::try_from(driver.car_idx).ok()?;
+                let track_surface = at(&track_surface, car_idx);
+                if track_surface == Some(TrackSurface::NotInWorld) {
+                    return None;
+                }
+
+                Some(CarState {
+                    car_idx,
+                    driver,
+                    position: at(&position, car_idx),
+                    class_position: at(&class_position, car_idx),
+                    lap: at(&lap, car_idx),
+                    lap_completed: at(&lap_completed, car_idx),
+                    lap_dist_pct: at(&lap_dist_pct, car_idx),
+                    gear: at(&gear, car_idx),
+                    last_lap_time: at(&last_lap_time, car_idx),
+                    track_surface,
+                    on_pit_road: at(&on_pit_road, car_idx),
+                })
+            })
+            .collect();
+        cars.sort_by_key(|car| car.car_idx);
+        cars
+    }
+
+    /// Standings within each car class, keyed by `CarClassID`.
+    ///
+    /// Builds on [`per_car_view`](Self::per_car_view), dropping the pace car and
+    /// cars without a class id. Each class is ordered by `CarIdxClassPosition`,
+    /// with cars not yet classified (position 0 or no array) last, by car index.
+    pub fn per_class_standings<'a>(
+        &self,
+        driver_info: &'a DriverInfoData,
+    ) -> HashMap<i32, Vec<CarState<'a>>> {
+        let pace_car_idx = driver_info.pace_car().map(|driver| driver.car_idx);
+
+        let mut classes: HashMap<i32, Vec<CarState<'a>>> = HashMap::new();
+        for car in self.per_car_view(driver_info) {
+            let is_pace_car =
+                car.driver.car_is_pace_car == Some(1) || Some(car.driver.car_idx) == pace_car_idx;
+            if is_pace_car {
+                continue;
+            }
+            if let Some(class_id) = car.driver.car_class_id {
+                classes.entry(class_id).or_default().push(car);
+            }
+        }
+
+        for cars in classes.values_mut() {
+            cars.sort_by_key(|car| {
+                let classified = car.class_position.filter(|&position| position > 0);
+                (classified.is_none(), classified, car.car_idx)
+            });
+        }
+        classes
+    }
+
+    fn car_array<T: VarData>(&self, name: &str) -> Option<Vec<T>> {
+        self.get::<Vec<T>>(name).ok()
+    }
+}
+
+fn at<T: Copy>(values: &Option<Vec<T>>, car_idx: usize) -> Option<T> {
+    values.as_ref()?.get(car_idx).copied()
+}
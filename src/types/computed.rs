@@ -0,0 +1,133 @@
+//! Derived channels computed from raw telemetry
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use super::{FramePacket, Value};
+use crate::{Result, TelemetryError};
+
+/// Function evaluating a computed channel for one frame
+pub type ComputeFn = dyn Fn(&FramePacket) -> Value + Send + Sync;
+
+/// Channels being evaluated on this thread, innermost last
+#[derive(Default)]
+struct Evaluation {
+    stack: Vec<String>,
+    /// First cycle found while the outermost channel was evaluated
+    cycle: Option<String>,
+}
+
+thread_local! {
+    static EVALUATION: RefCell<Evaluation> = RefCell::default();
+}
+
+/// Pops the channel pushed by [`ComputedChannels::evaluate`], even if it panics
+struct Evaluating;
+
+impl Drop for Evaluating {
+    fn drop(&mut self) {
+        EVALUATION.with_borrow_mut(|evaluation| {
+            evaluation.stack.pop();
+            if evaluation.stack.is_empty() {
+                evaluation.cycle = None;
+            }
+        });
+    }
+}
+
+/// Named channels derived from a frame's raw variables.
+///
+/// Registered on a connection with `add_computed` and attached to every packet it
+/// delivers, so [`FramePacket::get_value`] and [`FramePacket::to_map`] return
+/// computed channels alongside raw ones. Channels are evaluated on access, never
+/// for frames that do not ask for them. A channel may read other computed
+/// channels, but not itself, directly or through others.
+#[derive(Clone, Default)]
+pub struct ComputedChannels {
+    channels: BTreeMap<String, Arc<ComputeFn>>,
+}
+
+impl ComputedChannels {
+    /// Create an empty set of channels
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a channel, replacing any existing channel of the same name.
+    pub fn insert<F>(&mut self, name: impl Into<String>, compute: F)
+    where
+        F: Fn(&FramePacket) -> Value + Send + Sync + 'static,
+    {
+        self.channels.insert(name.into(), Arc::new(compute));
+    }
+
+    /// Check if a channel is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.channels.contains_key(name)
+    }
+
+    /// Channel names, sorted.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.channels.keys().map(String::as_str)
+    }
+
+    /// Get the number of channels.
+    pub fn len(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Returns true when no channels are registered.
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty()
+    }
+
+    /// Evaluate the channel named `name` for `packet`.
+    ///
+    /// Returns `FieldNotFound` when no channel is registered under `name`, and a
+    /// `Parse` error when the channel reads itself through
+    /// [`FramePacket::get_value`], directly or via other channels. The inner read
+    /// fails instead of recursing, and the outermost evaluation reports the cycle.
+    pub fn evaluate(&self, name: &str, packet: &FramePacket) -> Result<Value> {
+        let compute = self
+            .channels
+            .get(name)
+            .ok_or_else(|| TelemetryError::FieldNotFound { field: name.to_string() })?;
+
+        let cycle = EVALUATION.with_borrow_mut(|evaluation| {
+            if !evaluation.stack.iter().any(|active| active == name) {
+                evaluation.stack.push(name.to_string());
+                return None;
+            }
+            let path = format!("{} -> {}", evaluation.stack.join(" -> "), name);
+            Some(evaluation.cycle.get_or_insert(path).clone())
+        });
+        if let Some(path) = cycle {
+            return Err(cycle_error(&path));
+        }
+
+        let _evaluating = Evaluating;
+        let value = compute(packet);
+        let cycle = EVALUATION.with_borrow_mut(|evaluation| {
+            if evaluation.stack.len() == 1 { evaluation.cycle.take() } else { None }
+        });
+        match cycle {
+            Some(path) => Err(cycle_error(&path)),
+            None => Ok(value),
+        }
+    }
+}
+
+impl fmt::Debug for ComputedChannels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+fn cycle_error(path: &str) -> TelemetryError {
+    TelemetryError::Parse {
+        context: "Computed channel".to_string(),
+        details: format!("Channel reads itself: {}", path),
+    }
+}
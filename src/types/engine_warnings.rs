@@ -0,0 +1,51 @@
+//! EngineWarnings decoding
+
+use super::BitField;
+
+/// Decoded `EngineWarnings` bitfield
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct EngineWarnings {
+    pub water_temp_warning: bool,
+    pub fuel_pressure_warning: bool,
+    pub oil_pressure_warning: bool,
+    pub engine_stalled: bool,
+    pub pit_speed_limiter: bool,
+    pub rev_limiter_active: bool,
+    pub oil_temp_warning: bool,
+    pub mandatory_repair_needed: bool,
+    pub optional_repair_needed: bool,
+}
+
+impl EngineWarnings {
+    /// Any temperature or pressure warning light is on
+    pub fn has_warning_light(&self) -> bool {
+        self.water_temp_warning
+            || self.fuel_pressure_warning
+            || self.oil_pressure_warning
+            || self.oil_temp_warning
+    }
+
+    /// Any repair (mandatory or optional) is needed
+    pub fn repair_needed(&self) -> bool {
+        self.mandatory_repair_needed || self.optional_repair_needed
+    }
+}
+
+/// Decode a BitField carrying EngineWarnings into named booleans
+pub fn decode_engine_warnings(bits: BitField) -> EngineWarnings {
+    use super::irsdk_flags::engine_warnings as ew;
+
+    EngineWarnings {
+        water_temp_warning: bits.has_flag(ew::WATER_TEMP_WARNING),
+        fuel_pressure_warning: bits.has_flag(ew::FUEL_PRESSURE_WARNING),
+        oil_pressure_warning: bits.has_flag(ew::OIL_PRESSURE_WARNING),
+        engine_stalled: bits.has_flag(ew::ENGINE_STALLED),
+        pit_speed_limiter: bits.has_flag(ew::PIT_SPEED_LIMITER),
+        rev_limiter_active: bits.has_flag(ew::REV_LIMITER_ACTIVE),
+        oil_temp_warning: bits.has_flag(ew::OIL_TEMP_WARNING),
+        mandatory_repair_needed: bits.has_flag(ew::MAND_REP_NEEDED),
+        optional_repair_needed: bits.has_flag(ew::OPT_REP_NEEDED),
+    }
+}
@@ -1,13 +1,25 @@
 //! Frame packet types for stream-based architecture
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use super::VariableSchema;
+use super::{ArrayView, BitField, ComputedChannels, Value, VarData, VariableSchema};
+use crate::{Result, TelemetryError};
 
 /// Raw telemetry frame packet for the stream-based architecture
 ///
 /// This is the fundamental data unit that flows through the system.
 /// All other data (adaptations, sessions) is derived from this.
+///
+/// # Ownership
+///
+/// A packet borrows nothing from its connection. Providers copy each frame into
+/// its own buffer, and the buffer, schema and any computed channels are
+/// reference-counted, so a packet is `Send + Sync + 'static` and cloning it costs
+/// at most three atomic increments.
+/// Packets can be buffered (`stream.take(1000).collect::<Vec<_>>()`) and
+/// processed on another thread after the connection is dropped. Consecutive
+/// packets share one schema allocation until the schema changes.
 #[derive(Debug, Clone)]
 pub struct FramePacket {
     /// Telemetry data buffer (zero-copy via Arc)
@@ -21,6 +33,9 @@ pub struct FramePacket {
 
     /// Variable schema for field access
     pub schema: Arc<VariableSchema>,
+
+    /// Computed channels attached by the connection that delivered the packet
+    computed: Option<Arc<ComputedChannels>>,
 }
 
 impl FramePacket {
@@ -31,6 +46,215 @@ impl FramePacket {
         session_version: u32,
         schema: Arc<VariableSchema>,
     ) -> Self {
-        Self { data: data.into(), tick, session_version, schema }
+        Self { data: data.into(), tick, session_version, schema, computed: None }
+    }
+
+    /// Attach computed channels to this packet.
+    pub fn with_computed(self, computed: Arc<ComputedChannels>) -> Self {
+        Self { computed: Some(computed), ..self }
+    }
+
+    /// Computed channels readable through [`get_value`](Self::get_value) and
+    /// [`to_map`](Self::to_map), if any are attached
+    pub fn computed(&self) -> Option<&ComputedChannels> {
+        self.computed.as_deref()
+    }
+
+    /// Read a variable by name.
+    ///
+    /// Returns `FieldNotFound` for unknown names and `TypeConversion` when the
+    /// variable's type does not match `T`. Reads are bounds-checked.
+    pub fn get<T: VarData>(&self, name: &str) -> Result<T> {
+        let info = self
+            .schema
+            .get_variable(name)
+            .ok_or_else(|| TelemetryError::FieldNotFound { field: name.to_string() })?;
+        T::from_bytes(&self.data, info)
+    }
+
+    /// View an array variable by name without copying its elements.
+    ///
+    /// See [`ArrayView`]; the view shares this packet's buffer.
+    pub fn get_array_view<T: VarData>(&self, name: &str) -> Result<ArrayView<T>> {
+        let info = self
+            .schema
+            .get_variable(name)
+            .ok_or_else(|| TelemetryError::FieldNotFound { field: name.to_string() })?;
+        ArrayView::new(Arc::clone(&self.data), info)
+    }
+
+    /// Read a variable by name as whatever type the schema declares.
+    ///
+    /// Array variables (`count > 1`) are returned as `Value::Array`. Names not in
+    /// the schema are looked up in the attached [`ComputedChannels`] and
+    /// evaluated on the spot; a channel that reads itself fails with a `Parse`
+    /// error instead of recursing.
+    pub fn get_value(&self, name: &str) -> Result<Value> {
+        if self.schema.has_variable(name) {
+            return self.get(name);
+        }
+        match &self.computed {
+            Some(computed) => computed.evaluate(name, self),
+            None => Err(TelemetryError::FieldNotFound { field: name.to_string() }),
+        }
+    }
+
+    /// Read every variable in the schema, plus any computed channels, into a map
+    /// keyed by name.
+    ///
+    /// Intended for logging and tests: this allocates on every call, so prefer
+    /// adapters or [`FramePacket::get`] on the streaming path. Variables that
+    /// cannot be read from this buffer are left out.
+    pub fn to_map(&self) -> HashMap<String, Value> {
+        let raw = self.schema.variables.iter().filter_map(|(name, info)| {
+            Value::from_bytes(&self.data, info).ok().map(|value| (name.clone(), value))
+        });
+        let computed = self.computed.iter().flat_map(|computed| {
+            computed
+                .names()
+                .filter(|name| !self.schema.has_variable(name))
+                .filter_map(|name| Some((name.to_string(), computed.evaluate(name, self).ok()?)))
+        });
+        raw.chain(computed).collect()
+    }
+
+    /// Read a `Float32` variable by name.
+    pub fn get_f32(&self, name: &str) -> Result<f32> {
+        self.get(name)
+    }
+
+    /// Read a `Float64` variable by name.
+    pub fn get_f64(&self, name: &str) -> Result<f64> {
+        self.get(name)
+    }
+
+    /// Read an `Int32` variable by name.
+    pub fn get_i32(&self, name: &str) -> Result<i32> {
+        self.get(name)
+    }
+
+    /// Read a `Bool` variable by name.
+    pub fn get_bool(&self, name: &str) -> Result<bool> {
+        self.get(name)
+    }
+
+    /// Read a `BitField` variable by name.
+    pub fn get_bitfield(&self, name: &str) -> Result<BitField> {
+        self.get(name)
+    }
+
+    /// Metadata for correlating this frame with session changes.
+    ///
+    /// `session_time` is read from the `SessionTime` variable when the schema has it.
+    pub fn meta(&self) -> FrameMeta {
+        let session_time = self.get_f64("SessionTime").ok();
+
+        FrameMeta { tick: self.tick, session_version: self.session_version, session_time }
+    }
+}
+
+/// Owned, serializable form of a [`FramePacket`] for fixtures and regression tests.
+///
+/// Carries the schema by value, so a saved frame decodes without the recording
+/// it was captured from. Build one with `SerializableFrame::from(&packet)` and
+/// turn it back into a packet with `FramePacket::try_from`, which validates the
+/// schema.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct SerializableFrame {
+    /// Tick count of the captured frame
+    pub tick: u32,
+    /// Session version of the captured frame
+    pub session_version: u32,
+    /// Variable schema the frame was laid out with
+    pub schema: VariableSchema,
+    /// Raw frame bytes
+    pub data: Vec<u8>,
+}
+
+impl From<&FramePacket> for SerializableFrame {
+    fn from(packet: &FramePacket) -> Self {
+        Self {
+            tick: packet.tick,
+            session_version: packet.session_version,
+            schema: (*packet.schema).clone(),
+            data: packet.data.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<SerializableFrame> for FramePacket {
+    type Error = TelemetryError;
+
+    fn try_from(frame: SerializableFrame) -> Result<Self> {
+        frame.schema.validate()?;
+        Ok(FramePacket::new(frame.data, frame.tick, frame.session_version, Arc::new(frame.schema)))
+    }
+}
+
+/// Per-frame metadata delivered alongside typed frames
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct FrameMeta {
+    /// Monotonic frame counter
+    pub tick: u32,
+
+    /// Session version (changes when session info is re-published)
+    pub session_version: u32,
+
+    /// Session time in seconds, if the source provides `SessionTime`
+    pub session_time: Option<f64>,
+}
+
+/// Consecutive typed frames with their metadata, oldest first
+///
+/// Produced by `subscribe_windowed` for derived channels that need neighbouring
+/// frames, such as acceleration from `Speed` or yaw rate from `Yaw`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameWindow<T> {
+    /// Frames in arrival order; never empty
+    pub frames: Vec<(FrameMeta, T)>,
+
+    /// Source tick rate, used to convert tick deltas when `SessionTime` is unavailable
+    pub source_hz: f64,
+}
+
+impl<T> FrameWindow<T> {
+    /// Oldest frame in the window
+    pub fn oldest(&self) -> &T {
+        &self.frames[0].1
+    }
+
+    /// Newest frame in the window
+    pub fn newest(&self) -> &T {
+        &self.frames[self.frames.len() - 1].1
+    }
+
+    /// Ticks between the oldest and newest frame (handles wraparound)
+    pub fn tick_delta(&self) -> u32 {
+        let last = self.frames.len() - 1;
+        self.frames[last].0.tick.wrapping_sub(self.frames[0].0.tick)
+    }
+
+    /// Seconds between the oldest and newest frame
+    ///
+    /// Uses `SessionTime` when both frames carry it, otherwise the tick delta at
+    /// the source rate.
+    pub fn elapsed(&self) -> f64 {
+        let last = self.frames.len() - 1;
+        match (self.frames[0].0.session_time, self.frames[last].0.session_time) {
+            (Some(start), Some(end)) => end - start,
+            _ => self.tick_delta() as f64 / self.source_hz,
+        }
+    }
+
+    /// Rate of change of `value` per second across the window
+    ///
+    /// Returns `None` when no time has elapsed (e.g. a repeated frame).
+    pub fn rate_of(&self, value: impl Fn(&T) -> f64) -> Option<f64> {
+        let elapsed = self.elapsed();
+        (elapsed > 0.0).then(|| (value(self.newest()) - value(self.oldest())) / elapsed)
     }
 }
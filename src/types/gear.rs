@@ -0,0 +1,64 @@
+//! Typed gear selection
+
+use std::fmt;
+
+use super::{VarData, VariableInfo};
+
+/// Selected gear, decoded from the `Gear` variable.
+///
+/// iRacing reports `-1` for reverse, `0` for neutral and `1..` for forward gears.
+/// Implements [`VarData`], so adapter fields can be declared as `gear: Gear`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum Gear {
+    Reverse,
+    #[default]
+    Neutral,
+    /// Forward gear number, starting at 1
+    Forward(u8),
+}
+
+impl Gear {
+    /// Returns true for any forward gear.
+    pub fn is_forward(self) -> bool {
+        matches!(self, Gear::Forward(_))
+    }
+}
+
+impl From<i32> for Gear {
+    fn from(value: i32) -> Self {
+        match value {
+            ..0 => Gear::Reverse,
+            0 => Gear::Neutral,
+            n => Gear::Forward(u8::try_from(n).unwrap_or(u8::MAX)),
+        }
+    }
+}
+
+impl From<Gear> for i32 {
+    fn from(gear: Gear) -> Self {
+        match gear {
+            Gear::Reverse => -1,
+            Gear::Neutral => 0,
+            Gear::Forward(n) => n as i32,
+        }
+    }
+}
+
+/// Dashboard notation: `R`, `N`, or the gear number.
+impl fmt::Display for Gear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gear::Reverse => f.write_str("R"),
+            Gear::Neutral => f.write_str("N"),
+            Gear::Forward(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+impl VarData for Gear {
+    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
+        i32::from_bytes(data, info).map(Gear::from)
+    }
+}
@@ -1,18 +1,18 @@
 //! Incident classification types for IRSDK 1.19
 
-use serde::{Deserialize, Serialize};
-
 use super::BitField;
 
 /// High-level classification of an incident: report + penalty
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IncidentClassification {
     pub report: IncidentReport,
     pub penalty: IncidentPenalty,
 }
 
 /// Discrete incident report categories from the low byte
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IncidentReport {
     NoReport,
     OutOfControl,
@@ -27,7 +27,8 @@ pub enum IncidentReport {
 }
 
 /// Discrete incident penalty magnitudes from the high byte
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IncidentPenalty {
     None,
     ZeroX,
@@ -3,15 +3,95 @@
 //! This module contains flag constants for EngineWarnings, SessionFlags, and IncidentFlags
 //! from the iRacing SDK (IRSDK 1.19).
 
-// Engine warnings (added in 1.19)
+// Engine warnings (irsdk_EngineWarnings)
 pub mod engine_warnings {
-    pub const MAND_REP_NEEDED: u32 = 0x0080; // irsdk_mandRepNeeded
-    pub const OPT_REP_NEEDED: u32 = 0x0100; // irsdk_optRepNeeded
+    pub const WATER_TEMP_WARNING: u32 = 0x0001; // irsdk_waterTempWarning
+    pub const FUEL_PRESSURE_WARNING: u32 = 0x0002; // irsdk_fuelPressureWarning
+    pub const OIL_PRESSURE_WARNING: u32 = 0x0004; // irsdk_oilPressureWarning
+    pub const ENGINE_STALLED: u32 = 0x0008; // irsdk_engineStalled
+    pub const PIT_SPEED_LIMITER: u32 = 0x0010; // irsdk_pitSpeedLimiter
+    pub const REV_LIMITER_ACTIVE: u32 = 0x0020; // irsdk_revLimiterActive
+    pub const OIL_TEMP_WARNING: u32 = 0x0040; // irsdk_oilTempWarning
+    pub const MAND_REP_NEEDED: u32 = 0x0080; // irsdk_mandRepNeeded (1.19)
+    pub const OPT_REP_NEEDED: u32 = 0x0100; // irsdk_optRepNeeded (1.19)
+
+    /// Flag names for `BitField::decode_named`, matching `EngineWarnings` fields
+    pub const NAMES: &[(u32, &str)] = &[
+        (WATER_TEMP_WARNING, "water_temp_warning"),
+        (FUEL_PRESSURE_WARNING, "fuel_pressure_warning"),
+        (OIL_PRESSURE_WARNING, "oil_pressure_warning"),
+        (ENGINE_STALLED, "engine_stalled"),
+        (PIT_SPEED_LIMITER, "pit_speed_limiter"),
+        (REV_LIMITER_ACTIVE, "rev_limiter_active"),
+        (OIL_TEMP_WARNING, "oil_temp_warning"),
+        (MAND_REP_NEEDED, "mandatory_repair_needed"),
+        (OPT_REP_NEEDED, "optional_repair_needed"),
+    ];
 }
 
-// Global session flags additions (1.19)
+// Global session flags (irsdk_Flags)
 pub mod session_flags {
-    pub const DQ_SCORING_INVALID: u32 = 0x0020_0000; // irsdk_dqScoringInvalid
+    // Global flags
+    pub const CHECKERED: u32 = 0x0000_0001; // irsdk_checkered
+    pub const WHITE: u32 = 0x0000_0002; // irsdk_white
+    pub const GREEN: u32 = 0x0000_0004; // irsdk_green
+    pub const YELLOW: u32 = 0x0000_0008; // irsdk_yellow
+    pub const RED: u32 = 0x0000_0010; // irsdk_red
+    pub const BLUE: u32 = 0x0000_0020; // irsdk_blue
+    pub const DEBRIS: u32 = 0x0000_0040; // irsdk_debris
+    pub const CROSSED: u32 = 0x0000_0080; // irsdk_crossed
+    pub const YELLOW_WAVING: u32 = 0x0000_0100; // irsdk_yellowWaving
+    pub const ONE_LAP_TO_GREEN: u32 = 0x0000_0200; // irsdk_oneLapToGreen
+    pub const GREEN_HELD: u32 = 0x0000_0400; // irsdk_greenHeld
+    pub const TEN_TO_GO: u32 = 0x0000_0800; // irsdk_tenToGo
+    pub const FIVE_TO_GO: u32 = 0x0000_1000; // irsdk_fiveToGo
+    pub const RANDOM_WAVING: u32 = 0x0000_2000; // irsdk_randomWaving
+    pub const CAUTION: u32 = 0x0000_4000; // irsdk_caution
+    pub const CAUTION_WAVING: u32 = 0x0000_8000; // irsdk_cautionWaving
+
+    // Driver black flags
+    pub const BLACK: u32 = 0x0001_0000; // irsdk_black
+    pub const DISQUALIFY: u32 = 0x0002_0000; // irsdk_disqualify
+    pub const SERVICEABLE: u32 = 0x0004_0000; // irsdk_servicible
+    pub const FURLED: u32 = 0x0008_0000; // irsdk_furled
+    pub const REPAIR: u32 = 0x0010_0000; // irsdk_repair
+    pub const DQ_SCORING_INVALID: u32 = 0x0020_0000; // irsdk_dqScoringInvalid (1.19)
+
+    // Start lights
+    pub const START_HIDDEN: u32 = 0x1000_0000; // irsdk_startHidden
+    pub const START_READY: u32 = 0x2000_0000; // irsdk_startReady
+    pub const START_SET: u32 = 0x4000_0000; // irsdk_startSet
+    pub const START_GO: u32 = 0x8000_0000; // irsdk_startGo
+
+    /// Flag names for `BitField::decode_named`, matching `SessionFlags` fields
+    pub const NAMES: &[(u32, &str)] = &[
+        (CHECKERED, "checkered"),
+        (WHITE, "white"),
+        (GREEN, "green"),
+        (YELLOW, "yellow"),
+        (RED, "red"),
+        (BLUE, "blue"),
+        (DEBRIS, "debris"),
+        (CROSSED, "crossed"),
+        (YELLOW_WAVING, "yellow_waving"),
+        (ONE_LAP_TO_GREEN, "one_lap_to_green"),
+        (GREEN_HELD, "green_held"),
+        (TEN_TO_GO, "ten_to_go"),
+        (FIVE_TO_GO, "five_to_go"),
+        (RANDOM_WAVING, "random_waving"),
+        (CAUTION, "caution"),
+        (CAUTION_WAVING, "caution_waving"),
+        (BLACK, "black"),
+        (DISQUALIFY, "disqualify"),
+        (SERVICEABLE, "serviceable"),
+        (FURLED, "furled"),
+        (REPAIR, "repair"),
+        (DQ_SCORING_INVALID, "dq_scoring_invalid"),
+        (START_HIDDEN, "start_hidden"),
+        (START_READY, "start_ready"),
+        (START_SET, "start_set"),
+        (START_GO, "start_go"),
+    ];
 }
 
 // Incident flags (1.19): combined report (low byte) + penalty (high byte)
@@ -55,25 +55,47 @@
 //! }
 //! ```
 
+mod array_view;
 mod bitfield;
+#[cfg(feature = "session")]
+mod car_state;
+mod computed;
+mod engine_warnings;
 mod frame;
+mod gear;
 mod incident;
 pub mod irsdk_flags;
+mod pit_state;
 mod schema;
+mod session_flags;
+mod spotter;
+mod track_surface;
 mod update_rate;
 mod var_data;
 mod variable_type;
 
 // Re-export all public types
+pub use array_view::ArrayView;
 pub use bitfield::{
     BitField, engine_mandatory_repair_needed, engine_optional_repair_needed,
     session_dq_scoring_invalid, tick_after_u32,
 };
-pub use frame::FramePacket;
+#[cfg(feature = "session")]
+pub use car_state::CarState;
+pub use computed::{ComputeFn, ComputedChannels};
+pub use engine_warnings::{EngineWarnings, decode_engine_warnings};
+pub use frame::{FrameMeta, FramePacket, FrameWindow, SerializableFrame};
+pub use gear::Gear;
 pub use incident::{IncidentClassification, IncidentPenalty, IncidentReport, decode_incident};
-pub use schema::{VariableInfo, VariableSchema};
+pub use pit_state::{PitState, decode_pit_state};
+#[cfg(feature = "json")]
+pub use schema::SCHEMA_JSON_VERSION;
+pub use schema::{SchemaDelta, VariableInfo, VariableSchema};
+pub use session_flags::{SessionFlags, decode_session_flags};
+pub use spotter::SpotterState;
+pub use track_surface::{TrackSurface, TrackSurfaceMaterial};
 pub use update_rate::UpdateRate;
-pub use var_data::VarData;
+pub use var_data::{VarData, from_bytes_array, from_bytes_timeseries};
 pub use variable_type::{Value, VariableType};
 
 #[cfg(test)]
@@ -307,6 +329,107 @@ mod tests {
             let flag = 1 << bit_index;
             prop_assert_eq!(bitfield.has_flag(flag), expected_bit_set);
         }
+
+        #[test]
+        fn prop_variable_type_read_decodes_and_bounds_checks(
+            data_type in prop::sample::select(vec![
+                VariableType::Char, VariableType::Int8, VariableType::UInt8,
+                VariableType::Int16, VariableType::UInt16, VariableType::Int32,
+                VariableType::UInt32, VariableType::Float32, VariableType::Float64,
+                VariableType::Bool, VariableType::BitField
+            ]),
+            offset in 0..40usize,
+            data in prop::collection::vec(any::<u8>(), 0..48)
+        ) {
+            let size = data_type.size();
+            match data_type.read(&data, offset) {
+                Ok(value) => {
+                    prop_assert!(offset + size <= data.len());
+                    let expected = expected_value(data_type, &data[offset..offset + size]);
+                    prop_assert!(same_value(&value, &expected), "{:?} != {:?}", value, expected);
+                }
+                Err(crate::TelemetryError::Memory { offset: reported, .. }) => {
+                    prop_assert!(offset + size > data.len());
+                    prop_assert_eq!(reported, offset);
+                }
+                Err(other) => prop_assert!(false, "Unexpected error {:?}", other),
+            }
+        }
+
+        #[test]
+        fn prop_frame_packet_get_value_matches_schema_type(
+            data_type in prop::sample::select(vec![
+                VariableType::Char, VariableType::Int8, VariableType::UInt8,
+                VariableType::Int16, VariableType::UInt16, VariableType::Int32,
+                VariableType::UInt32, VariableType::Float32, VariableType::Float64,
+                VariableType::Bool, VariableType::BitField
+            ]),
+            count in 1..6usize,
+            offset in 0..16usize,
+            data in prop::collection::vec(any::<u8>(), 64)
+        ) {
+            use std::collections::HashMap;
+            use std::sync::Arc;
+
+            let info = VariableInfo {
+                name: "Var".to_string(),
+                data_type,
+                offset,
+                count,
+                count_as_time: false,
+                units: String::new(),
+                description: String::new(),
+            };
+            let schema = VariableSchema::new(HashMap::from([("Var".to_string(), info)]), 64).unwrap();
+            let packet = FramePacket::new(data.clone(), 0, 0, Arc::new(schema));
+
+            let size = data_type.size();
+            let expected: Vec<Value> = (0..count)
+                .map(|i| expected_value(data_type, &data[offset + i * size..offset + (i + 1) * size]))
+                .collect();
+
+            match packet.get_value("Var").unwrap() {
+                Value::Array(values) => {
+                    prop_assert!(count > 1, "Scalars must not be wrapped in an array");
+                    prop_assert_eq!(values.len(), count);
+                    for (actual, expected) in values.iter().zip(&expected) {
+                        prop_assert!(same_value(actual, expected), "{:?} != {:?}", actual, expected);
+                    }
+                }
+                scalar => {
+                    prop_assert_eq!(count, 1);
+                    prop_assert!(same_value(&scalar, &expected[0]), "{:?} != {:?}", scalar, expected[0]);
+                }
+            }
+        }
+    }
+
+    /// Decode a single element the way the iRacing SDK lays it out
+    fn expected_value(data_type: VariableType, bytes: &[u8]) -> Value {
+        match data_type {
+            VariableType::Char => Value::Char(bytes[0]),
+            VariableType::Int8 => Value::Int8(bytes[0] as i8),
+            VariableType::UInt8 => Value::UInt8(bytes[0]),
+            VariableType::Bool => Value::Bool(bytes[0] != 0),
+            VariableType::Int16 => Value::Int16(i16::from_le_bytes(bytes.try_into().unwrap())),
+            VariableType::UInt16 => Value::UInt16(u16::from_le_bytes(bytes.try_into().unwrap())),
+            VariableType::Int32 => Value::Int32(i32::from_le_bytes(bytes.try_into().unwrap())),
+            VariableType::UInt32 => Value::UInt32(u32::from_le_bytes(bytes.try_into().unwrap())),
+            VariableType::Float32 => Value::Float32(f32::from_le_bytes(bytes.try_into().unwrap())),
+            VariableType::Float64 => Value::Float64(f64::from_le_bytes(bytes.try_into().unwrap())),
+            VariableType::BitField => {
+                Value::BitField(BitField::new(u32::from_le_bytes(bytes.try_into().unwrap())))
+            }
+        }
+    }
+
+    /// Value equality that treats floats bitwise so NaN payloads compare equal
+    fn same_value(a: &Value, b: &Value) -> bool {
+        match (a, b) {
+            (Value::Float32(a), Value::Float32(b)) => a.to_bits() == b.to_bits(),
+            (Value::Float64(a), Value::Float64(b)) => a.to_bits() == b.to_bits(),
+            _ => a == b,
+        }
     }
 
     // Unit tests for trivial constructors and pure functions
@@ -325,12 +448,175 @@ mod tests {
         assert_eq!(VariableType::Float64.size(), 8);
     }
 
+    #[test]
+    fn from_bytes_array_reads_consecutive_elements() {
+        let values = [0.25f32, 0.5, 0.75];
+        let mut data = vec![0u8; 4];
+        for value in values {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        let info = VariableInfo {
+            name: "CarIdxLapDistPct".to_string(),
+            data_type: VariableType::Float32,
+            offset: 4,
+            count: 3,
+            count_as_time: false,
+            units: "%".to_string(),
+            description: String::new(),
+        };
+
+        assert_eq!(from_bytes_array::<f32>(&data, &info).unwrap(), values);
+        assert!(matches!(
+            from_bytes_array::<i32>(&data, &info),
+            Err(crate::TelemetryError::TypeConversion { .. })
+        ));
+        assert!(matches!(
+            from_bytes_array::<f32>(&data[..12], &info),
+            Err(crate::TelemetryError::Memory { .. })
+        ));
+    }
+
+    #[test]
+    fn array_view_decodes_elements_in_place() {
+        use std::sync::Arc;
+
+        let values = [0.25f32, 0.5, 0.75];
+        let mut data = vec![0u8; 4];
+        for value in values {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        let info = VariableInfo {
+            name: "CarIdxLapDistPct".to_string(),
+            data_type: VariableType::Float32,
+            offset: 4,
+            count: 3,
+            count_as_time: false,
+            units: "%".to_string(),
+            description: String::new(),
+        };
+        let data: Arc<[u8]> = data.into();
+
+        let view = ArrayView::<f32>::new(Arc::clone(&data), &info).unwrap();
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.get(1), Some(0.5));
+        assert_eq!(view.get(3), None);
+        assert_eq!(view.to_vec(), from_bytes_array::<f32>(&data, &info).unwrap());
+        assert_eq!(view.as_bytes(), &data[4..]);
+        assert_eq!(view.iter().sum::<f32>(), 1.5);
+
+        assert!(matches!(
+            ArrayView::<i32>::new(Arc::clone(&data), &info),
+            Err(crate::TelemetryError::TypeConversion { .. })
+        ));
+        assert!(matches!(
+            ArrayView::<f32>::new(data[..12].into(), &info),
+            Err(crate::TelemetryError::Memory { .. })
+        ));
+    }
+
+    #[test]
+    fn timeseries_samples_are_spread_across_one_tick() {
+        let samples = [0.1f32, 0.2, 0.3, 0.4, 0.5, 0.6];
+        let data: Vec<u8> = samples.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut info = VariableInfo {
+            name: "LatAccel_ST".to_string(),
+            data_type: VariableType::Float32,
+            offset: 0,
+            count: 6,
+            count_as_time: true,
+            units: "m/s^2".to_string(),
+            description: String::new(),
+        };
+
+        let series = from_bytes_timeseries::<f32>(&data, &info, 60.0).unwrap();
+        assert_eq!(series.len(), 6);
+        for (i, (offset, value)) in series.iter().enumerate() {
+            assert!((offset - i as f64 / 360.0).abs() < 1e-12);
+            assert_eq!(*value, samples[i]);
+        }
+
+        assert!(from_bytes_timeseries::<f32>(&data, &info, 0.0).is_err());
+        info.count_as_time = false;
+        assert!(!info.is_timeseries());
+        assert!(matches!(
+            from_bytes_timeseries::<f32>(&data, &info, 60.0),
+            Err(crate::TelemetryError::TypeConversion { .. })
+        ));
+    }
+
+    #[test]
+    fn fixed_array_requires_enough_elements() {
+        let data: Vec<u8> = [1i32, 2, 3, 4].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let info = VariableInfo {
+            name: "CarIdxGear".to_string(),
+            data_type: VariableType::Int32,
+            offset: 0,
+            count: 4,
+            count_as_time: false,
+            units: String::new(),
+            description: String::new(),
+        };
+
+        assert_eq!(<[i32; 3]>::from_bytes(&data, &info).unwrap(), [1, 2, 3]);
+        assert_eq!(<[i32; 4]>::from_bytes(&data, &info).unwrap(), [1, 2, 3, 4]);
+        assert!(matches!(
+            <[i32; 5]>::from_bytes(&data, &info),
+            Err(crate::TelemetryError::TypeConversion { .. })
+        ));
+    }
+
+    #[test]
+    fn string_reads_nul_terminated_char_array() {
+        let mut data = vec![0xFFu8; 2];
+        data.extend_from_slice(b"Lime Rock\0junk");
+        let mut info = VariableInfo {
+            name: "TrackName".to_string(),
+            data_type: VariableType::Char,
+            offset: 2,
+            count: 14,
+            count_as_time: false,
+            units: String::new(),
+            description: String::new(),
+        };
+
+        assert_eq!(String::from_bytes(&data, &info).unwrap(), "Lime Rock");
+
+        info.count = 4;
+        assert_eq!(String::from_bytes(&data, &info).unwrap(), "Lime");
+
+        info.count = 15;
+        assert!(matches!(
+            String::from_bytes(&data, &info),
+            Err(crate::TelemetryError::Memory { .. })
+        ));
+
+        info.data_type = VariableType::Int8;
+        assert!(matches!(
+            String::from_bytes(&data, &info),
+            Err(crate::TelemetryError::TypeConversion { .. })
+        ));
+    }
+
     #[test]
     fn bitfield_constructor_works() {
         let bitfield = BitField::new(0x12345678);
         assert_eq!(bitfield.value(), 0x12345678);
     }
 
+    #[test]
+    fn bitfield_set_bits_and_named_flags() {
+        use irsdk_flags::{engine_warnings, session_flags};
+
+        let bits =
+            BitField::new(session_flags::GREEN | session_flags::BLUE | session_flags::START_GO);
+        assert_eq!(bits.set_bits().collect::<Vec<_>>(), vec![2, 5, 31]);
+        assert_eq!(bits.decode_named(session_flags::NAMES), vec!["green", "blue", "start_go"]);
+
+        let warnings = BitField::new(engine_warnings::PIT_SPEED_LIMITER);
+        assert_eq!(warnings.decode_named(engine_warnings::NAMES), vec!["pit_speed_limiter"]);
+        assert!(BitField::new(0).decode_named(session_flags::NAMES).is_empty());
+    }
+
     #[test]
     fn bitfield_flag_operations_basic() {
         let bitfield = BitField::new(0b1010);
@@ -362,6 +648,27 @@ mod tests {
         assert!(matches!(decoded.penalty, IncidentPenalty::ZeroX));
     }
 
+    #[test]
+    fn test_session_flags_decoding() {
+        use crate::irsdk_flags::session_flags as sf;
+
+        let green = decode_session_flags(BitField::new(sf::GREEN | sf::START_GO));
+        assert!(green.green && green.start_go);
+        assert!(green.is_green());
+        assert!(!green.is_caution());
+
+        let caution = decode_session_flags(BitField::new(sf::GREEN | sf::CAUTION_WAVING));
+        assert!(caution.is_caution());
+        assert!(caution.is_yellow());
+        assert!(!caution.is_green(), "Caution overrides green");
+
+        let black = decode_session_flags(BitField::new(sf::BLACK | sf::DQ_SCORING_INVALID));
+        assert!(black.has_black_flag());
+        assert!(black.dq_scoring_invalid);
+
+        assert_eq!(decode_session_flags(BitField::new(0)), SessionFlags::default());
+    }
+
     #[test]
     fn test_engine_warnings_new_bits_present() {
         use crate::irsdk_flags::engine_warnings as ew;
@@ -370,6 +677,52 @@ mod tests {
         assert!(flags.has_flag(ew::OPT_REP_NEEDED));
     }
 
+    #[test]
+    fn test_engine_warnings_decoding() {
+        use crate::irsdk_flags::engine_warnings as ew;
+
+        let warnings =
+            decode_engine_warnings(BitField::new(ew::OIL_PRESSURE_WARNING | ew::PIT_SPEED_LIMITER));
+        assert!(warnings.oil_pressure_warning);
+        assert!(warnings.pit_speed_limiter);
+        assert!(!warnings.engine_stalled);
+        assert!(warnings.has_warning_light());
+        assert!(!warnings.repair_needed());
+
+        let repair = decode_engine_warnings(BitField::new(ew::MAND_REP_NEEDED));
+        assert!(repair.mandatory_repair_needed);
+        assert!(!repair.optional_repair_needed);
+        assert!(repair.repair_needed());
+        assert!(!repair.has_warning_light());
+    }
+
+    #[test]
+    fn test_pit_state_decoding() {
+        use crate::irsdk_flags::{engine_warnings as ew, session_flags as sf};
+
+        let entering = decode_pit_state(
+            BitField::new(ew::PIT_SPEED_LIMITER | ew::OPT_REP_NEEDED),
+            BitField::new(sf::GREEN | sf::SERVICEABLE),
+            true,
+        );
+        assert!(entering.on_pit_road && entering.speed_limiter && entering.serviceable);
+        assert!(entering.optional_repair && entering.repair_needed());
+        assert!(!entering.must_pit());
+        assert!(!entering.limiter_off_on_pit_road());
+
+        let meatball = decode_pit_state(BitField::new(0), BitField::new(sf::REPAIR), false);
+        assert!(meatball.mandatory_repair && meatball.must_pit());
+
+        let penalty = decode_pit_state(BitField::new(0), BitField::new(sf::BLACK), true);
+        assert!(penalty.penalty_pending && penalty.must_pit());
+        assert!(penalty.limiter_off_on_pit_road());
+
+        assert_eq!(
+            decode_pit_state(BitField::new(0), BitField::new(0), false),
+            PitState::default()
+        );
+    }
+
     #[test]
     fn test_engine_repair_helpers() {
         use crate::irsdk_flags::engine_warnings as ew;
@@ -389,4 +742,439 @@ mod tests {
         let none = BitField::new(0);
         assert!(!session_dq_scoring_invalid(none));
     }
+
+    #[test]
+    fn test_schema_diff_reports_layout_changes() {
+        use crate::test_utils::test_schema;
+
+        let old = test_schema(
+            &[
+                ("Speed", VariableType::Float32, 0, 1),
+                ("RPM", VariableType::Float32, 4, 1),
+                ("DRS_Status", VariableType::Int32, 8, 1),
+            ],
+            12,
+        );
+        let new = test_schema(
+            &[
+                ("Speed", VariableType::Float32, 0, 1),
+                ("RPM", VariableType::Float32, 8, 1),
+                ("P2P_Count", VariableType::Int32, 4, 1),
+            ],
+            16,
+        );
+
+        let delta = old.diff(&new);
+        assert_eq!(delta.added, vec!["P2P_Count".to_string()]);
+        assert_eq!(delta.removed, vec!["DRS_Status".to_string()]);
+        assert_eq!(delta.changed, vec!["RPM".to_string()]);
+        assert!(delta.frame_size_changed);
+        assert!(delta.touches("RPM"));
+        assert!(!delta.touches("Speed"));
+
+        assert!(old.diff(&old.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_gear_decoding() {
+        assert_eq!(Gear::from(-1), Gear::Reverse);
+        assert_eq!(Gear::from(0), Gear::Neutral);
+        assert_eq!(Gear::from(4), Gear::Forward(4));
+        assert_eq!(i32::from(Gear::Reverse), -1);
+        assert_eq!(Gear::Reverse.to_string(), "R");
+        assert_eq!(Gear::Forward(6).to_string(), "6");
+
+        let info = VariableInfo {
+            name: "Gear".to_string(),
+            data_type: VariableType::Int32,
+            offset: 4,
+            count: 1,
+            count_as_time: false,
+            units: String::new(),
+            description: String::new(),
+        };
+        let mut data = vec![0u8; 8];
+        data[4..8].copy_from_slice(&(-1i32).to_le_bytes());
+        assert_eq!(Gear::from_bytes(&data, &info).unwrap(), Gear::Reverse);
+
+        let float_info = VariableInfo { data_type: VariableType::Float32, ..info };
+        assert!(Gear::from_bytes(&data, &float_info).is_err());
+    }
+
+    #[test]
+    fn test_track_surface_decoding() {
+        assert_eq!(TrackSurface::from(-1), TrackSurface::NotInWorld);
+        assert_eq!(TrackSurface::from(2), TrackSurface::ApproachingPits);
+        assert_eq!(TrackSurface::from(9), TrackSurface::Unknown(9));
+        assert!(TrackSurface::from(1).is_on_pit_road());
+        assert!(TrackSurface::from(3).is_on_track());
+        assert!(!TrackSurface::from(0).is_on_pit_road());
+
+        assert_eq!(TrackSurfaceMaterial::from(2), TrackSurfaceMaterial::Asphalt(2));
+        assert_eq!(TrackSurfaceMaterial::from(14), TrackSurfaceMaterial::Rumble(4));
+        assert_eq!(TrackSurfaceMaterial::from(25), TrackSurfaceMaterial::Gravel(2));
+        assert_eq!(TrackSurfaceMaterial::from(27), TrackSurfaceMaterial::Astroturf);
+        assert_eq!(TrackSurfaceMaterial::from(40), TrackSurfaceMaterial::Unknown(40));
+        assert!(TrackSurfaceMaterial::from(5).is_paved());
+        assert!(TrackSurfaceMaterial::from(16).is_run_off());
+        assert!(!TrackSurfaceMaterial::from(7).is_run_off(), "Racing dirt is a racing surface");
+
+        let info = VariableInfo {
+            name: "PlayerTrackSurface".to_string(),
+            data_type: VariableType::Int32,
+            offset: 0,
+            count: 1,
+            count_as_time: false,
+            units: "irsdk_TrkLoc".to_string(),
+            description: String::new(),
+        };
+        let surface = TrackSurface::from_bytes(&1i32.to_le_bytes(), &info).unwrap();
+        assert_eq!(surface, TrackSurface::InPitStall);
+    }
+
+    #[test]
+    fn test_spotter_state_decoding() {
+        assert_eq!(SpotterState::from(0), SpotterState::Off);
+        assert_eq!(SpotterState::from(4), SpotterState::CarLeftRight);
+        assert_eq!(SpotterState::from(9), SpotterState::Unknown(9));
+        assert!(SpotterState::from(5).has_car_left());
+        assert!(!SpotterState::from(5).has_car_right());
+        assert!(SpotterState::from(4).has_car_left() && SpotterState::from(4).has_car_right());
+        assert!(!SpotterState::Clear.has_car_left() && !SpotterState::Clear.has_car_right());
+
+        let info = VariableInfo {
+            name: "CarLeftRight".to_string(),
+            data_type: VariableType::Int32,
+            offset: 0,
+            count: 1,
+            count_as_time: false,
+            units: "irsdk_CarLeftRight".to_string(),
+            description: String::new(),
+        };
+        let spotter = SpotterState::from_bytes(&6i32.to_le_bytes(), &info).unwrap();
+        assert_eq!(spotter, SpotterState::TwoCarsRight);
+    }
+
+    #[test]
+    fn test_schema_overlap_names_colliding_variables() {
+        use crate::TelemetryError;
+        use crate::test_utils::test_schema;
+
+        let packed = test_schema(
+            &[
+                ("Speed", VariableType::Float32, 0, 1),
+                ("CarIdxLapDistPct", VariableType::Float32, 4, 4),
+                ("Gear", VariableType::Int32, 20, 1),
+            ],
+            24,
+        );
+        assert!(packed.validate_no_overlap().is_ok());
+
+        // Gear sits inside the array, which is only caught against the array's end
+        let overlapping = test_schema(
+            &[
+                ("Speed", VariableType::Float32, 0, 1),
+                ("CarIdxLapDistPct", VariableType::Float32, 4, 4),
+                ("Gear", VariableType::Int32, 8, 1),
+                ("RPM", VariableType::Float32, 16, 1),
+            ],
+            24,
+        );
+        match overlapping.validate_no_overlap() {
+            Err(TelemetryError::SchemaValidation { reason, .. }) => {
+                assert!(reason.contains("'CarIdxLapDistPct' and 'Gear'"), "{}", reason);
+            }
+            other => panic!("expected overlap error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_frame_packet_outlives_its_source() {
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        fn assert_detachable<T: Clone + Send + Sync + 'static>() {}
+        assert_detachable::<FramePacket>();
+
+        let info = VariableInfo {
+            name: "Speed".to_string(),
+            data_type: VariableType::Float32,
+            offset: 0,
+            count: 1,
+            count_as_time: false,
+            units: "m/s".to_string(),
+            description: String::new(),
+        };
+        let schema =
+            Arc::new(VariableSchema::new(HashMap::from([("Speed".to_string(), info)]), 4).unwrap());
+
+        let buffered: Vec<FramePacket> = (0..3u32)
+            .map(|tick| {
+                FramePacket::new((tick as f32).to_le_bytes().to_vec(), tick, 0, Arc::clone(&schema))
+            })
+            .collect();
+        drop(schema);
+
+        let speeds = std::thread::spawn(move || {
+            buffered.iter().map(|packet| packet.get_f32("Speed").unwrap()).collect::<Vec<_>>()
+        })
+        .join()
+        .unwrap();
+        assert_eq!(speeds, vec![0.0, 1.0, 2.0]);
+    }
+
+    #[cfg(feature = "session")]
+    #[test]
+    fn test_per_car_view_joins_car_arrays_with_drivers() {
+        use crate::schema::session::{Driver, DriverInfoData};
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let array = |name: &str, data_type, offset| {
+            let info = VariableInfo {
+                name: name.to_string(),
+                data_type,
+                offset,
+                count: 3,
+                count_as_time: false,
+                units: String::new(),
+                description: String::new(),
+            };
+            (name.to_string(), info)
+        };
+        let schema = VariableSchema::new(
+            HashMap::from([
+                array("CarIdxPosition", VariableType::Int32, 0),
+                array("CarIdxLapDistPct", VariableType::Float32, 12),
+                array("CarIdxTrackSurface", VariableType::Int32, 24),
+                array("CarIdxOnPitRoad", VariableType::Bool, 36),
+            ]),
+            40,
+        )
+        .unwrap();
+
+        let mut data = vec![0u8; 40];
+        for (car, (position, dist, surface)) in
+            [(2i32, 0.25f32, 3i32), (0, 0.0, -1), (1, 0.75, 1)].into_iter().enumerate()
+        {
+            data[car * 4..car * 4 + 4].copy_from_slice(&position.to_le_bytes());
+            data[12 + car * 4..16 + car * 4].copy_from_slice(&dist.to_le_bytes());
+            data[24 + car * 4..28 + car * 4].copy_from_slice(&surface.to_le_bytes());
+        }
+        data[38] = 1;
+        let packet = FramePacket::new(data, 1, 1, Arc::new(schema));
+
+        let driver = |car_idx: i32| Driver { car_idx, ..Default::default() };
+        let driver_info = DriverInfoData {
+            drivers: Some(vec![driver(2), driver(0), driver(1), driver(7)]),
+            ..Default::default()
+        };
+
+        let cars = packet.per_car_view(&driver_info);
+        let indices: Vec<usize> = cars.iter().map(|car| car.car_idx).collect();
+        assert_eq!(indices, vec![0, 2, 7], "Car 1 is not in the world");
+
+        assert_eq!(cars[0].position, Some(2));
+        assert_eq!(cars[0].track_surface, Some(TrackSurface::OnTrack));
+        assert_eq!(cars[1].lap_dist_pct, Some(0.75));
+        assert_eq!(cars[1].on_pit_road, Some(true));
+        assert_eq!(cars[1].driver.car_idx, 2);
+        assert_eq!(cars[0].gear, None, "Frame has no CarIdxGear");
+        assert_eq!((cars[2].position, cars[2].track_surface), (None, None), "Car 7 has no slot");
+    }
+
+    #[cfg(feature = "session")]
+    #[test]
+    fn test_per_class_standings_orders_each_class() {
+        use crate::schema::session::{Driver, DriverInfoData};
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let info = VariableInfo {
+            name: "CarIdxClassPosition".to_string(),
+            data_type: VariableType::Int32,
+            offset: 0,
+            count: 6,
+            count_as_time: false,
+            units: String::new(),
+            description: String::new(),
+        };
+        let schema =
+            VariableSchema::new(HashMap::from([("CarIdxClassPosition".to_string(), info)]), 24)
+                .unwrap();
+        let data: Vec<u8> = [0i32, 2, 1, 0, 1, 2].iter().flat_map(|p| p.to_le_bytes()).collect();
+        let packet = FramePacket::new(data, 1, 1, Arc::new(schema));
+
+        let driver = |car_idx: i32, class: i32| Driver {
+            car_idx,
+            car_class_id: Some(class),
+            ..Default::default()
+        };
+        let driver_info = DriverInfoData {
+            pace_car_idx: Some(0),
+            drivers: Some(vec![
+                driver(0, 11),
+                driver(1, 4029),
+                driver(2, 4029),
+                driver(3, 4029),
+                driver(4, 2523),
+                driver(5, 2523),
+            ]),
+            ..Default::default()
+        };
+
+        let standings = packet.per_class_standings(&driver_info);
+        let order = |class: i32| -> Vec<usize> {
+            standings[&class].iter().map(|car| car.car_idx).collect()
+        };
+
+        assert_eq!(standings.len(), 2, "The pace car's class is dropped");
+        assert_eq!(order(4029), vec![2, 1, 3], "Unclassified car 3 comes last");
+        assert_eq!(order(2523), vec![4, 5]);
+        assert_eq!(standings[&2523][1].class_position, Some(2));
+    }
+
+    #[test]
+    fn test_frame_packet_typed_getters() {
+        use crate::TelemetryError;
+        use crate::test_utils::{PacketBuilder, test_schema};
+        use std::sync::Arc;
+
+        let schema = Arc::new(test_schema(
+            &[
+                ("Speed", VariableType::Float32, 0, 1),
+                ("Gear", VariableType::Int32, 4, 1),
+                ("SessionTime", VariableType::Float64, 8, 1),
+                ("IsOnTrack", VariableType::Bool, 16, 1),
+                ("SessionFlags", VariableType::BitField, 20, 1),
+            ],
+            24,
+        ));
+        let packet = PacketBuilder::new(&schema)
+            .set("Speed", 42.5f32.to_le_bytes())
+            .set("Gear", 3i32.to_le_bytes())
+            .set("SessionTime", 12.25f64.to_le_bytes())
+            .set("IsOnTrack", [1])
+            .set("SessionFlags", 0x4u32.to_le_bytes())
+            .build(7);
+
+        assert_eq!(packet.get_f32("Speed").unwrap(), 42.5);
+        assert_eq!(packet.get_i32("Gear").unwrap(), 3);
+        assert_eq!(packet.get_f64("SessionTime").unwrap(), 12.25);
+        assert!(packet.get_bool("IsOnTrack").unwrap());
+        assert_eq!(packet.get_bitfield("SessionFlags").unwrap().value(), 0x4);
+
+        assert!(matches!(
+            packet.get_f32("Missing"),
+            Err(TelemetryError::FieldNotFound { field }) if field == "Missing"
+        ));
+        assert!(matches!(packet.get_i32("Speed"), Err(TelemetryError::TypeConversion { .. })));
+
+        let map = packet.to_map();
+        assert_eq!(map.len(), 5);
+        assert_eq!(map["Speed"], Value::Float32(42.5));
+        assert_eq!(map["Gear"], Value::Int32(3));
+        assert_eq!(map["IsOnTrack"], Value::Bool(true));
+    }
+
+    #[test]
+    fn test_frame_packet_evaluates_computed_channels() {
+        use crate::TelemetryError;
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let speed = VariableInfo {
+            name: "Speed".to_string(),
+            data_type: VariableType::Float32,
+            offset: 0,
+            count: 1,
+            count_as_time: false,
+            units: "m/s".to_string(),
+            description: String::new(),
+        };
+        let schema = VariableSchema::new(HashMap::from([("Speed".to_string(), speed)]), 4).unwrap();
+        let packet = FramePacket::new(10.0f32.to_le_bytes().to_vec(), 1, 1, Arc::new(schema));
+
+        let mut computed = ComputedChannels::new();
+        computed.insert("SpeedKph", |frame: &FramePacket| {
+            Value::Float32(frame.get_f32("Speed").unwrap_or(0.0) * 3.6)
+        });
+        computed.insert("DoubleKph", |frame: &FramePacket| match frame.get_value("SpeedKph") {
+            Ok(Value::Float32(kph)) => Value::Float32(kph * 2.0),
+            _ => Value::Float32(0.0),
+        });
+
+        assert!(matches!(packet.get_value("SpeedKph"), Err(TelemetryError::FieldNotFound { .. })));
+
+        let packet = packet.with_computed(Arc::new(computed));
+        assert_eq!(packet.get_value("SpeedKph").unwrap(), Value::Float32(36.0));
+        assert_eq!(packet.get_value("DoubleKph").unwrap(), Value::Float32(72.0));
+        assert_eq!(packet.get_value("Speed").unwrap(), Value::Float32(10.0));
+        assert!(matches!(packet.get_value("Missing"), Err(TelemetryError::FieldNotFound { .. })));
+
+        let map = packet.to_map();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["SpeedKph"], Value::Float32(36.0));
+    }
+
+    #[test]
+    fn test_computed_channel_cycles_fail_instead_of_recursing() {
+        use crate::TelemetryError;
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let schema = Arc::new(VariableSchema::new(HashMap::new(), 0).unwrap());
+        let read = |name: &'static str| {
+            move |frame: &FramePacket| frame.get_value(name).unwrap_or(Value::Int32(0))
+        };
+
+        let mut computed = ComputedChannels::new();
+        computed.insert("Echo", read("Echo"));
+        computed.insert("Ping", read("Pong"));
+        computed.insert("Pong", read("Ping"));
+        computed.insert("Answer", |_: &FramePacket| Value::Int32(42));
+        computed.insert("Relay", read("Answer"));
+        let packet = FramePacket::new(Vec::new(), 1, 1, schema).with_computed(Arc::new(computed));
+
+        assert!(matches!(packet.get_value("Echo"), Err(TelemetryError::Parse { .. })));
+        let error = packet.get_value("Ping").unwrap_err().to_string();
+        assert!(error.contains("Ping -> Pong -> Ping"), "{}", error);
+
+        // A failed evaluation leaves nothing behind for the next read
+        assert_eq!(packet.get_value("Relay").unwrap(), Value::Int32(42));
+        assert_eq!(packet.computed().map(ComputedChannels::len), Some(5));
+        assert_eq!(packet.to_map().len(), 2);
+    }
+
+    #[cfg(feature = "session")]
+    #[test]
+    fn test_serializable_frame_round_trips_packet() {
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let info = VariableInfo {
+            name: "Speed".to_string(),
+            data_type: VariableType::Float32,
+            offset: 0,
+            count: 1,
+            count_as_time: false,
+            units: "m/s".to_string(),
+            description: String::new(),
+        };
+        let schema = VariableSchema::new(HashMap::from([("Speed".to_string(), info)]), 4).unwrap();
+        let packet = FramePacket::new(31.5f32.to_le_bytes().to_vec(), 120, 3, Arc::new(schema));
+
+        let yaml = serde_yaml_ng::to_string(&SerializableFrame::from(&packet)).unwrap();
+        let saved: SerializableFrame = serde_yaml_ng::from_str(&yaml).unwrap();
+        let restored = FramePacket::try_from(saved.clone()).unwrap();
+
+        assert_eq!(restored.meta(), packet.meta());
+        assert_eq!(restored.data, packet.data);
+        assert_eq!(restored.get_f32("Speed").unwrap(), 31.5);
+
+        // Schemas are validated when the packet is rebuilt
+        let mut corrupt = saved;
+        corrupt.schema.variables.get_mut("Speed").unwrap().count = 0;
+        assert!(FramePacket::try_from(corrupt).is_err());
+    }
 }
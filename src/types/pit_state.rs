@@ -0,0 +1,54 @@
+//! Pit state composed from EngineWarnings and SessionFlags
+
+use super::{BitField, decode_engine_warnings, decode_session_flags};
+
+/// Pit-related state of the player's car
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct PitState {
+    /// Car is between the pit cones (from `OnPitRoad`)
+    pub on_pit_road: bool,
+    /// Pit speed limiter is engaged
+    pub speed_limiter: bool,
+    /// Repairs must be completed before rejoining (engine warning or meatball flag)
+    pub mandatory_repair: bool,
+    /// Optional repairs are available
+    pub optional_repair: bool,
+    /// Car may be serviced in the pits
+    pub serviceable: bool,
+    /// A black flag penalty must be served in the pits
+    pub penalty_pending: bool,
+}
+
+impl PitState {
+    /// The car has to come in, for mandatory repairs or a penalty
+    pub fn must_pit(&self) -> bool {
+        self.mandatory_repair || self.penalty_pending
+    }
+
+    /// Any repair (mandatory or optional) is waiting
+    pub fn repair_needed(&self) -> bool {
+        self.mandatory_repair || self.optional_repair
+    }
+
+    /// On pit road without the speed limiter, risking a speeding penalty
+    pub fn limiter_off_on_pit_road(&self) -> bool {
+        self.on_pit_road && !self.speed_limiter
+    }
+}
+
+/// Combine `EngineWarnings`, `SessionFlags` and `OnPitRoad` into a [`PitState`]
+pub fn decode_pit_state(engine: BitField, session: BitField, on_pit_road: bool) -> PitState {
+    let warnings = decode_engine_warnings(engine);
+    let flags = decode_session_flags(session);
+
+    PitState {
+        on_pit_road,
+        speed_limiter: warnings.pit_speed_limiter,
+        mandatory_repair: warnings.mandatory_repair_needed || flags.repair,
+        optional_repair: warnings.optional_repair_needed,
+        serviceable: flags.serviceable,
+        penalty_pending: flags.black,
+    }
+}
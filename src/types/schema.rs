@@ -1,12 +1,12 @@
 //! Telemetry variable schema types
 
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::VariableType;
 
 /// Schema describing the structure and metadata of telemetry variables.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
 pub struct VariableSchema {
     /// Map of variable names to their metadata (provides O(1) lookup)
@@ -58,6 +58,46 @@ impl VariableSchema {
         Ok(())
     }
 
+    /// Check that no two variables occupy the same bytes of the frame.
+    ///
+    /// Not part of [`VariableSchema::validate`]; schemas read from IBT files and
+    /// shared memory run it after construction. Overlapping
+    /// `[offset, offset + size * count)` ranges mean the header was corrupt or
+    /// misread, and reads would silently return garbage.
+    pub fn validate_no_overlap(&self) -> crate::Result<()> {
+        let mut ranges: Vec<(usize, usize, &str)> = self
+            .variables
+            .values()
+            .map(|info| {
+                let end = info.offset + info.data_type.size() * info.count;
+                (info.offset, end, info.name.as_str())
+            })
+            .collect();
+        ranges.sort_unstable();
+
+        // Track the furthest-reaching range seen so far so a long array that
+        // spans several later variables is still caught.
+        let mut furthest: Option<(usize, &str)> = None;
+        for (start, end, name) in ranges {
+            match furthest {
+                Some((furthest_end, furthest_name)) if start < furthest_end => {
+                    return Err(crate::TelemetryError::schema_validation_error(
+                        format!(
+                            "Variables '{}' and '{}' overlap at offset {}",
+                            furthest_name, name, start
+                        ),
+                        None,
+                        None,
+                    ));
+                }
+                Some((furthest_end, _)) if end <= furthest_end => {}
+                _ => furthest = Some((end, name)),
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get variable info by name (O(1) lookup).
     pub fn get_variable(&self, name: &str) -> Option<&VariableInfo> {
         self.variables.get(name)
@@ -72,10 +112,201 @@ impl VariableSchema {
     pub fn variable_count(&self) -> usize {
         self.variables.len()
     }
+
+    /// Get all variables sorted by name.
+    pub fn sorted_variables(&self) -> Vec<&VariableInfo> {
+        let mut variables: Vec<&VariableInfo> = self.variables.values().collect();
+        variables.sort_by(|a, b| a.name.cmp(&b.name));
+        variables
+    }
+
+    /// Compare this schema's layout against a newer one.
+    ///
+    /// Only `offset`, `data_type` and `count` are compared; units and descriptions
+    /// do not affect extraction. Returned names are sorted.
+    pub fn diff(&self, other: &VariableSchema) -> SchemaDelta {
+        let mut delta = SchemaDelta {
+            frame_size_changed: self.frame_size != other.frame_size,
+            ..Default::default()
+        };
+
+        for (name, old) in &self.variables {
+            match other.variables.get(name) {
+                None => delta.removed.push(name.clone()),
+                Some(new)
+                    if old.offset != new.offset
+                        || old.data_type != new.data_type
+                        || old.count != new.count =>
+                {
+                    delta.changed.push(name.clone())
+                }
+                Some(_) => {}
+            }
+        }
+        delta.added = other
+            .variables
+            .keys()
+            .filter(|name| !self.variables.contains_key(*name))
+            .cloned()
+            .collect();
+
+        delta.added.sort();
+        delta.removed.sort();
+        delta.changed.sort();
+        delta
+    }
+}
+
+/// Layout version written by [`VariableSchema::to_json`]
+#[cfg(feature = "json")]
+pub const SCHEMA_JSON_VERSION: u32 = 1;
+
+/// On-disk shape of an exported schema
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+struct SchemaJson {
+    version: u32,
+    frame_size: usize,
+    variables: Vec<VariableJson>,
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+struct VariableJson {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: VariableType,
+    offset: usize,
+    count: usize,
+    #[serde(default)]
+    count_as_time: bool,
+    #[serde(default)]
+    units: String,
+    #[serde(default)]
+    description: String,
+}
+
+#[cfg(feature = "json")]
+impl VariableSchema {
+    /// Export the schema as versioned JSON for tooling and snapshot tests.
+    ///
+    /// Variables are listed sorted by name so the output is stable across runs:
+    /// `{ "version": 1, "frame_size": .., "variables": [{ "name", "type", "offset",
+    /// "count", "count_as_time", "units", "description" }] }`.
+    pub fn to_json(&self) -> serde_json::Value {
+        let variables: Vec<serde_json::Value> = self
+            .sorted_variables()
+            .into_iter()
+            .map(|info| {
+                serde_json::json!({
+                    "name": info.name,
+                    "type": info.data_type,
+                    "offset": info.offset,
+                    "count": info.count,
+                    "count_as_time": info.count_as_time,
+                    "units": info.units,
+                    "description": info.description,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "version": SCHEMA_JSON_VERSION,
+            "frame_size": self.frame_size,
+            "variables": variables,
+        })
+    }
+
+    /// Export the schema like [`to_json`](Self::to_json), listing `computed`
+    /// channel names under a separate `"computed"` key.
+    ///
+    /// Computed channels have no layout in the frame, so they are kept apart from
+    /// `"variables"`; [`from_json`](Self::from_json) ignores them.
+    pub fn to_json_with_computed(&self, computed: &super::ComputedChannels) -> serde_json::Value {
+        let mut json = self.to_json();
+        json["computed"] = computed.names().collect();
+        json
+    }
+
+    /// Rebuild a schema from [`to_json`](Self::to_json) output.
+    ///
+    /// The result is validated like a schema read from an IBT file; unknown layout
+    /// versions and malformed documents are parse errors.
+    pub fn from_json(value: &serde_json::Value) -> crate::Result<Self> {
+        let document = <SchemaJson as serde::Deserialize>::deserialize(value).map_err(|e| {
+            crate::TelemetryError::Parse {
+                context: "Schema JSON".to_string(),
+                details: e.to_string(),
+            }
+        })?;
+        if document.version != SCHEMA_JSON_VERSION {
+            return Err(crate::TelemetryError::Parse {
+                context: "Schema JSON".to_string(),
+                details: format!(
+                    "Unsupported schema version {} (expected {})",
+                    document.version, SCHEMA_JSON_VERSION
+                ),
+            });
+        }
+
+        let variables = document
+            .variables
+            .into_iter()
+            .map(|var| {
+                let info = VariableInfo {
+                    name: var.name.clone(),
+                    data_type: var.data_type,
+                    offset: var.offset,
+                    count: var.count,
+                    count_as_time: var.count_as_time,
+                    units: var.units,
+                    description: var.description,
+                };
+                (var.name, info)
+            })
+            .collect();
+
+        let schema = Self::new(variables, document.frame_size)?;
+        schema.validate_no_overlap()?;
+        Ok(schema)
+    }
+}
+
+/// Layout differences between two schemas, produced by [`VariableSchema::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct SchemaDelta {
+    /// Variables only present in the newer schema
+    pub added: Vec<String>,
+    /// Variables only present in the older schema
+    pub removed: Vec<String>,
+    /// Variables whose offset, data type or count changed
+    pub changed: Vec<String>,
+    /// Whether the total frame size changed
+    pub frame_size_changed: bool,
+}
+
+impl SchemaDelta {
+    /// Returns true when the layouts are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && !self.frame_size_changed
+    }
+
+    /// Check whether a variable was added, removed or changed.
+    pub fn touches(&self, name: &str) -> bool {
+        [&self.added, &self.removed, &self.changed]
+            .iter()
+            .any(|names| names.binary_search_by(|n| n.as_str().cmp(name)).is_ok())
+    }
 }
 
 /// Information about a specific telemetry variable.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
 pub struct VariableInfo {
     /// Variable name as defined by iRacing
@@ -93,3 +324,61 @@ pub struct VariableInfo {
     /// Human-readable description
     pub description: String,
 }
+
+impl VariableInfo {
+    /// Whether the array elements are samples over time rather than per-car or
+    /// per-corner values (iRacing's `countAsTime`, e.g. the `_ST` variables).
+    pub fn is_timeseries(&self) -> bool {
+        self.count_as_time
+    }
+
+    /// Ensure all `count` elements of the variable lie within a frame of `len` bytes.
+    ///
+    /// Returns `Memory` otherwise. A live frame can be shorter than the schema it
+    /// is read with when iRacing switches cars and resizes its buffer before the
+    /// schema is rebuilt.
+    pub fn check_bounds(&self, len: usize) -> crate::Result<()> {
+        let end = self
+            .data_type
+            .size()
+            .checked_mul(self.count)
+            .and_then(|bytes| self.offset.checked_add(bytes));
+        match end {
+            Some(end) if end <= len => Ok(()),
+            _ => Err(crate::TelemetryError::Memory { offset: self.offset, source: None }),
+        }
+    }
+
+    /// Scalar layout of element 0, without the name, units or description.
+    ///
+    /// Building it does not allocate, so hot paths can decode array elements
+    /// without copying the variable's strings.
+    pub(crate) fn element_layout(&self) -> VariableInfo {
+        VariableInfo {
+            name: String::new(),
+            data_type: self.data_type,
+            offset: self.offset,
+            count: 1,
+            count_as_time: false,
+            units: String::new(),
+            description: String::new(),
+        }
+    }
+
+    /// Ensure the variable holds at least `len` elements.
+    ///
+    /// Fixed-size array reads (`[T; N]`) call this on every read, so a short
+    /// variable returns a `TypeConversion` error instead of partial data.
+    pub fn require_count(&self, len: usize) -> crate::Result<()> {
+        if self.count < len {
+            return Err(crate::TelemetryError::TypeConversion {
+                details: format!(
+                    "Variable '{}' has {} elements, expected at least {}",
+                    self.name, self.count, len
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
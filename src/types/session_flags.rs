@@ -0,0 +1,92 @@
+//! SessionFlags decoding
+
+use super::BitField;
+
+/// Decoded `SessionFlags` bitfield
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub struct SessionFlags {
+    pub checkered: bool,
+    pub white: bool,
+    pub green: bool,
+    pub yellow: bool,
+    pub red: bool,
+    pub blue: bool,
+    pub debris: bool,
+    pub crossed: bool,
+    pub yellow_waving: bool,
+    pub one_lap_to_green: bool,
+    pub green_held: bool,
+    pub ten_to_go: bool,
+    pub five_to_go: bool,
+    pub random_waving: bool,
+    pub caution: bool,
+    pub caution_waving: bool,
+    pub black: bool,
+    pub disqualify: bool,
+    pub serviceable: bool,
+    pub furled: bool,
+    pub repair: bool,
+    pub dq_scoring_invalid: bool,
+    pub start_hidden: bool,
+    pub start_ready: bool,
+    pub start_set: bool,
+    pub start_go: bool,
+}
+
+impl SessionFlags {
+    /// Full-course caution is out or being waved
+    pub fn is_caution(&self) -> bool {
+        self.caution || self.caution_waving
+    }
+
+    /// Green flag is shown and no caution is active
+    pub fn is_green(&self) -> bool {
+        self.green && !self.is_caution()
+    }
+
+    /// Any local or full-course yellow is shown
+    pub fn is_yellow(&self) -> bool {
+        self.yellow || self.yellow_waving || self.is_caution()
+    }
+
+    /// Any driver black flag (black, disqualify, furled or repair) is shown
+    pub fn has_black_flag(&self) -> bool {
+        self.black || self.disqualify || self.furled || self.repair
+    }
+}
+
+/// Decode a BitField carrying SessionFlags into named booleans
+pub fn decode_session_flags(bits: BitField) -> SessionFlags {
+    use super::irsdk_flags::session_flags as sf;
+
+    SessionFlags {
+        checkered: bits.has_flag(sf::CHECKERED),
+        white: bits.has_flag(sf::WHITE),
+        green: bits.has_flag(sf::GREEN),
+        yellow: bits.has_flag(sf::YELLOW),
+        red: bits.has_flag(sf::RED),
+        blue: bits.has_flag(sf::BLUE),
+        debris: bits.has_flag(sf::DEBRIS),
+        crossed: bits.has_flag(sf::CROSSED),
+        yellow_waving: bits.has_flag(sf::YELLOW_WAVING),
+        one_lap_to_green: bits.has_flag(sf::ONE_LAP_TO_GREEN),
+        green_held: bits.has_flag(sf::GREEN_HELD),
+        ten_to_go: bits.has_flag(sf::TEN_TO_GO),
+        five_to_go: bits.has_flag(sf::FIVE_TO_GO),
+        random_waving: bits.has_flag(sf::RANDOM_WAVING),
+        caution: bits.has_flag(sf::CAUTION),
+        caution_waving: bits.has_flag(sf::CAUTION_WAVING),
+        black: bits.has_flag(sf::BLACK),
+        disqualify: bits.has_flag(sf::DISQUALIFY),
+        serviceable: bits.has_flag(sf::SERVICEABLE),
+        furled: bits.has_flag(sf::FURLED),
+        repair: bits.has_flag(sf::REPAIR),
+        dq_scoring_invalid: bits.has_flag(sf::DQ_SCORING_INVALID),
+        start_hidden: bits.has_flag(sf::START_HIDDEN),
+        start_ready: bits.has_flag(sf::START_READY),
+        start_set: bits.has_flag(sf::START_SET),
+        start_go: bits.has_flag(sf::START_GO),
+    }
+}
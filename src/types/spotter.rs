@@ -0,0 +1,66 @@
+//! Spotter proximity decoding
+
+use super::{VarData, VariableInfo};
+
+/// Cars alongside the player, decoded from `CarLeftRight` (`irsdk_CarLeftRight`).
+///
+/// Implements [`VarData`], so adapter fields can be declared as `spotter: SpotterState`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum SpotterState {
+    /// Spotter is not active (e.g. the player is not in the car)
+    #[default]
+    Off,
+    /// No cars alongside
+    Clear,
+    CarLeft,
+    CarRight,
+    /// Cars on both sides
+    CarLeftRight,
+    /// Two cars alongside on the left (three wide)
+    TwoCarsLeft,
+    /// Two cars alongside on the right (three wide)
+    TwoCarsRight,
+    /// Value not defined by the SDK version this crate targets
+    Unknown(i32),
+}
+
+impl SpotterState {
+    /// At least one car is alongside on the left
+    pub fn has_car_left(self) -> bool {
+        matches!(
+            self,
+            SpotterState::CarLeft | SpotterState::CarLeftRight | SpotterState::TwoCarsLeft
+        )
+    }
+
+    /// At least one car is alongside on the right
+    pub fn has_car_right(self) -> bool {
+        matches!(
+            self,
+            SpotterState::CarRight | SpotterState::CarLeftRight | SpotterState::TwoCarsRight
+        )
+    }
+}
+
+impl From<i32> for SpotterState {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => SpotterState::Off,          // irsdk_LROff
+            1 => SpotterState::Clear,        // irsdk_LRClear
+            2 => SpotterState::CarLeft,      // irsdk_LRCarLeft
+            3 => SpotterState::CarRight,     // irsdk_LRCarRight
+            4 => SpotterState::CarLeftRight, // irsdk_LRCarLeftRight
+            5 => SpotterState::TwoCarsLeft,  // irsdk_LR2CarsLeft
+            6 => SpotterState::TwoCarsRight, // irsdk_LR2CarsRight
+            other => SpotterState::Unknown(other),
+        }
+    }
+}
+
+impl VarData for SpotterState {
+    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
+        i32::from_bytes(data, info).map(SpotterState::from)
+    }
+}
@@ -0,0 +1,136 @@
+//! Track location and surface material decoding
+
+use super::{VarData, VariableInfo};
+
+/// Where a car is on track, decoded from `PlayerTrackSurface` / `CarIdxTrackSurface`
+/// (`irsdk_TrkLoc`).
+///
+/// Implements [`VarData`], so adapter fields can be declared as `surface: TrackSurface`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum TrackSurface {
+    /// Car is not in the world (e.g. in the garage or an empty car slot)
+    #[default]
+    NotInWorld,
+    OffTrack,
+    InPitStall,
+    /// On pit road, approaching or leaving the pit stall
+    ApproachingPits,
+    OnTrack,
+    /// Value not defined by the SDK version this crate targets
+    Unknown(i32),
+}
+
+impl TrackSurface {
+    /// Car is on pit road, including its pit stall
+    pub fn is_on_pit_road(self) -> bool {
+        matches!(self, TrackSurface::InPitStall | TrackSurface::ApproachingPits)
+    }
+
+    /// Car is on the racing surface
+    pub fn is_on_track(self) -> bool {
+        self == TrackSurface::OnTrack
+    }
+}
+
+impl From<i32> for TrackSurface {
+    fn from(value: i32) -> Self {
+        match value {
+            -1 => TrackSurface::NotInWorld,     // irsdk_NotInWorld
+            0 => TrackSurface::OffTrack,        // irsdk_OffTrack
+            1 => TrackSurface::InPitStall,      // irsdk_InPitStall
+            2 => TrackSurface::ApproachingPits, // irsdk_AproachingPits
+            3 => TrackSurface::OnTrack,         // irsdk_OnTrack
+            other => TrackSurface::Unknown(other),
+        }
+    }
+}
+
+impl VarData for TrackSurface {
+    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
+        i32::from_bytes(data, info).map(TrackSurface::from)
+    }
+}
+
+/// Surface material under a car, decoded from `PlayerTrackSurfaceMaterial` /
+/// `CarIdxTrackSurfaceMaterial` (`irsdk_TrkSurf`).
+///
+/// Numbered variants carry the SDK's material grade (e.g. `Asphalt(2)` is
+/// `irsdk_Asphalt2Material`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "tauri", derive(specta::Type))]
+pub enum TrackSurfaceMaterial {
+    #[default]
+    NotInWorld,
+    Undefined,
+    Asphalt(u8),
+    Concrete(u8),
+    RacingDirt(u8),
+    Paint(u8),
+    Rumble(u8),
+    Grass(u8),
+    Dirt(u8),
+    Sand,
+    Gravel(u8),
+    Grasscrete,
+    Astroturf,
+    /// Value not defined by the SDK version this crate targets
+    Unknown(i32),
+}
+
+impl TrackSurfaceMaterial {
+    /// Asphalt, concrete or painted surfaces
+    pub fn is_paved(self) -> bool {
+        matches!(
+            self,
+            TrackSurfaceMaterial::Asphalt(_)
+                | TrackSurfaceMaterial::Concrete(_)
+                | TrackSurfaceMaterial::Paint(_)
+        )
+    }
+
+    /// Grass, dirt, sand, gravel and similar run-off surfaces (excludes racing dirt)
+    pub fn is_run_off(self) -> bool {
+        matches!(
+            self,
+            TrackSurfaceMaterial::Grass(_)
+                | TrackSurfaceMaterial::Dirt(_)
+                | TrackSurfaceMaterial::Sand
+                | TrackSurfaceMaterial::Gravel(_)
+                | TrackSurfaceMaterial::Grasscrete
+                | TrackSurfaceMaterial::Astroturf
+        )
+    }
+}
+
+impl From<i32> for TrackSurfaceMaterial {
+    fn from(value: i32) -> Self {
+        // Grade within a material group, e.g. 2 for irsdk_Asphalt2Material
+        let grade = |first: i32| (value - first + 1) as u8;
+
+        match value {
+            -1 => TrackSurfaceMaterial::NotInWorld,
+            0 => TrackSurfaceMaterial::Undefined,
+            1..=4 => TrackSurfaceMaterial::Asphalt(grade(1)),
+            5..=6 => TrackSurfaceMaterial::Concrete(grade(5)),
+            7..=8 => TrackSurfaceMaterial::RacingDirt(grade(7)),
+            9..=10 => TrackSurfaceMaterial::Paint(grade(9)),
+            11..=14 => TrackSurfaceMaterial::Rumble(grade(11)),
+            15..=18 => TrackSurfaceMaterial::Grass(grade(15)),
+            19..=22 => TrackSurfaceMaterial::Dirt(grade(19)),
+            23 => TrackSurfaceMaterial::Sand,
+            24..=25 => TrackSurfaceMaterial::Gravel(grade(24)),
+            26 => TrackSurfaceMaterial::Grasscrete,
+            27 => TrackSurfaceMaterial::Astroturf,
+            other => TrackSurfaceMaterial::Unknown(other),
+        }
+    }
+}
+
+impl VarData for TrackSurfaceMaterial {
+    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
+        i32::from_bytes(data, info).map(TrackSurfaceMaterial::from)
+    }
+}
@@ -1,20 +1,73 @@
 //! Update rate control for telemetry streams
 
-use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::{Result, TelemetryError};
 
 /// Update rate for telemetry streams
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
 pub enum UpdateRate {
-    /// Full speed from source (typically 60Hz)
+    /// Full speed from source (the recorded tick rate, e.g. 60Hz or 360Hz)
     Native,
 
     /// Throttled to maximum Hz
     /// If the requested rate exceeds source rate, Native is used
+    ///
+    /// The source is only read once the interval elapses, taking whatever
+    /// frame is ready at that moment.
     Max(u32),
+
+    /// Bounded to `hz`, always yielding the freshest frame
+    /// If the requested rate exceeds source rate, Native is used
+    ///
+    /// Unlike `Max`, the source keeps being drained between ticks so the frame
+    /// emitted at each tick is the most recent one, with intermediate frames
+    /// coalesced. If no new frame arrived during an interval, the next one is
+    /// emitted as soon as it arrives instead of waiting for another tick.
+    LatestAt(u32),
 }
 
 impl UpdateRate {
+    /// Checked constructor for [`UpdateRate::Max`], rejecting a rate of 0
+    pub fn max(hz: u32) -> Result<Self> {
+        Self::check_hz(hz).map(UpdateRate::Max)
+    }
+
+    /// Checked constructor for [`UpdateRate::LatestAt`], rejecting a rate of 0
+    pub fn latest_at(hz: u32) -> Result<Self> {
+        Self::check_hz(hz).map(UpdateRate::LatestAt)
+    }
+
+    fn check_hz(hz: u32) -> Result<u32> {
+        if hz == 0 {
+            return Err(TelemetryError::Parse {
+                context: "Update rate".to_string(),
+                details: "Rate must be at least 1Hz".to_string(),
+            });
+        }
+        Ok(hz)
+    }
+
+    /// Returns true for [`UpdateRate::Native`]
+    pub fn is_native(&self) -> bool {
+        matches!(self, UpdateRate::Native)
+    }
+
+    /// Minimum time between emitted frames, before normalization
+    ///
+    /// `None` means unthrottled: either `Native` or a rate of 0, which the
+    /// unchecked variants can still carry.
+    pub fn interval(&self) -> Option<Duration> {
+        match *self {
+            UpdateRate::Native | UpdateRate::Max(0) | UpdateRate::LatestAt(0) => None,
+            UpdateRate::Max(hz) | UpdateRate::LatestAt(hz) => {
+                Some(Duration::from_secs_f64(1.0 / hz as f64))
+            }
+        }
+    }
+
     /// Normalize rate against source frequency
     /// Returns effective rate to use
     pub fn normalize(self, source_hz: f64) -> Self {
@@ -22,22 +75,42 @@ impl UpdateRate {
             UpdateRate::Native => UpdateRate::Native,
             UpdateRate::Max(hz) if hz as f64 >= source_hz => UpdateRate::Native,
             UpdateRate::Max(hz) => UpdateRate::Max(hz),
+            UpdateRate::LatestAt(hz) if hz as f64 >= source_hz => UpdateRate::Native,
+            UpdateRate::LatestAt(hz) => UpdateRate::LatestAt(hz),
         }
     }
 
     /// Check if throttling is needed
     pub fn needs_throttle(self, source_hz: f64) -> bool {
-        match self.normalize(source_hz) {
-            UpdateRate::Native => false,
-            UpdateRate::Max(_) => true,
-        }
+        self.throttle_interval(source_hz).is_some()
     }
 
     /// Get throttle interval if needed
-    pub fn throttle_interval(self, source_hz: f64) -> Option<std::time::Duration> {
-        match self.normalize(source_hz) {
-            UpdateRate::Native => None,
-            UpdateRate::Max(hz) => Some(std::time::Duration::from_secs_f64(1.0 / hz as f64)),
-        }
+    pub fn throttle_interval(self, source_hz: f64) -> Option<Duration> {
+        self.normalize(source_hz).interval()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_constructors_reject_zero() {
+        assert!(UpdateRate::max(0).is_err());
+        assert!(UpdateRate::latest_at(0).is_err());
+        assert_eq!(UpdateRate::max(30).unwrap(), UpdateRate::Max(30));
+    }
+
+    #[test]
+    fn interval_treats_zero_rate_as_unthrottled() {
+        assert_eq!(UpdateRate::Max(4).interval(), Some(Duration::from_millis(250)));
+        assert_eq!(UpdateRate::Max(0).interval(), None);
+        assert_eq!(UpdateRate::Native.interval(), None);
+        assert!(UpdateRate::Native.is_native());
+
+        assert!(!UpdateRate::Max(0).needs_throttle(60.0));
+        assert!(!UpdateRate::Max(120).needs_throttle(60.0));
+        assert!(UpdateRate::LatestAt(10).needs_throttle(60.0));
     }
 }
@@ -1,6 +1,6 @@
 //! Variable data parsing trait and implementations
 
-use super::{BitField, VariableInfo, VariableType};
+use super::{BitField, Value, VariableInfo, VariableType};
 
 /// Trait for types that can be parsed from binary telemetry data.
 pub trait VarData: Sized {
@@ -8,196 +8,142 @@ pub trait VarData: Sized {
     fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self>;
 }
 
-// Implement VarData for basic types
-impl VarData for f32 {
-    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::Float32 {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected Float32, got {:?}", info.data_type),
-            });
+/// Implement [`VarData`] for a scalar by type-checking `info` and decoding the
+/// element with [`VariableType::read`].
+macro_rules! impl_scalar_var_data {
+    ($ty:ty, $expected:literal, $($variant:ident)|+) => {
+        impl VarData for $ty {
+            fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
+                if !matches!(info.data_type, $(VariableType::$variant)|+) {
+                    return Err(crate::TelemetryError::TypeConversion {
+                        details: format!("Expected {}, got {:?}", $expected, info.data_type),
+                    });
+                }
+
+                match info.data_type.read(data, info.offset)? {
+                    $(Value::$variant(value))|+ => Ok(value),
+                    other => unreachable!("VariableType::read returned {:?}", other),
+                }
+            }
         }
-
-        let bytes = data
-            .get(info.offset..info.offset + 4)
-            .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
-
-        Ok(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
-    }
+    };
 }
 
-impl VarData for i32 {
+impl_scalar_var_data!(f32, "Float32", Float32);
+impl_scalar_var_data!(f64, "Float64", Float64);
+impl_scalar_var_data!(i32, "Int32", Int32);
+impl_scalar_var_data!(u32, "UInt32", UInt32);
+impl_scalar_var_data!(i16, "Int16", Int16);
+impl_scalar_var_data!(u16, "UInt16", UInt16);
+impl_scalar_var_data!(i8, "Int8", Int8);
+impl_scalar_var_data!(u8, "UInt8 or Char", UInt8 | Char);
+impl_scalar_var_data!(bool, "Bool", Bool);
+impl_scalar_var_data!(BitField, "BitField", BitField);
+
+// Char arrays are fixed-length, NUL-terminated C strings
+impl VarData for String {
     fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::Int32 {
+        if info.data_type != VariableType::Char {
             return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected Int32, got {:?}", info.data_type),
+                details: format!("Expected Char, got {:?}", info.data_type),
             });
         }
 
-        let bytes = data
-            .get(info.offset..info.offset + 4)
+        let bytes = info
+            .offset
+            .checked_add(info.count)
+            .and_then(|end| data.get(info.offset..end))
             .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
 
-        Ok(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        Ok(String::from_utf8_lossy(&bytes[..len]).into_owned())
     }
 }
 
-impl VarData for bool {
+// Runtime-typed values decode according to the variable's own data type
+impl VarData for Value {
     fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::Bool {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected Bool, got {:?}", info.data_type),
-            });
+        if info.count > 1 {
+            return from_bytes_array(data, info).map(Value::Array);
         }
 
-        let byte = data
-            .get(info.offset)
-            .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
-
-        Ok(*byte != 0)
+        info.data_type.read(data, info.offset)
     }
 }
 
-impl VarData for BitField {
-    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::BitField {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected BitField, got {:?}", info.data_type),
-            });
-        }
-
-        let bytes = data
-            .get(info.offset..info.offset + 4)
-            .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
-
-        Ok(BitField(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])))
-    }
+/// Read all `info.count` elements of an array variable.
+///
+/// Element `i` is parsed from `info.offset + i * info.data_type.size()`. Each
+/// element is type-checked and bounds-checked against `data`, so a mismatched
+/// `T` yields `TypeConversion` and a short frame yields `Memory`.
+pub fn from_bytes_array<T: VarData>(data: &[u8], info: &VariableInfo) -> crate::Result<Vec<T>> {
+    read_elements(data, info, info.count)
 }
 
-// Additional VarData implementations for all iRacing SDK types
-impl VarData for u8 {
-    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if !matches!(info.data_type, VariableType::UInt8 | VariableType::Char) {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected UInt8 or Char, got {:?}", info.data_type),
-            });
-        }
-
-        let byte = data
-            .get(info.offset)
+/// Read the first `count` elements of `info`, decoding each with a string-free
+/// copy of its layout
+fn read_elements<T: VarData>(
+    data: &[u8],
+    info: &VariableInfo,
+    count: usize,
+) -> crate::Result<Vec<T>> {
+    let element_size = info.data_type.size();
+    let mut element_info = info.element_layout();
+
+    let mut result = Vec::with_capacity(count);
+    for i in 0..count {
+        element_info.offset = i
+            .checked_mul(element_size)
+            .and_then(|delta| info.offset.checked_add(delta))
             .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
 
-        Ok(*byte)
+        result.push(T::from_bytes(data, &element_info)?);
     }
-}
-
-impl VarData for i8 {
-    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::Int8 {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected Int8, got {:?}", info.data_type),
-            });
-        }
-
-        let byte = data
-            .get(info.offset)
-            .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
 
-        Ok(*byte as i8)
-    }
+    Ok(result)
 }
 
-impl VarData for u16 {
-    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::UInt16 {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected UInt16, got {:?}", info.data_type),
-            });
-        }
-
-        let bytes = data
-            .get(info.offset..info.offset + 2)
-            .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
-
-        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+/// Read a timeseries array variable as `(offset_seconds, sample)` pairs.
+///
+/// The `count` samples of a `count_as_time` variable are spread evenly across one
+/// tick, so sample `i` is at `i / (tick_rate * count)` seconds after the frame's
+/// tick. Returns `TypeConversion` if the variable is not a timeseries or
+/// `tick_rate` is not positive.
+pub fn from_bytes_timeseries<T: VarData>(
+    data: &[u8],
+    info: &VariableInfo,
+    tick_rate: f64,
+) -> crate::Result<Vec<(f64, T)>> {
+    if !info.is_timeseries() {
+        return Err(crate::TelemetryError::TypeConversion {
+            details: format!("Variable '{}' is not a timeseries (count_as_time)", info.name),
+        });
     }
-}
-
-impl VarData for i16 {
-    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::Int16 {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected Int16, got {:?}", info.data_type),
-            });
-        }
-
-        let bytes = data
-            .get(info.offset..info.offset + 2)
-            .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
-
-        Ok(i16::from_le_bytes([bytes[0], bytes[1]]))
+    if !tick_rate.is_finite() || tick_rate <= 0.0 {
+        return Err(crate::TelemetryError::TypeConversion {
+            details: format!("Invalid tick rate {} for timeseries '{}'", tick_rate, info.name),
+        });
     }
-}
 
-impl VarData for u32 {
-    fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::UInt32 {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected UInt32, got {:?}", info.data_type),
-            });
-        }
-
-        let bytes = data
-            .get(info.offset..info.offset + 4)
-            .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
-
-        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
-    }
+    let sample_interval = 1.0 / (tick_rate * info.count as f64);
+    let samples = from_bytes_array(data, info)?;
+    Ok(samples.into_iter().enumerate().map(|(i, v)| (i as f64 * sample_interval, v)).collect())
 }
 
-impl VarData for f64 {
+// Array support for VarData
+impl<T: VarData> VarData for Vec<T> {
     fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.data_type != VariableType::Float64 {
-            return Err(crate::TelemetryError::TypeConversion {
-                details: format!("Expected Float64, got {:?}", info.data_type),
-            });
-        }
-
-        let bytes = data
-            .get(info.offset..info.offset + 8)
-            .ok_or(crate::TelemetryError::Memory { offset: info.offset, source: None })?;
-
-        Ok(f64::from_le_bytes([
-            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-        ]))
+        from_bytes_array(data, info)
     }
 }
 
-// Array support for VarData
-impl<T: VarData> VarData for Vec<T> {
+impl<T: VarData, const N: usize> VarData for [T; N] {
     fn from_bytes(data: &[u8], info: &VariableInfo) -> crate::Result<Self> {
-        if info.count == 0 {
-            return Ok(Vec::new());
-        }
-
-        let element_size = info.data_type.size();
-        let mut result = Vec::with_capacity(info.count);
-
-        for i in 0..info.count {
-            let element_offset = info.offset + (i * element_size);
-            let element_info = VariableInfo {
-                name: info.name.clone(),
-                data_type: info.data_type,
-                offset: element_offset,
-                count: 1,
-                count_as_time: info.count_as_time,
-                units: info.units.clone(),
-                description: info.description.clone(),
-            };
-
-            let element = T::from_bytes(data, &element_info)?;
-            result.push(element);
-        }
+        info.require_count(N)?;
 
-        Ok(result)
+        let values = read_elements::<T>(data, info, N)?;
+        values.try_into().map_err(|_| crate::TelemetryError::TypeConversion {
+            details: format!("Failed to collect {} elements of '{}'", N, info.name),
+        })
     }
 }
@@ -1,10 +1,9 @@
 //! Telemetry variable type definitions
 
-use serde::{Deserialize, Serialize};
-
 /// Supported telemetry data types.
 /// Maps to iRacing SDK's irsdk_VarType enum.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
 pub enum VariableType {
     /// 8-bit character (maps to irsdk_char)
@@ -46,10 +45,46 @@ impl VariableType {
             VariableType::Float64 => 8,
         }
     }
+
+    /// Decode one element of this type from `data` at `offset`.
+    ///
+    /// The single place raw telemetry bytes become typed values: a little-endian
+    /// read of [`size`](Self::size) bytes, returning `Memory` when the element
+    /// does not fit in `data`.
+    pub fn read(&self, data: &[u8], offset: usize) -> crate::Result<Value> {
+        let bytes = offset
+            .checked_add(self.size())
+            .and_then(|end| data.get(offset..end))
+            .ok_or(crate::TelemetryError::Memory { offset, source: None })?;
+
+        Ok(match self {
+            VariableType::Char => Value::Char(bytes[0]),
+            VariableType::Int8 => Value::Int8(i8::from_le_bytes([bytes[0]])),
+            VariableType::UInt8 => Value::UInt8(bytes[0]),
+            VariableType::Bool => Value::Bool(bytes[0] != 0),
+            VariableType::Int16 => Value::Int16(i16::from_le_bytes(le_bytes(bytes))),
+            VariableType::UInt16 => Value::UInt16(u16::from_le_bytes(le_bytes(bytes))),
+            VariableType::Int32 => Value::Int32(i32::from_le_bytes(le_bytes(bytes))),
+            VariableType::UInt32 => Value::UInt32(u32::from_le_bytes(le_bytes(bytes))),
+            VariableType::Float32 => Value::Float32(f32::from_le_bytes(le_bytes(bytes))),
+            VariableType::Float64 => Value::Float64(f64::from_le_bytes(le_bytes(bytes))),
+            VariableType::BitField => {
+                Value::BitField(super::BitField(u32::from_le_bytes(le_bytes(bytes))))
+            }
+        })
+    }
+}
+
+/// Copy a slice already sized by [`VariableType::size`] into a fixed array
+fn le_bytes<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut array = [0u8; N];
+    array.copy_from_slice(bytes);
+    array
 }
 
 /// Runtime value type that can hold any telemetry data.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "tauri", derive(specta::Type))]
 pub enum Value {
     Char(u8),
@@ -9,7 +9,8 @@ use std::time::Duration;
 use tracing::{debug, trace, warn};
 use windows::Win32::Foundation::{CloseHandle, HANDLE, WAIT_OBJECT_0, WAIT_TIMEOUT};
 use windows::Win32::System::Memory::{
-    FILE_MAP_READ, MEMORY_MAPPED_VIEW_ADDRESS, MapViewOfFile, OpenFileMappingW, UnmapViewOfFile,
+    FILE_MAP_READ, MEMORY_BASIC_INFORMATION, MEMORY_MAPPED_VIEW_ADDRESS, MapViewOfFile,
+    OpenFileMappingW, UnmapViewOfFile, VirtualQuery,
 };
 use windows::Win32::System::Threading::{
     OpenEventW, SYNCHRONIZATION_ACCESS_RIGHTS, WaitForSingleObject,
@@ -27,6 +28,13 @@ const IRSDK_ST_CONNECTED: i32 = 1;
 /// Maximum number of telemetry buffers
 const IRSDK_MAX_BUFS: usize = 4;
 
+// Headers are read in place from the shared memory view in native byte order,
+// which matches iRacing's little-endian layout only on little-endian targets.
+const _: () = assert!(
+    cfg!(target_endian = "little"),
+    "iRacing shared memory is little-endian; native-order header reads require a little-endian target"
+);
+
 /// Variable buffer containing tick count and offset information
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
@@ -123,6 +131,8 @@ pub enum WaitResult {
 pub struct Connection {
     mapping: HANDLE,
     base: NonNull<u8>,
+    /// Readable bytes in the mapped view, as reported by `VirtualQuery`
+    view_len: usize,
     event: HANDLE,
     last_tick_count: i32,
 }
@@ -148,6 +158,21 @@ impl Connection {
             })?
         };
 
+        // Size the view from the mapping itself rather than assuming iRacing's size
+        let view_len = unsafe {
+            let mut info = MEMORY_BASIC_INFORMATION::default();
+            let written = VirtualQuery(
+                Some(base.as_ptr() as *const _),
+                &mut info,
+                std::mem::size_of::<MEMORY_BASIC_INFORMATION>(),
+            );
+            if written == 0 {
+                let win_err = windows::core::Error::from_thread();
+                return Err(TelemetryError::windows_api_error("VirtualQuery", win_err));
+            }
+            info.RegionSize
+        };
+
         // Open the data valid event
         let event = unsafe {
             let wide_name = wide_string(IRSDK_DATAVALIDEVENTNAME);
@@ -161,7 +186,7 @@ impl Connection {
 
         // Initialize with i32::MAX to match C++ SDK's INT_MAX
         // This ensures the first frame is always accepted as "new"
-        let connection = Self { mapping, base, event, last_tick_count: i32::MAX };
+        let connection = Self { mapping, base, view_len, event, last_tick_count: i32::MAX };
 
         // Validate the connection
         connection.validate_connection()?;
@@ -216,6 +241,7 @@ impl Connection {
     /// At 60Hz (16.67ms frames), the hot path (data already available) never reaches
     /// this method, so spawn_blocking overhead is only paid during startup, pauses,
     /// or frame drops - exactly when we want cooperative yielding anyway.
+    #[cfg(feature = "runtime")]
     pub async fn wait_for_update_async(&self, timeout: Duration) -> Result<WaitResult> {
         // Convert HANDLE to raw pointer value (usize) to make it Send
         // SAFETY: Windows event handles are thread-safe kernel objects
@@ -311,22 +337,19 @@ impl Connection {
     }
 
     /// Get session info YAML string
-    pub fn session_info(&self) -> Option<&str> {
+    ///
+    /// Returns `Ok(None)` when iRacing has not published session info yet, and a
+    /// `Memory` error when the header places it outside the mapping.
+    pub fn session_info(&self) -> Result<Option<String>> {
         let header = self.header();
         if header.session_info_len <= 0 {
-            return None;
+            return Ok(None);
         }
 
-        unsafe {
-            let info_ptr = self.base.as_ptr().add(header.session_info_offset as usize);
-            let info_slice = std::slice::from_raw_parts(info_ptr, header.session_info_len as usize);
-
-            // Find null terminator - iRacing YAML is null-terminated
-            let null_pos = info_slice.iter().position(|&b| b == 0).unwrap_or(info_slice.len());
-            let yaml_bytes = &info_slice[..null_pos];
-
-            std::str::from_utf8(yaml_bytes).ok()
-        }
+        // SAFETY: `view_len` is the readable size of the view, queried when it was mapped
+        let memory = unsafe { std::slice::from_raw_parts(self.base.as_ptr(), self.view_len) };
+        crate::yaml_utils::extract_yaml(memory, header.session_info_offset, header.session_info_len)
+            .map(Some)
     }
 
     /// Get session info update counter
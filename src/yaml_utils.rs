@@ -5,7 +5,9 @@
 //! - Control characters that break YAML parsers
 //! - Inconsistent string quoting
 //!
-//! This module provides low-level YAML cleaning without parsing.
+//! This module provides low-level YAML extraction and cleaning without parsing,
+//! shared by the shared memory and IBT readers and usable on any memory snapshot
+//! laid out like iRacing's.
 
 use crate::{Result, TelemetryError};
 
@@ -53,46 +55,44 @@ pub fn preprocess_iracing_yaml(yaml: &str) -> Result<String> {
     Ok(result)
 }
 
-/// Extract YAML from a memory buffer
+/// Extract the session info YAML from a memory snapshot
 ///
-/// Handles null-terminated strings and validates UTF-8 encoding.
-/// Returns the raw YAML string without preprocessing.
-pub fn extract_yaml_from_memory(data: &[u8], offset: i32, length: i32) -> Result<String> {
-    // Validate parameters
-    if offset < 0 {
-        return Err(TelemetryError::Parse {
-            context: "YAML extraction".to_string(),
-            details: format!("Invalid offset: {}", offset),
-        });
-    }
-
-    if length <= 0 {
-        return Ok(String::new());
-    }
-
-    let offset = offset as usize;
-    let length = length as usize;
-
-    // Validate bounds
-    if offset + length > data.len() {
-        return Err(TelemetryError::Parse {
-            context: "YAML extraction".to_string(),
-            details: format!(
-                "YAML extends beyond buffer bounds: offset={}, len={}, buffer_size={}",
-                offset,
-                length,
-                data.len()
+/// `offset` and `len` are the `session_info_offset` and `session_info_len`
+/// header fields, as found in both the shared memory header and IBT files. The
+/// string ends at the first null byte or after `len` bytes. A zero `len` gives
+/// an empty string.
+///
+/// Returns a `Memory` error when the range is negative or extends past the end
+/// of `memory`, and a `Parse` error when the YAML is not valid UTF-8. The YAML
+/// is returned as stored; see [`preprocess_iracing_yaml`] for cleaning it.
+pub fn extract_yaml(memory: &[u8], offset: i32, len: i32) -> Result<String> {
+    let (Ok(start), Ok(len)) = (usize::try_from(offset), usize::try_from(len)) else {
+        return Err(TelemetryError::Memory {
+            offset: offset.max(0) as usize,
+            source: Some(
+                format!("Invalid session info offset {} or length {}", offset, len).into(),
             ),
         });
-    }
-
-    // Extract the substring
-    let yaml_data = &data[offset..offset + length];
+    };
+
+    let yaml_data =
+        start.checked_add(len).and_then(|end| memory.get(start..end)).ok_or_else(|| {
+            TelemetryError::Memory {
+                offset: start,
+                source: Some(
+                    format!(
+                        "Session info of {} bytes extends past the {} byte buffer",
+                        len,
+                        memory.len()
+                    )
+                    .into(),
+                ),
+            }
+        })?;
 
     // Find null terminator or use entire length
-    let yaml_len = yaml_data.iter().position(|&b| b == 0).unwrap_or(length);
+    let yaml_len = yaml_data.iter().position(|&b| b == 0).unwrap_or(len);
 
-    // Convert to string
     let yaml_str =
         std::str::from_utf8(&yaml_data[..yaml_len]).map_err(|e| TelemetryError::Parse {
             context: "YAML UTF-8 conversion".to_string(),
@@ -128,23 +128,31 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_yaml_from_memory_with_null_terminator() {
+    fn test_extract_yaml_with_null_terminator() {
         let data = b"SessionInfo:\n  TrackName: test\0padding";
-        let result = extract_yaml_from_memory(data, 0, data.len() as i32).unwrap();
+        let result = extract_yaml(data, 0, data.len() as i32).unwrap();
         assert_eq!(result, "SessionInfo:\n  TrackName: test");
     }
 
     #[test]
-    fn test_extract_yaml_from_memory_without_null() {
+    fn test_extract_yaml_without_null() {
         let data = b"SessionInfo:\n  TrackName: test";
-        let result = extract_yaml_from_memory(data, 0, data.len() as i32).unwrap();
+        let result = extract_yaml(data, 0, data.len() as i32).unwrap();
         assert_eq!(result, "SessionInfo:\n  TrackName: test");
     }
 
     #[test]
     fn test_extract_yaml_bounds_check() {
         let data = b"test";
-        let result = extract_yaml_from_memory(data, 0, 100);
-        assert!(result.is_err());
+        let result = extract_yaml(data, 0, 100);
+        assert!(matches!(result, Err(TelemetryError::Memory { .. })));
+
+        let result = extract_yaml(data, -1, 2);
+        assert!(matches!(result, Err(TelemetryError::Memory { .. })));
+
+        let result = extract_yaml(data, i32::MAX, i32::MAX);
+        assert!(matches!(result, Err(TelemetryError::Memory { .. })));
+
+        assert_eq!(extract_yaml(data, 4, 0).unwrap(), "");
     }
 }